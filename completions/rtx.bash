@@ -30,6 +30,9 @@ _rtx() {
             rtx,cache)
                 cmd="rtx__cache"
                 ;;
+            rtx,complete-versions)
+                cmd="rtx__complete__versions"
+                ;;
             rtx,completion)
                 cmd="rtx__completion"
                 ;;
@@ -81,6 +84,9 @@ _rtx() {
             rtx,link)
                 cmd="rtx__link"
                 ;;
+            rtx,lint)
+                cmd="rtx__lint"
+                ;;
             rtx,list)
                 cmd="rtx__ls"
                 ;;
@@ -255,6 +261,9 @@ _rtx() {
             rtx__help,cache)
                 cmd="rtx__help__cache"
                 ;;
+            rtx__help,complete-versions)
+                cmd="rtx__help__complete__versions"
+                ;;
             rtx__help,completion)
                 cmd="rtx__help__completion"
                 ;;
@@ -300,6 +309,9 @@ _rtx() {
             rtx__help,link)
                 cmd="rtx__help__link"
                 ;;
+            rtx__help,lint)
+                cmd="rtx__help__lint"
+                ;;
             rtx__help,local)
                 cmd="rtx__help__local"
                 ;;
@@ -547,7 +559,7 @@ _rtx() {
 
     case "${cmd}" in
         rtx)
-            opts="-j -r -v -h -V --debug --install-missing --jobs --log-level --raw --trace --verbose --help --version activate alias asdf bin-paths cache completion current deactivate direnv doctor env env-vars exec global hook-env implode install latest link local ls ls-remote outdated plugins prune reshim self-update settings shell sync trust uninstall upgrade use version where which render-help help"
+            opts="-j -r -v -h -V --debug --install-missing --jobs --log-level --raw --trace --verbose --help --version activate alias asdf bin-paths cache complete-versions completion current deactivate direnv doctor env env-vars exec global hook-env implode install latest link lint local ls ls-remote outdated plugins prune reshim self-update settings shell sync trust uninstall upgrade use version where which render-help help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -573,18 +585,18 @@ _rtx() {
             return 0
             ;;
         rtx__activate)
-            opts="-s -q -j -r -v -h --shell --status --quiet --debug --install-missing --jobs --log-level --raw --trace --verbose --help bash fish nu xonsh zsh"
+            opts="-s -q -j -r -v -h --shell --status --quiet --debug --install-missing --jobs --log-level --raw --trace --verbose --help bash fish nu pwsh xonsh zsh"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
                 --shell)
-                    COMPREPLY=($(compgen -W "bash fish nu xonsh zsh" -- "${cur}"))
+                    COMPREPLY=($(compgen -W "bash fish nu pwsh xonsh zsh" -- "${cur}"))
                     return 0
                     ;;
                 -s)
-                    COMPREPLY=($(compgen -W "bash fish nu xonsh zsh" -- "${cur}"))
+                    COMPREPLY=($(compgen -W "bash fish nu pwsh xonsh zsh" -- "${cur}"))
                     return 0
                     ;;
                 --jobs)
@@ -751,7 +763,7 @@ _rtx() {
             return 0
             ;;
         rtx__alias__ls)
-            opts="-p -j -r -v -h --plugin --debug --install-missing --jobs --log-level --raw --trace --verbose --help"
+            opts="-p -j -r -v -h --plugin --builtin --debug --install-missing --jobs --log-level --raw --trace --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -982,6 +994,32 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        rtx__complete__versions)
+            opts="-j -r -v -h --debug --install-missing --jobs --log-level --raw --trace --verbose --help <TOOL@PREFIX>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --jobs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -j)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --log-level)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         rtx__completion)
             opts="-s -j -r -v -h --shell --debug --install-missing --jobs --log-level --raw --trace --verbose --help bash elvish fish powershell zsh"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
@@ -1017,12 +1055,20 @@ _rtx() {
             return 0
             ;;
         rtx__current)
-            opts="-j -r -v -h --debug --install-missing --jobs --log-level --raw --trace --verbose --help [PLUGIN]"
+            opts="-j -r -v -h --unresolved --git-ref --path --debug --install-missing --jobs --log-level --raw --trace --verbose --help [PLUGIN]"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --git-ref)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --path)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --jobs)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -1269,18 +1315,22 @@ _rtx() {
             return 0
             ;;
         rtx__env)
-            opts="-s -j -r -v -h --shell --json --debug --install-missing --jobs --log-level --raw --trace --verbose --help [TOOL@VERSION]..."
+            opts="-s -j -r -v -h --shell --json --layers --diff --debug --install-missing --jobs --log-level --raw --trace --verbose --help [TOOL@VERSION]..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
                 --shell)
-                    COMPREPLY=($(compgen -W "bash fish nu xonsh zsh" -- "${cur}"))
+                    COMPREPLY=($(compgen -W "bash fish nu pwsh xonsh zsh" -- "${cur}"))
                     return 0
                     ;;
                 -s)
-                    COMPREPLY=($(compgen -W "bash fish nu xonsh zsh" -- "${cur}"))
+                    COMPREPLY=($(compgen -W "bash fish nu pwsh xonsh zsh" -- "${cur}"))
+                    return 0
+                    ;;
+                --diff)
+                    COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
                 --jobs)
@@ -1337,7 +1387,7 @@ _rtx() {
             return 0
             ;;
         rtx__exec)
-            opts="-c -j -r -v -h --command --cd --debug --install-missing --jobs --log-level --raw --trace --verbose --help [TOOL@VERSION]... [COMMAND]..."
+            opts="-c -j -r -v -h --command --cd --only --resolve-policy --debug --install-missing --jobs --log-level --raw --trace --verbose --help [TOOL@VERSION]... [COMMAND]..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1355,6 +1405,14 @@ _rtx() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --only)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --resolve-policy)
+                    COMPREPLY=($(compgen -W "strict fallback-to-installed fallback-to-latest" -- "${cur}"))
+                    return 0
+                    ;;
                 --jobs)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -1405,7 +1463,7 @@ _rtx() {
             return 0
             ;;
         rtx__help)
-            opts="activate alias asdf bin-paths cache completion current deactivate direnv doctor env env-vars exec global hook-env implode install latest link local ls ls-remote outdated plugins prune reshim self-update settings shell sync trust uninstall upgrade use version where which render-help help"
+            opts="activate alias asdf bin-paths cache complete-versions completion current deactivate direnv doctor env env-vars exec global hook-env implode install latest link lint local ls ls-remote outdated plugins prune reshim self-update settings shell sync trust uninstall upgrade use version where which render-help help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1558,6 +1616,20 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        rtx__help__complete__versions)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         rtx__help__completion)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
@@ -1810,6 +1882,20 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        rtx__help__lint)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         rtx__help__local)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
@@ -2245,18 +2331,18 @@ _rtx() {
             return 0
             ;;
         rtx__hook__env)
-            opts="-s -j -r -v -h --shell --status --debug --install-missing --jobs --log-level --raw --trace --verbose --help"
+            opts="-s -j -r -v -h --shell --status --dry-run --debug --install-missing --jobs --log-level --raw --trace --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
                 --shell)
-                    COMPREPLY=($(compgen -W "bash fish nu xonsh zsh" -- "${cur}"))
+                    COMPREPLY=($(compgen -W "bash fish nu pwsh xonsh zsh" -- "${cur}"))
                     return 0
                     ;;
                 -s)
-                    COMPREPLY=($(compgen -W "bash fish nu xonsh zsh" -- "${cur}"))
+                    COMPREPLY=($(compgen -W "bash fish nu pwsh xonsh zsh" -- "${cur}"))
                     return 0
                     ;;
                 --jobs)
@@ -2305,7 +2391,7 @@ _rtx() {
             return 0
             ;;
         rtx__install)
-            opts="-f -v -j -r -h --force --verbose --debug --install-missing --jobs --log-level --raw --trace --help [TOOL@VERSION]..."
+            opts="-f -v -j -r -h --force --nearest --fail-fast --verbose --debug --install-missing --jobs --log-level --raw --trace --help [TOOL@VERSION]..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2382,6 +2468,32 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        rtx__lint)
+            opts="-j -r -v -h --debug --install-missing --jobs --log-level --raw --trace --verbose --help [FILES]..."
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --jobs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -j)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --log-level)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         rtx__local)
             opts="-p -j -r -v -h --parent --pin --fuzzy --remove --path --debug --install-missing --jobs --log-level --raw --trace --verbose --help [TOOL@VERSION]..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
@@ -2413,7 +2525,7 @@ _rtx() {
             return 0
             ;;
         rtx__ls)
-            opts="-p -c -i -m -j -r -v -h --plugin --current --installed --parseable --json --missing --prefix --debug --install-missing --jobs --log-level --raw --trace --verbose --help [PLUGIN_ARG]"
+            opts="-p -c -i -m -j -r -v -h --plugin --current --installed --parseable --json --missing --prefix --source --debug --install-missing --jobs --log-level --raw --trace --verbose --help [PLUGIN_ARG]"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2431,6 +2543,10 @@ _rtx() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --source)
+                    COMPREPLY=($(compgen -W "config env arg stdin" -- "${cur}"))
+                    return 0
+                    ;;
                 --jobs)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -2451,7 +2567,7 @@ _rtx() {
             return 0
             ;;
         rtx__ls__remote)
-            opts="-j -r -v -h --debug --install-missing --jobs --log-level --raw --trace --verbose --help <TOOL@VERSION> [PREFIX]"
+            opts="-j -r -v -h --save-snapshot --debug --install-missing --jobs --log-level --raw --trace --verbose --help <TOOL@VERSION> [PREFIX]"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3037,7 +3153,7 @@ _rtx() {
             return 0
             ;;
         rtx__settings__ls)
-            opts="-j -r -v -h --debug --install-missing --jobs --log-level --raw --trace --verbose --help"
+            opts="-j -r -v -h --origin --debug --install-missing --jobs --log-level --raw --trace --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -14,6 +14,7 @@ complete -c rtx -n "__fish_use_subcommand" -f -a "alias" -d 'Manage aliases'
 complete -c rtx -n "__fish_use_subcommand" -f -a "asdf" -d '[internal] simulates asdf for plugins that call "asdf" internally'
 complete -c rtx -n "__fish_use_subcommand" -f -a "bin-paths" -d 'List all the active runtime bin paths'
 complete -c rtx -n "__fish_use_subcommand" -f -a "cache" -d 'Manage the rtx cache'
+complete -c rtx -n "__fish_use_subcommand" -f -a "complete-versions" -d 'internal command used by shell completions to list versions of a tool'
 complete -c rtx -n "__fish_use_subcommand" -f -a "completion" -d 'Generate shell completions'
 complete -c rtx -n "__fish_use_subcommand" -f -a "current" -d 'Shows current active and installed runtime versions'
 complete -c rtx -n "__fish_use_subcommand" -f -a "deactivate" -d 'Disable rtx for current shell session'
@@ -28,6 +29,7 @@ complete -c rtx -n "__fish_use_subcommand" -f -a "implode" -d 'Removes rtx CLI a
 complete -c rtx -n "__fish_use_subcommand" -f -a "install" -d 'Install a tool version'
 complete -c rtx -n "__fish_use_subcommand" -f -a "latest" -d 'Gets the latest available version for a plugin'
 complete -c rtx -n "__fish_use_subcommand" -f -a "link" -d 'Symlinks a tool version into rtx'
+complete -c rtx -n "__fish_use_subcommand" -f -a "lint" -d 'Check config files for problems'
 complete -c rtx -n "__fish_use_subcommand" -f -a "local" -d 'Sets/gets tool version in local .tool-versions or .rtx.toml'
 complete -c rtx -n "__fish_use_subcommand" -f -a "ls" -d 'List installed and/or currently selected tool versions'
 complete -c rtx -n "__fish_use_subcommand" -f -a "ls-remote" -d 'List runtime versions available for install'
@@ -48,7 +50,7 @@ complete -c rtx -n "__fish_use_subcommand" -f -a "where" -d 'Display the install
 complete -c rtx -n "__fish_use_subcommand" -f -a "which" -d 'Shows the path that a bin name points to'
 complete -c rtx -n "__fish_use_subcommand" -f -a "render-help" -d 'internal command to generate markdown from help'
 complete -c rtx -n "__fish_use_subcommand" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
-complete -c rtx -n "__fish_seen_subcommand_from activate" -s s -l shell -d 'Shell type to generate the script for' -r -f -a "{bash	,fish	,nu	,xonsh	,zsh	}"
+complete -c rtx -n "__fish_seen_subcommand_from activate" -s s -l shell -d 'Shell type to generate the script for' -r -f -a "{bash	,fish	,nu	,pwsh	,xonsh	,zsh	}"
 complete -c rtx -n "__fish_seen_subcommand_from activate" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
 default: 4' -r
 complete -c rtx -n "__fish_seen_subcommand_from activate" -l log-level -d 'Set the log output verbosity' -r
@@ -93,6 +95,7 @@ complete -c rtx -n "__fish_seen_subcommand_from alias; and __fish_seen_subcomman
 complete -c rtx -n "__fish_seen_subcommand_from alias; and __fish_seen_subcommand_from ls" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
 default: 4' -r
 complete -c rtx -n "__fish_seen_subcommand_from alias; and __fish_seen_subcommand_from ls" -l log-level -d 'Set the log output verbosity' -r
+complete -c rtx -n "__fish_seen_subcommand_from alias; and __fish_seen_subcommand_from ls" -l builtin -d 'Only show the aliases the plugins define, not the ones from config files'
 complete -c rtx -n "__fish_seen_subcommand_from alias; and __fish_seen_subcommand_from ls" -l debug -d 'Sets log level to debug'
 complete -c rtx -n "__fish_seen_subcommand_from alias; and __fish_seen_subcommand_from ls" -l install-missing -d 'Automatically install missing tools'
 complete -c rtx -n "__fish_seen_subcommand_from alias; and __fish_seen_subcommand_from ls" -s r -l raw -d 'Directly pipe stdin/stdout/stderr to user.
@@ -171,6 +174,16 @@ complete -c rtx -n "__fish_seen_subcommand_from cache; and __fish_seen_subcomman
 complete -c rtx -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from clear" -s h -l help -d 'Print help'
 complete -c rtx -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from clear; and not __fish_seen_subcommand_from help" -f -a "clear" -d 'Deletes all cache files in rtx'
 complete -c rtx -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from clear; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rtx -n "__fish_seen_subcommand_from complete-versions" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
+default: 4' -r
+complete -c rtx -n "__fish_seen_subcommand_from complete-versions" -l log-level -d 'Set the log output verbosity' -r
+complete -c rtx -n "__fish_seen_subcommand_from complete-versions" -l debug -d 'Sets log level to debug'
+complete -c rtx -n "__fish_seen_subcommand_from complete-versions" -l install-missing -d 'Automatically install missing tools'
+complete -c rtx -n "__fish_seen_subcommand_from complete-versions" -s r -l raw -d 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1'
+complete -c rtx -n "__fish_seen_subcommand_from complete-versions" -l trace -d 'Sets log level to trace'
+complete -c rtx -n "__fish_seen_subcommand_from complete-versions" -s v -l verbose -d 'Show installation output'
+complete -c rtx -n "__fish_seen_subcommand_from complete-versions" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c rtx -n "__fish_seen_subcommand_from completion" -s s -l shell -d 'Shell type to generate completions for' -r -f -a "{bash	,elvish	,fish	,powershell	,zsh	}"
 complete -c rtx -n "__fish_seen_subcommand_from completion" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
 default: 4' -r
@@ -182,9 +195,12 @@ Sets --jobs=1'
 complete -c rtx -n "__fish_seen_subcommand_from completion" -l trace -d 'Sets log level to trace'
 complete -c rtx -n "__fish_seen_subcommand_from completion" -s v -l verbose -d 'Show installation output'
 complete -c rtx -n "__fish_seen_subcommand_from completion" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rtx -n "__fish_seen_subcommand_from current" -l git-ref -d 'Read the config files as they were at a git ref e.g.: `--git-ref HEAD~5`' -r
+complete -c rtx -n "__fish_seen_subcommand_from current" -l path -d 'Show the versions for a file or directory instead of the current directory e.g.: `--path ~/src/myproject/index.js`' -r -F
 complete -c rtx -n "__fish_seen_subcommand_from current" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
 default: 4' -r
 complete -c rtx -n "__fish_seen_subcommand_from current" -l log-level -d 'Set the log output verbosity' -r
+complete -c rtx -n "__fish_seen_subcommand_from current" -l unresolved -d 'Show the versions as they were requested instead of resolving them e.g.: `node lts` instead of `node 20.5.0`'
 complete -c rtx -n "__fish_seen_subcommand_from current" -l debug -d 'Sets log level to debug'
 complete -c rtx -n "__fish_seen_subcommand_from current" -l install-missing -d 'Automatically install missing tools'
 complete -c rtx -n "__fish_seen_subcommand_from current" -s r -l raw -d 'Directly pipe stdin/stdout/stderr to user.
@@ -264,11 +280,13 @@ Sets --jobs=1'
 complete -c rtx -n "__fish_seen_subcommand_from doctor" -l trace -d 'Sets log level to trace'
 complete -c rtx -n "__fish_seen_subcommand_from doctor" -s v -l verbose -d 'Show installation output'
 complete -c rtx -n "__fish_seen_subcommand_from doctor" -s h -l help -d 'Print help (see more with \'--help\')'
-complete -c rtx -n "__fish_seen_subcommand_from env" -s s -l shell -d 'Shell type to generate environment variables for' -r -f -a "{bash	,fish	,nu	,xonsh	,zsh	}"
+complete -c rtx -n "__fish_seen_subcommand_from env" -s s -l shell -d 'Shell type to generate environment variables for' -r -f -a "{bash	,fish	,nu	,pwsh	,xonsh	,zsh	}"
+complete -c rtx -n "__fish_seen_subcommand_from env" -l diff -d 'Show the env vars that change when changing from the current directory to DIR' -r -F
 complete -c rtx -n "__fish_seen_subcommand_from env" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
 default: 4' -r
 complete -c rtx -n "__fish_seen_subcommand_from env" -l log-level -d 'Set the log output verbosity' -r
 complete -c rtx -n "__fish_seen_subcommand_from env" -l json -d 'Output in JSON format'
+complete -c rtx -n "__fish_seen_subcommand_from env" -l layers -d 'Show the value each config file sets for the env vars from `[env]`'
 complete -c rtx -n "__fish_seen_subcommand_from env" -l debug -d 'Sets log level to debug'
 complete -c rtx -n "__fish_seen_subcommand_from env" -l install-missing -d 'Automatically install missing tools'
 complete -c rtx -n "__fish_seen_subcommand_from env" -s r -l raw -d 'Directly pipe stdin/stdout/stderr to user.
@@ -290,6 +308,8 @@ complete -c rtx -n "__fish_seen_subcommand_from env-vars" -s v -l verbose -d 'Sh
 complete -c rtx -n "__fish_seen_subcommand_from env-vars" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c rtx -n "__fish_seen_subcommand_from exec" -s c -l command -d 'Command string to execute' -r -f -a "(__fish_complete_command)"
 complete -c rtx -n "__fish_seen_subcommand_from exec" -l cd -d 'Change to this directory before executing the command' -r -f -a "(__fish_complete_directories)"
+complete -c rtx -n "__fish_seen_subcommand_from exec" -l only -d 'Only set the environment and PATH for these plugin(s) other tools in the toolset are not added to PATH' -r
+complete -c rtx -n "__fish_seen_subcommand_from exec" -l resolve-policy -d 'What to use when a version isn\'t installed e.g.: `--resolve-policy fallback-to-installed` uses node@20.1.0 for node@20 if it\'s installed instead of installing node@20.5.0' -r -f -a "{strict	use the version it resolves to\, it\'s installed or reported as missing,fallback-to-installed	use the newest installed version matching the request\, e.g.: 20.1.0 for `node@20` even if 20.5.0 is available,fallback-to-latest	like fallback-to-installed\, but use the newest installed version of the same major version if none match\, e.g.: 20.5.0 for `node@20.1`}"
 complete -c rtx -n "__fish_seen_subcommand_from exec" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
 default: 4' -r
 complete -c rtx -n "__fish_seen_subcommand_from exec" -l log-level -d 'Set the log output verbosity' -r
@@ -317,11 +337,12 @@ Sets --jobs=1'
 complete -c rtx -n "__fish_seen_subcommand_from global" -l trace -d 'Sets log level to trace'
 complete -c rtx -n "__fish_seen_subcommand_from global" -s v -l verbose -d 'Show installation output'
 complete -c rtx -n "__fish_seen_subcommand_from global" -s h -l help -d 'Print help (see more with \'--help\')'
-complete -c rtx -n "__fish_seen_subcommand_from hook-env" -s s -l shell -d 'Shell type to generate script for' -r -f -a "{bash	,fish	,nu	,xonsh	,zsh	}"
+complete -c rtx -n "__fish_seen_subcommand_from hook-env" -s s -l shell -d 'Shell type to generate script for' -r -f -a "{bash	,fish	,nu	,pwsh	,xonsh	,zsh	}"
 complete -c rtx -n "__fish_seen_subcommand_from hook-env" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
 default: 4' -r
 complete -c rtx -n "__fish_seen_subcommand_from hook-env" -l log-level -d 'Set the log output verbosity' -r
 complete -c rtx -n "__fish_seen_subcommand_from hook-env" -l status -d 'Show "rtx: <PLUGIN>@<VERSION>" message when changing directories'
+complete -c rtx -n "__fish_seen_subcommand_from hook-env" -l dry-run -d 'Show the env vars that would be added, changed, or removed instead of the shell code'
 complete -c rtx -n "__fish_seen_subcommand_from hook-env" -l debug -d 'Sets log level to debug'
 complete -c rtx -n "__fish_seen_subcommand_from hook-env" -l install-missing -d 'Automatically install missing tools'
 complete -c rtx -n "__fish_seen_subcommand_from hook-env" -s r -l raw -d 'Directly pipe stdin/stdout/stderr to user.
@@ -345,6 +366,8 @@ complete -c rtx -n "__fish_seen_subcommand_from install" -s j -l jobs -d 'Number
 default: 4' -r
 complete -c rtx -n "__fish_seen_subcommand_from install" -l log-level -d 'Set the log output verbosity' -r
 complete -c rtx -n "__fish_seen_subcommand_from install" -s f -l force -d 'Force reinstall even if already installed'
+complete -c rtx -n "__fish_seen_subcommand_from install" -l nearest -d 'Only install the tools declared in the nearest config file not the ones inherited from parent directories or the global config'
+complete -c rtx -n "__fish_seen_subcommand_from install" -l fail-fast -d 'Stop at the first tool that fails to install instead of installing the rest'
 complete -c rtx -n "__fish_seen_subcommand_from install" -s v -l verbose -d 'Show installation output'
 complete -c rtx -n "__fish_seen_subcommand_from install" -l debug -d 'Sets log level to debug'
 complete -c rtx -n "__fish_seen_subcommand_from install" -l install-missing -d 'Automatically install missing tools'
@@ -374,6 +397,16 @@ Sets --jobs=1'
 complete -c rtx -n "__fish_seen_subcommand_from link" -l trace -d 'Sets log level to trace'
 complete -c rtx -n "__fish_seen_subcommand_from link" -s v -l verbose -d 'Show installation output'
 complete -c rtx -n "__fish_seen_subcommand_from link" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rtx -n "__fish_seen_subcommand_from lint" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
+default: 4' -r
+complete -c rtx -n "__fish_seen_subcommand_from lint" -l log-level -d 'Set the log output verbosity' -r
+complete -c rtx -n "__fish_seen_subcommand_from lint" -l debug -d 'Sets log level to debug'
+complete -c rtx -n "__fish_seen_subcommand_from lint" -l install-missing -d 'Automatically install missing tools'
+complete -c rtx -n "__fish_seen_subcommand_from lint" -s r -l raw -d 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1'
+complete -c rtx -n "__fish_seen_subcommand_from lint" -l trace -d 'Sets log level to trace'
+complete -c rtx -n "__fish_seen_subcommand_from lint" -s v -l verbose -d 'Show installation output'
+complete -c rtx -n "__fish_seen_subcommand_from lint" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c rtx -n "__fish_seen_subcommand_from local" -l remove -d 'Remove the plugin(s) from .tool-versions' -r
 complete -c rtx -n "__fish_seen_subcommand_from local" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
 default: 4' -r
@@ -393,6 +426,8 @@ complete -c rtx -n "__fish_seen_subcommand_from local" -s v -l verbose -d 'Show
 complete -c rtx -n "__fish_seen_subcommand_from local" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c rtx -n "__fish_seen_subcommand_from ls" -s p -l plugin -d 'Only show tool versions from [PLUGIN]' -r
 complete -c rtx -n "__fish_seen_subcommand_from ls" -l prefix -d 'Display versions matching this prefix' -r
+complete -c rtx -n "__fish_seen_subcommand_from ls" -l source -d 'Only show tool versions selected by this kind of source
+e.g.: `--source env` for the ones set with RTX_NODE_VERSION' -r -f -a "{config	a config file: .tool-versions\, .rtx.toml\, or a legacy version file,env	an env var like RTX_NODE_VERSION,arg	a command line argument,stdin	a `.tool-versions` list piped to `rtx install -`}"
 complete -c rtx -n "__fish_seen_subcommand_from ls" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
 default: 4' -r
 complete -c rtx -n "__fish_seen_subcommand_from ls" -l log-level -d 'Set the log output verbosity' -r
@@ -411,6 +446,7 @@ complete -c rtx -n "__fish_seen_subcommand_from ls" -s h -l help -d 'Print help
 complete -c rtx -n "__fish_seen_subcommand_from ls-remote" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
 default: 4' -r
 complete -c rtx -n "__fish_seen_subcommand_from ls-remote" -l log-level -d 'Set the log output verbosity' -r
+complete -c rtx -n "__fish_seen_subcommand_from ls-remote" -l save-snapshot -d 'Save every version to .rtx/<TOOL>/versions-snapshot.json in the project instead of printing them'
 complete -c rtx -n "__fish_seen_subcommand_from ls-remote" -l debug -d 'Sets log level to debug'
 complete -c rtx -n "__fish_seen_subcommand_from ls-remote" -l install-missing -d 'Automatically install missing tools'
 complete -c rtx -n "__fish_seen_subcommand_from ls-remote" -s r -l raw -d 'Directly pipe stdin/stdout/stderr to user.
@@ -592,6 +628,7 @@ complete -c rtx -n "__fish_seen_subcommand_from settings; and __fish_seen_subcom
 complete -c rtx -n "__fish_seen_subcommand_from settings; and __fish_seen_subcommand_from ls" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
 default: 4' -r
 complete -c rtx -n "__fish_seen_subcommand_from settings; and __fish_seen_subcommand_from ls" -l log-level -d 'Set the log output verbosity' -r
+complete -c rtx -n "__fish_seen_subcommand_from settings; and __fish_seen_subcommand_from ls" -l origin -d 'Show where each setting came from: a config file, an env var, or the default'
 complete -c rtx -n "__fish_seen_subcommand_from settings; and __fish_seen_subcommand_from ls" -l debug -d 'Sets log level to debug'
 complete -c rtx -n "__fish_seen_subcommand_from settings; and __fish_seen_subcommand_from ls" -l install-missing -d 'Automatically install missing tools'
 complete -c rtx -n "__fish_seen_subcommand_from settings; and __fish_seen_subcommand_from ls" -s r -l raw -d 'Directly pipe stdin/stdout/stderr to user.
@@ -768,45 +805,47 @@ Sets --jobs=1'
 complete -c rtx -n "__fish_seen_subcommand_from render-help" -l trace -d 'Sets log level to trace'
 complete -c rtx -n "__fish_seen_subcommand_from render-help" -s v -l verbose -d 'Show installation output'
 complete -c rtx -n "__fish_seen_subcommand_from render-help" -s h -l help -d 'Print help'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "activate" -d 'Initializes rtx in the current shell'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "alias" -d 'Manage aliases'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "asdf" -d '[internal] simulates asdf for plugins that call "asdf" internally'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "bin-paths" -d 'List all the active runtime bin paths'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "cache" -d 'Manage the rtx cache'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "completion" -d 'Generate shell completions'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "current" -d 'Shows current active and installed runtime versions'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "deactivate" -d 'Disable rtx for current shell session'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "direnv" -d 'Output direnv function to use rtx inside direnv'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "doctor" -d 'Check rtx installation for possible problems.'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "env" -d 'Exports env vars to activate rtx a single time'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "env-vars" -d 'Manage environment variables'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "exec" -d 'Execute a command with tool(s) set'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "global" -d 'Sets/gets the global tool version(s)'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "hook-env" -d '[internal] called by activate hook to update env vars directory change'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "implode" -d 'Removes rtx CLI and all related data'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "install" -d 'Install a tool version'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "latest" -d 'Gets the latest available version for a plugin'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "link" -d 'Symlinks a tool version into rtx'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "local" -d 'Sets/gets tool version in local .tool-versions or .rtx.toml'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "ls" -d 'List installed and/or currently selected tool versions'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "ls-remote" -d 'List runtime versions available for install'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "outdated" -d '[experimental] Shows outdated tool versions'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "plugins" -d 'Manage plugins'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "prune" -d 'Delete unused versions of tools'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "reshim" -d 'rebuilds the shim farm'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "self-update" -d 'Updates rtx itself'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "settings" -d 'Manage settings'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "shell" -d 'Sets a tool version for the current shell session'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "sync" -d 'Add tool versions from external tools to rtx'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "trust" -d 'Marks a config file as trusted'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "uninstall" -d 'Removes runtime versions'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "upgrade" -d '[experimental] Upgrades outdated tool versions'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "use" -d 'Change the active version of a tool locally or globally.'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "version" -d 'Show rtx version'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "where" -d 'Display the installation path for a runtime'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "which" -d 'Shows the path that a bin name points to'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "render-help" -d 'internal command to generate markdown from help'
-complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "activate" -d 'Initializes rtx in the current shell'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "alias" -d 'Manage aliases'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "asdf" -d '[internal] simulates asdf for plugins that call "asdf" internally'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "bin-paths" -d 'List all the active runtime bin paths'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "cache" -d 'Manage the rtx cache'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "complete-versions" -d 'internal command used by shell completions to list versions of a tool'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "completion" -d 'Generate shell completions'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "current" -d 'Shows current active and installed runtime versions'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "deactivate" -d 'Disable rtx for current shell session'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "direnv" -d 'Output direnv function to use rtx inside direnv'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "doctor" -d 'Check rtx installation for possible problems.'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "env" -d 'Exports env vars to activate rtx a single time'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "env-vars" -d 'Manage environment variables'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "exec" -d 'Execute a command with tool(s) set'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "global" -d 'Sets/gets the global tool version(s)'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "hook-env" -d '[internal] called by activate hook to update env vars directory change'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "implode" -d 'Removes rtx CLI and all related data'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "install" -d 'Install a tool version'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "latest" -d 'Gets the latest available version for a plugin'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "link" -d 'Symlinks a tool version into rtx'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "lint" -d 'Check config files for problems'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "local" -d 'Sets/gets tool version in local .tool-versions or .rtx.toml'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "ls" -d 'List installed and/or currently selected tool versions'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "ls-remote" -d 'List runtime versions available for install'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "outdated" -d '[experimental] Shows outdated tool versions'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "plugins" -d 'Manage plugins'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "prune" -d 'Delete unused versions of tools'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "reshim" -d 'rebuilds the shim farm'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "self-update" -d 'Updates rtx itself'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "settings" -d 'Manage settings'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "shell" -d 'Sets a tool version for the current shell session'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "sync" -d 'Add tool versions from external tools to rtx'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "trust" -d 'Marks a config file as trusted'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "uninstall" -d 'Removes runtime versions'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "upgrade" -d '[experimental] Upgrades outdated tool versions'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "use" -d 'Change the active version of a tool locally or globally.'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "version" -d 'Show rtx version'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "where" -d 'Display the installation path for a runtime'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "which" -d 'Shows the path that a bin name points to'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "render-help" -d 'internal command to generate markdown from help'
+complete -c rtx -n "__fish_seen_subcommand_from help; and not __fish_seen_subcommand_from activate; and not __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from asdf; and not __fish_seen_subcommand_from bin-paths; and not __fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from complete-versions; and not __fish_seen_subcommand_from completion; and not __fish_seen_subcommand_from current; and not __fish_seen_subcommand_from deactivate; and not __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from doctor; and not __fish_seen_subcommand_from env; and not __fish_seen_subcommand_from env-vars; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from global; and not __fish_seen_subcommand_from hook-env; and not __fish_seen_subcommand_from implode; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from latest; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from lint; and not __fish_seen_subcommand_from local; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from outdated; and not __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from prune; and not __fish_seen_subcommand_from reshim; and not __fish_seen_subcommand_from self-update; and not __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from shell; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from trust; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from upgrade; and not __fish_seen_subcommand_from use; and not __fish_seen_subcommand_from version; and not __fish_seen_subcommand_from where; and not __fish_seen_subcommand_from which; and not __fish_seen_subcommand_from render-help; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from get; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from unset" -f -a "get" -d 'Show an alias for a plugin'
 complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from alias; and not __fish_seen_subcommand_from get; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from unset" -f -a "ls" -d 'List aliases
 Shows the aliases that can be specified.
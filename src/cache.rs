@@ -5,6 +5,8 @@ use std::io::{Read, Write};
 use std::path::PathBuf;
 use std::time::Duration;
 
+use crate::config::is_offline;
+use crate::file;
 use crate::file::{display_path, modified_duration};
 use color_eyre::eyre::Result;
 use flate2::read::ZlibDecoder;
@@ -50,13 +52,20 @@ where
         self
     }
 
+    /// always fetches instead of reading the cache file, it's still written
+    pub fn with_no_cache(mut self, no_cache: bool) -> Self {
+        self.no_cache = no_cache;
+        self
+    }
+
     pub fn get_or_try_init<F>(&self, fetch: F) -> Result<&T>
     where
         F: FnOnce() -> Result<T>,
     {
         let val = self.cache.get_or_try_init(|| {
             let path = &self.cache_file_path;
-            if !self.no_cache && self.is_fresh() {
+            // when offline stale data is better than none
+            if !self.no_cache && (self.is_fresh() || (is_offline() && path.exists())) {
                 match self.parse() {
                     Ok(val) => return Ok::<_, color_eyre::Report>(val),
                     Err(err) => {
@@ -73,7 +82,7 @@ where
         Ok(val)
     }
 
-    fn parse(&self) -> Result<T> {
+    pub fn parse(&self) -> Result<T> {
         let path = &self.cache_file_path;
         trace!("reading {}", display_path(path));
         let mut zlib = ZlibDecoder::new(File::open(path)?);
@@ -82,20 +91,22 @@ where
         Ok(rmp_serde::from_slice(&bytes)?)
     }
 
+    /// rtx processes started at the same time (e.g.: shells opened together) may write the same
+    /// cache, so it's written atomically and readers see either the old or the new contents
     pub fn write(&self, val: &T) -> Result<()> {
         let path = &self.cache_file_path;
         trace!("writing {}", display_path(path));
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
         }
-        let mut zlib = ZlibEncoder::new(File::create(path)?, Compression::fast());
+        let mut zlib = ZlibEncoder::new(Vec::new(), Compression::fast());
         zlib.write_all(&rmp_serde::to_vec_named(&val)?[..])?;
-
-        Ok(())
+        file::write_atomic(path, zlib.finish()?)
     }
 
-    #[allow(dead_code)]
-    pub fn clear(&self) -> Result<()> {
+    /// removes the cache file and forgets the value already loaded by this process
+    pub fn clear(&mut self) -> Result<()> {
+        self.cache.take();
         let path = &self.cache_file_path;
         trace!("clearing cache {}", path.display());
         if path.exists() {
@@ -138,11 +149,51 @@ mod tests {
     #[test]
     fn test_cache() {
         // does not fail with invalid path
-        let cache = CacheManager::new("/invalid:path/to/cache".into());
+        let mut cache = CacheManager::new("/invalid:path/to/cache".into());
         cache.clear().unwrap();
         let val = cache.get_or_try_init(|| Ok(1)).unwrap();
         assert_eq!(val, &1);
         let val = cache.get_or_try_init(|| Ok(2)).unwrap();
         assert_eq!(val, &1);
+        cache.clear().unwrap();
+        let val = cache.get_or_try_init(|| Ok(2)).unwrap();
+        assert_eq!(val, &2);
+    }
+
+    #[test]
+    fn test_concurrent_writes() {
+        let path = crate::env::temp_dir().join("rtx-test-cache-concurrent.msgpack.z");
+        let _ = fs::remove_file(&path);
+        let vals = std::thread::scope(|s| {
+            let threads = (0..2)
+                .map(|i| {
+                    let path = path.clone();
+                    s.spawn(move || {
+                        let cache = CacheManager::new(path);
+                        for _ in 0..50 {
+                            cache.write(&vec![i; 1000]).unwrap();
+                        }
+                        cache.get_or_try_init(|| Ok(vec![i; 1000])).unwrap().clone()
+                    })
+                })
+                .collect::<Vec<_>>();
+            threads
+                .into_iter()
+                .map(|t| t.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        for val in vals {
+            assert!(val == vec![0; 1000] || val == vec![1; 1000]);
+        }
+        let cache = CacheManager::<Vec<i32>>::new(path.clone());
+        let val = cache.parse().unwrap();
+        assert!(val == vec![0; 1000] || val == vec![1; 1000]);
+
+        // a corrupt cache is rebuilt
+        fs::write(&path, "partial").unwrap();
+        let cache = CacheManager::new(path.clone());
+        assert_eq!(cache.get_or_try_init(|| Ok(vec![2])).unwrap(), &vec![2]);
+        assert_eq!(cache.parse().unwrap(), vec![2]);
+        fs::remove_file(&path).unwrap();
     }
 }
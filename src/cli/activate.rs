@@ -6,6 +6,7 @@ use crate::dirs;
 
 use crate::env::RTX_EXE;
 use crate::file::touch_dir;
+use crate::hook_env;
 use crate::output::Output;
 use crate::shell::{get_shell, ShellType};
 
@@ -16,7 +17,8 @@ use crate::shell::{get_shell, ShellType};
 /// (e.g. ~/.zshrc, ~/.bashrc)
 ///
 /// This is only intended to be used in interactive sessions, not scripts.
-/// rtx is only capable of updating PATH when the prompt is displayed to the user.
+/// rtx sets PATH when it's activated and is only capable of updating it
+/// when the prompt is displayed to the user.
 /// For non-interactive use-cases, use shims instead.
 ///
 /// Typically this can be added with something like the following:
@@ -48,14 +50,29 @@ pub struct Activate {
 }
 
 impl Command for Activate {
-    fn run(self, _config: Config, out: &mut Output) -> Result<()> {
-        let shell = get_shell(self.shell_type.or(self.shell))
+    fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
+        let shell = self
+            .shell_type
+            .or(self.shell)
+            .or_else(ShellType::load)
             .expect("no shell provided, use `--shell=zsh`");
 
         // touch ROOT to allow hook-env to run
         let _ = touch_dir(&dirs::ROOT);
 
-        let output = shell.activate(&RTX_EXE, self.status);
+        let script = hook_env::load_toolset(&mut config)
+            .and_then(|ts| ts.activation_script(&config, shell, &RTX_EXE, self.status));
+        let output = match script {
+            Ok(script) => script,
+            Err(err) => {
+                // a broken config shouldn't break the shell, the hook still sets the env once
+                // it's fixed
+                warn!("failed to set the env, activating without it: {err:#}");
+                get_shell(Some(shell))
+                    .unwrap()
+                    .activate(&RTX_EXE, self.status)
+            }
+        };
         out.stdout.write(output);
 
         Ok(())
@@ -67,6 +84,7 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
   $ <bold>eval "$(rtx activate bash)"</bold>
   $ <bold>eval "$(rtx activate zsh)"</bold>
   $ <bold>rtx activate fish | source</bold>
+  $ <bold>rtx activate pwsh | Out-String | Invoke-Expression</bold>
   $ <bold>execx($(rtx activate xonsh))</bold>
 "#
 );
@@ -13,17 +13,29 @@ use crate::plugins::PluginName;
 ///
 ///   [alias.node]
 ///   lts = "20.0.0"
+///
+/// A trailing comment on an alias entry is shown alongside it:
+///
+///   [alias.node]
+///   lts = "18.0.0" # pinned for project X
 #[derive(Debug, clap::Args)]
 #[clap(visible_alias = "list", after_long_help = AFTER_LONG_HELP, verbatim_doc_comment)]
 pub struct AliasLs {
     /// Show aliases for <PLUGIN>
     #[clap(short, long)]
     pub plugin: Option<PluginName>,
+
+    /// Only show the aliases the plugins define, not the ones from config files
+    #[clap(long)]
+    pub builtin: bool,
 }
 
 impl Command for AliasLs {
     fn run(self, config: Config, out: &mut Output) -> Result<()> {
-        for (plugin_name, aliases) in config.get_all_aliases() {
+        if self.builtin {
+            return self.builtin(&config, out);
+        }
+        for (plugin_name, aliases) in config.get_all_aliases()? {
             if let Some(plugin) = &self.plugin {
                 if plugin_name != plugin {
                     continue;
@@ -35,6 +47,30 @@ impl Command for AliasLs {
                     // hide the nvm-style aliases so only asdf-style ones display
                     continue;
                 }
+                let comment = config
+                    .get_alias_comment(plugin_name, from)
+                    .map(|c| format!("# {c}"))
+                    .unwrap_or_default();
+                let line = format!("{:20} {:20} {:20} {}", plugin_name, from, to, comment);
+                rtxprintln!(out, "{}", line.trim_end());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl AliasLs {
+    fn builtin(&self, config: &Config, out: &mut Output) -> Result<()> {
+        for (plugin_name, tool) in &config.tools {
+            if let Some(plugin) = &self.plugin {
+                if plugin_name != plugin {
+                    continue;
+                }
+            }
+            for (from, to) in tool.list_aliases(&config.settings)? {
+                if plugin_name == "node" && from.starts_with("lts/") {
+                    continue;
+                }
                 rtxprintln!(out, "{:20} {:20} {}", plugin_name, from, to);
             }
         }
@@ -46,11 +82,16 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
     r#"<bold><underline>Examples:</underline></bold>
   $ <bold>rtx aliases</bold>
   node    lts-hydrogen   20.0.0
+
+  $ <bold>rtx alias ls --plugin node --builtin</bold>
+  node    lts            18
 "#
 );
 
 #[cfg(test)]
 mod tests {
+    use insta::assert_snapshot;
+
     use crate::assert_cli;
 
     #[test]
@@ -58,4 +99,13 @@ mod tests {
         let stdout = assert_cli!("aliases");
         assert!(stdout.contains("my/alias"));
     }
+
+    #[test]
+    fn test_alias_ls_builtin() {
+        let stdout = assert_cli!("alias", "ls", "--plugin", "tiny", "--builtin");
+        assert_snapshot!(stdout, @r###"
+        tiny                 lts                  3.1.0
+        tiny                 lts-prev             2.0.0
+        "###);
+    }
 }
@@ -45,6 +45,7 @@ impl Command for Alias {
     fn run(self, config: Config, out: &mut Output) -> Result<()> {
         let cmd = self.command.unwrap_or(Commands::Ls(ls::AliasLs {
             plugin: self.plugin,
+            builtin: false,
         }));
 
         cmd.run(config, out)
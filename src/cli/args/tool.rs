@@ -18,14 +18,14 @@ impl ToolArg {
     pub fn parse(input: &str) -> Self {
         match input.split_once('@') {
             Some((plugin, version)) => {
-                let plugin = unalias_plugin(plugin).to_string();
+                let plugin = unalias_plugin(plugin);
                 Self {
                     plugin: plugin.clone(),
                     tvr: Some(ToolVersionRequest::new(plugin, version)),
                 }
             }
             None => Self {
-                plugin: unalias_plugin(input).into(),
+                plugin: unalias_plugin(input),
                 tvr: None,
             },
         }
@@ -0,0 +1,43 @@
+use color_eyre::eyre::Result;
+
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::output::Output;
+use crate::plugins::unalias_plugin;
+
+/// internal command used by shell completions to list versions of a tool
+///
+/// prints installed and remote versions starting with the given prefix, newest first
+/// e.g.: `rtx complete-versions node@1` is used for `rtx install node@1<TAB>`
+#[derive(Debug, clap::Args)]
+#[clap(hide = true, verbatim_doc_comment)]
+pub struct CompleteVersions {
+    /// Tool and optional version prefix
+    /// e.g.: node@18
+    #[clap(value_name = "TOOL@PREFIX", verbatim_doc_comment)]
+    tool: String,
+}
+
+impl Command for CompleteVersions {
+    fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
+        let (plugin, prefix) = self.tool.split_once('@').unwrap_or((&self.tool, ""));
+        let plugin = unalias_plugin(plugin);
+        for version in config.complete_versions(&plugin, prefix) {
+            rtxprintln!(out, "{}@{}", plugin, version);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use crate::assert_cli;
+
+    #[test]
+    fn test_complete_versions() {
+        let stdout = assert_cli!("complete-versions", "dummy@1.1");
+        assert_snapshot!(stdout, @"dummy@1.1.0");
+    }
+}
@@ -1,4 +1,7 @@
+use std::path::PathBuf;
+
 use color_eyre::eyre::Result;
+use itertools::Itertools;
 
 use crate::cli::command::Command;
 
@@ -19,31 +22,69 @@ pub struct Current {
     /// e.g.: ruby, node
     #[clap()]
     plugin: Option<String>,
+
+    /// Show the versions as they were requested instead of resolving them
+    /// e.g.: `node lts` instead of `node 20.5.0`
+    #[clap(long)]
+    unresolved: bool,
+
+    /// Read the config files as they were at a git ref
+    /// e.g.: `--git-ref HEAD~5`
+    #[clap(long, value_name = "REF")]
+    git_ref: Option<String>,
+
+    /// Show the versions for a file or directory instead of the current directory
+    /// e.g.: `--path ~/src/myproject/index.js`
+    #[clap(long, value_name = "PATH", value_hint = clap::ValueHint::AnyPath, conflicts_with = "git_ref")]
+    path: Option<PathBuf>,
 }
 
 impl Command for Current {
     fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
-        let ts = ToolsetBuilder::new().build(&mut config)?;
+        let mut tsb = ToolsetBuilder::new();
+        if let Some(git_ref) = &self.git_ref {
+            tsb = tsb.with_git_ref(git_ref);
+        }
+        if let Some(path) = &self.path {
+            tsb = tsb.with_path(path);
+        }
+        let ts = tsb.build(&mut config)?;
+        if self.unresolved {
+            return self.unresolved(&ts, out);
+        }
         match &self.plugin {
             Some(plugin_name) => {
                 let plugin_name = unalias_plugin(plugin_name);
-                match config.tools.get(plugin_name) {
-                    Some(plugin) => self.one(&config, ts, out, plugin),
-                    None => {
-                        warn!("Plugin {} is not installed", plugin_name);
-                        Ok(())
-                    }
+                match config.tools.get(&plugin_name) {
+                    Some(plugin) => self.one(&config, ts, out, plugin)?,
+                    None => config
+                        .warnings
+                        .push(&plugin_name, "plugin is not installed"),
                 }
             }
-            None => self.all(&config, ts, out),
+            None => self.all(&config, ts, out)?,
         }
+        config.check_strict()
     }
 }
 
 impl Current {
+    fn unresolved(&self, ts: &Toolset, out: &mut Output) -> Result<()> {
+        let plugin = self.plugin.as_deref().map(unalias_plugin);
+        for (plugin_name, requests) in ts.unresolved() {
+            let versions = requests.iter().map(|tvr| tvr.version()).join(" ");
+            match &plugin {
+                Some(p) if p == plugin_name => rtxprintln!(out, "{versions}"),
+                Some(_) => {}
+                None => rtxprintln!(out, "{plugin_name} {versions}"),
+            }
+        }
+        Ok(())
+    }
+
     fn one(&self, config: &Config, ts: Toolset, out: &mut Output, tool: &Tool) -> Result<()> {
         if !tool.is_installed() {
-            warn!("Plugin {} is not installed", tool.name);
+            config.warnings.push(&tool.name, "plugin is not installed");
             return Ok(());
         }
         match ts
@@ -63,7 +104,7 @@ impl Current {
                 );
             }
             None => {
-                warn!("Plugin {} does not have a version set", tool.name);
+                config.warnings.push(&tool.name, "no version is set");
             }
         };
         Ok(())
@@ -76,11 +117,12 @@ impl Current {
             }
             for tv in versions {
                 if !plugin.is_version_installed(tv) {
-                    let source = ts.versions.get(&tv.plugin_name).unwrap().source.clone();
-                    warn!(
-                        "{}@{} is specified in {}, but not installed",
-                        tv.plugin_name, &tv.version, &source
+                    let source = &ts.versions.get(&tv.plugin_name).unwrap().source;
+                    let msg = format!(
+                        "{}@{} is specified but not installed",
+                        tv.plugin_name, tv.version
                     );
+                    config.warnings.push(source.to_string(), msg);
                 }
             }
             rtxprintln!(
@@ -113,6 +155,10 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
   # can output multiple versions
   $ <bold>rtx current python</bold>
   3.11.0 3.10.0
+
+  # shows aliases and prefixes before they're resolved
+  $ <bold>rtx current --unresolved node</bold>
+  lts
 "#
 );
 
@@ -120,6 +166,8 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
 mod tests {
     use std::env;
 
+    use insta::assert_snapshot;
+
     use crate::{assert_cli, assert_cli_snapshot};
 
     #[test]
@@ -132,6 +180,17 @@ mod tests {
         assert_cli_snapshot!("current", "tiny");
     }
 
+    #[test]
+    fn test_current_unresolved() {
+        let output = assert_cli!("current", "--unresolved");
+        assert_snapshot!(output, @r###"
+        tiny 3
+        dummy ref:master
+        "###);
+        let output = assert_cli!("current", "--unresolved", "tiny");
+        assert_snapshot!(output, @"3");
+    }
+
     #[test]
     fn test_current_missing() {
         assert_cli!("uninstall", "dummy@1.0.1");
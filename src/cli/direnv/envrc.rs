@@ -41,17 +41,7 @@ impl Command for Envrc {
         for cf in config.config_files.keys() {
             writeln!(file, "watch_file {}", cf.to_string_lossy())?;
         }
-        for (k, v) in ts.env(&config) {
-            writeln!(
-                file,
-                "export {}={}",
-                shell_escape::unix::escape(k.into()),
-                shell_escape::unix::escape(v.into()),
-            )?;
-        }
-        for path in ts.list_paths(&config).into_iter().rev() {
-            writeln!(file, "PATH_add {}", path.to_string_lossy())?;
-        }
+        write!(file, "{}", ts.envrc(&config)?)?;
 
         rtxprintln!(out, "{}", envrc_path.to_string_lossy());
         Ok(())
@@ -31,7 +31,7 @@ impl Command for DirenvExec {
             .build(&mut config)?;
         let mut cmd = env_cmd();
 
-        for (k, v) in ts.env_with_path(&config) {
+        for (k, v) in ts.env_with_path(&config)? {
             cmd = cmd.env(k, v);
         }
 
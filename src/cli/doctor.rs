@@ -8,7 +8,8 @@ use indenter::indented;
 use crate::build_time::built_info;
 use crate::cli::command::Command;
 use crate::cli::version::VERSION;
-use crate::config::Config;
+use crate::config::{Config, DoctorFinding, Severity};
+use crate::file::display_path;
 use crate::git::Git;
 use crate::output::Output;
 use crate::plugins::PluginType;
@@ -44,39 +45,48 @@ impl Command for Doctor {
             style("toolset:").bold(),
             indent(ts.to_string())
         );
+        rtxprintln!(
+            out,
+            "{}\n{}\n",
+            style("config hash:").bold(),
+            indent(config.content_hash(&ts)?)
+        );
 
-        let mut checks = Vec::new();
-        for plugin in config.tools.values() {
-            if !plugin.is_installed() {
-                checks.push(format!("plugin {} is not installed", &plugin.name));
-                continue;
-            }
+        let mut findings = config.doctor();
+        for warning in config.warnings.take() {
+            findings.push(DoctorFinding::new(Severity::Warning, warning.to_string()));
         }
-
         if let Some(latest) = cli::version::check_for_new_version(duration::HOURLY) {
-            checks.push(format!(
+            let msg = format!(
                 "new rtx version {} available, currently on {}",
                 latest,
                 env!("CARGO_PKG_VERSION")
-            ));
+            );
+            findings.push(DoctorFinding::new(Severity::Warning, msg));
         }
 
         if !config.is_activated() {
             let cmd = style("rtx activate").yellow().for_stderr();
-            checks.push(format!(
-                "rtx is not activated, run `{cmd}` for setup instructions"
-            ));
+            let msg = format!("rtx is not activated, run `{cmd}` for setup instructions");
+            findings.push(DoctorFinding::new(Severity::Warning, msg));
         }
 
-        if checks.is_empty() {
+        // info findings are worth showing but aren't problems
+        let problems = findings
+            .iter()
+            .filter(|f| f.severity > Severity::Info)
+            .count();
+        if problems == 0 {
             rtxprintln!(out, "No problems found");
         } else {
-            let checks_plural = if checks.len() == 1 { "" } else { "s" };
-            let summary = format!("{} problem{checks_plural} found:", checks.len());
+            let plural = if problems == 1 { "" } else { "s" };
+            let summary = format!("{problems} problem{plural} found:");
             rtxprintln!(out, "{}", style(summary).red().bold());
-            for check in &checks {
-                rtxprintln!(out, "{}\n", check);
-            }
+        }
+        for finding in &findings {
+            rtxprintln!(out, "{}\n", finding);
+        }
+        if problems > 0 {
             exit(1);
         }
 
@@ -109,6 +119,9 @@ fn render_config_files(config: &Config) -> String {
     for f in config.config_files.keys().rev() {
         s.push_str(&format!("  {}\n", f.display()));
     }
+    if let Some(name) = config.project_name() {
+        s.push_str(&format!("{} {name}\n", style("project:").bold()));
+    }
     s
 }
 
@@ -138,6 +151,9 @@ fn render_plugins(config: &Config) -> String {
             PluginType::Core => format!("  {padded_name} (core)\n"),
         };
         s.push_str(&si);
+        if let Some(dir) = p.custom_installs_path() {
+            s.push_str(&format!("    installs: {}\n", display_path(&dir)));
+        }
     }
     s
 }
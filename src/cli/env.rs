@@ -1,11 +1,15 @@
+use std::path::PathBuf;
+
 use color_eyre::eyre::Result;
 
 use crate::cli::args::tool::{ToolArg, ToolArgParser};
 use crate::cli::command::Command;
 use crate::config::Config;
+use crate::dirs;
+use crate::file::display_path;
 use crate::output::Output;
 use crate::shell::{get_shell, ShellType};
-use crate::toolset::{Toolset, ToolsetBuilder};
+use crate::toolset::{env_diff_for_paths, Toolset, ToolsetBuilder};
 
 /// Exports env vars to activate rtx a single time
 ///
@@ -25,10 +29,31 @@ pub struct Env {
     /// Output in JSON format
     #[clap(long, visible_short_alias = 'J', overrides_with = "shell")]
     json: bool,
+
+    /// Show the value each config file sets for the env vars from `[env]`
+    ///
+    /// Closest config file first, values that a closer file replaces are marked as overridden.
+    #[clap(long, verbatim_doc_comment, conflicts_with_all = ["shell", "json", "tool"])]
+    layers: bool,
+
+    /// Show the env vars that change when changing from the current directory to DIR
+    ///
+    /// Nothing is installed, only tool versions that are already installed are used.
+    #[clap(long, value_name = "DIR", verbatim_doc_comment, conflicts_with_all = ["shell", "json", "tool", "layers"])]
+    diff: Option<PathBuf>,
 }
 
 impl Command for Env {
     fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
+        if self.layers {
+            return self.output_layers(config, out);
+        }
+        if let Some(dir) = &self.diff {
+            for change in env_diff_for_paths(&mut config, &dirs::CURRENT, dir)? {
+                rtxprintln!(out, "{change}");
+            }
+            return Ok(());
+        }
         let ts = ToolsetBuilder::new()
             .with_install_missing()
             .with_args(&self.tool)
@@ -42,8 +67,23 @@ impl Command for Env {
 }
 
 impl Env {
+    fn output_layers(&self, config: Config, out: &mut Output) -> Result<()> {
+        for (k, layers) in config.env_layers() {
+            for layer in layers {
+                let overridden = if layer.overridden {
+                    " (overridden)"
+                } else {
+                    ""
+                };
+                let path = display_path(&layer.path);
+                rtxprintln!(out, "{k}={} {path}{overridden}", layer.value);
+            }
+        }
+        Ok(())
+    }
+
     fn output_json(&self, config: Config, out: &mut Output, ts: Toolset) -> Result<()> {
-        let env = ts.env_with_path(&config);
+        let env = ts.env_with_path(&config)?;
         rtxprintln!(out, "{}", serde_json::to_string_pretty(&env)?);
         Ok(())
     }
@@ -51,7 +91,7 @@ impl Env {
     fn output_shell(&self, config: Config, out: &mut Output, ts: Toolset) -> Result<()> {
         let default_shell = get_shell(Some(ShellType::Bash)).unwrap();
         let shell = get_shell(self.shell).unwrap_or(default_shell);
-        for (k, v) in ts.env_with_path(&config) {
+        for (k, v) in ts.env_with_path(&config)? {
             let k = k.to_string();
             let v = v.to_string();
             rtxprint!(out, "{}", shell.set_env(&k, &v));
@@ -66,17 +106,30 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
   $ <bold>eval "$(rtx env -s zsh)"</bold>
   $ <bold>rtx env -s fish | source</bold>
   $ <bold>execx($(rtx env -s xonsh))</bold>
+
+  $ <bold>rtx env --layers</bold>
+  NODE_ENV=production ~/myproject/.rtx.toml
+  NODE_ENV=development ~/.config/rtx/config.toml (overridden)
+
+  $ <bold>rtx env --diff ~/other-project</bold>
+  + NODE_ENV=production
+  ~ PATH
+      + ~/.local/share/rtx/installs/node/20.0.0/bin
+      - ~/.local/share/rtx/installs/node/18.0.0/bin
 "#
 );
 
 #[cfg(test)]
 mod tests {
-    use std::env;
+    use std::{env, fs};
 
+    use insta::assert_snapshot;
     use pretty_assertions::assert_str_eq;
 
     use crate::cli::tests::grep;
+    use crate::config::{default_config_filename, default_tool_versions_filename};
     use crate::dirs;
+    use crate::test::replace_path;
     use crate::{assert_cli, assert_cli_snapshot};
 
     #[test]
@@ -133,4 +186,17 @@ mod tests {
     fn test_env_json() {
         assert_cli_snapshot!("env", "-J");
     }
+
+    #[test]
+    fn test_env_diff() {
+        let dir = env::temp_dir().join("rtx-test-env-diff");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(default_tool_versions_filename()), "tiny system\n").unwrap();
+        let rtx_toml = "[env]\nRTX_TEST_ENV_DIFF = \"1\"\n";
+        fs::write(dir.join(default_config_filename()), rtx_toml).unwrap();
+        let stdout = assert_cli!("env", "--diff", dir.to_str().unwrap());
+        assert_snapshot!(replace_path(&stdout));
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
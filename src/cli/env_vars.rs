@@ -5,7 +5,6 @@ use crate::config::config_file::rtx_toml::RtxToml;
 use crate::config::config_file::{self, ConfigFile};
 use crate::config::Config;
 use crate::dirs;
-use crate::env::RTX_DEFAULT_CONFIG_FILENAME;
 use crate::output::Output;
 
 use super::args::env_var::{EnvVarArg, EnvVarArgParser};
@@ -13,13 +12,13 @@ use super::args::env_var::{EnvVarArg, EnvVarArgParser};
 /// Manage environment variables
 ///
 /// By default this command modifies ".rtx.toml" in the current directory.
-/// You can specify the file name by either setting the RTX_DEFAULT_CONFIG_FILENAME environment variable, or by using the --file option.
+/// You can specify the file name by either setting the default_config_filename setting, the RTX_DEFAULT_CONFIG_FILENAME environment variable, or by using the --file option.
 #[derive(Debug, clap::Args)]
 #[clap(verbatim_doc_comment)]
 pub struct EnvVars {
     /// The TOML file to update
     ///
-    /// Defaults to RTX_DEFAULT_CONFIG_FILENAME environment variable, or ".rtx.toml".
+    /// Defaults to the default_config_filename setting, or ".rtx.toml".
     #[clap(long, verbatim_doc_comment, required = false)]
     file: Option<String>,
 
@@ -39,7 +38,7 @@ impl Command for EnvVars {
     fn run(self, config: Config, _out: &mut Output) -> Result<()> {
         let filename = self
             .file
-            .unwrap_or_else(|| RTX_DEFAULT_CONFIG_FILENAME.to_string());
+            .unwrap_or_else(|| config.settings.default_config_filename.clone());
 
         let mut rtx_toml = get_rtx_toml(&config, filename.as_str())?;
 
@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::ffi::{OsStr, OsString};
 use std::path::PathBuf;
 
@@ -14,7 +14,8 @@ use crate::config::Config;
 use crate::config::MissingRuntimeBehavior::Ignore;
 use crate::env;
 use crate::output::Output;
-use crate::toolset::ToolsetBuilder;
+use crate::plugins::unalias_plugin;
+use crate::toolset::{ResolvePolicy, ToolsetBuilder};
 
 /// Execute a command with tool(s) set
 ///
@@ -44,34 +45,62 @@ pub struct Exec {
     /// Change to this directory before executing the command
     #[clap(visible_short_alias = 'C', value_hint = ValueHint::DirPath, long)]
     pub cd: Option<PathBuf>,
+
+    /// Only set the environment and PATH for these plugin(s)
+    /// other tools in the toolset are not added to PATH
+    #[clap(long, value_name = "PLUGIN")]
+    pub only: Vec<String>,
+
+    /// What to use when a version isn't installed
+    /// e.g.: `--resolve-policy fallback-to-installed` uses node@20.1.0 for node@20 if it's
+    /// installed instead of installing node@20.5.0
+    #[clap(long, value_name = "POLICY", default_value = "strict")]
+    pub resolve_policy: ResolvePolicy,
 }
 
 impl Command for Exec {
     fn run(self, mut config: Config, _out: &mut Output) -> Result<()> {
-        let ts = ToolsetBuilder::new()
+        let mut ts = ToolsetBuilder::new()
             .with_args(&self.tool)
             .with_install_missing()
+            .with_resolve_policy(self.resolve_policy)
             .build(&mut config)?;
+        if !self.only.is_empty() {
+            let only = self.only.iter().map(|p| unalias_plugin(p));
+            ts.retain_plugins(&only.collect())?;
+        }
+        ts.check_version_policy(&config)?;
         let (program, args) = parse_command(&env::SHELL, &self.command, &self.c);
-        let mut env = ts.env_with_path(&config);
+        let mut env = ts.env_with_path(&config)?;
         if config.settings.missing_runtime_behavior != Ignore {
             // prevent rtx from auto-installing inside a shim
             env.insert("RTX_MISSING_RUNTIME_BEHAVIOR".into(), "warn".into());
         }
 
-        self.exec(program, args, env)
+        // exec replaces the process so the warnings have to be shown before
+        config.warnings.render();
+        self.exec(program, args, env, &config.env_remove)
     }
 }
 
 impl Exec {
     #[cfg(not(test))]
-    fn exec<T, U, E>(&self, program: T, args: U, env: BTreeMap<E, E>) -> Result<()>
+    fn exec<T, U, E>(
+        &self,
+        program: T,
+        args: U,
+        env: BTreeMap<E, E>,
+        env_remove: &BTreeSet<String>,
+    ) -> Result<()>
     where
         T: IntoExecutablePath,
         U: IntoIterator,
         U::Item: Into<OsString>,
         E: AsRef<OsStr>,
     {
+        for k in env_remove {
+            env::remove_var(k);
+        }
         for (k, v) in env.iter() {
             env::set_var(k, v);
         }
@@ -85,7 +114,13 @@ impl Exec {
     }
 
     #[cfg(test)]
-    fn exec<T, U, E>(&self, program: T, args: U, env: BTreeMap<E, E>) -> Result<()>
+    fn exec<T, U, E>(
+        &self,
+        program: T,
+        args: U,
+        env: BTreeMap<E, E>,
+        env_remove: &BTreeSet<String>,
+    ) -> Result<()>
     where
         T: IntoExecutablePath,
         U: IntoIterator,
@@ -96,6 +131,9 @@ impl Exec {
         if let Some(cd) = &self.cd {
             cmd = cmd.dir(cd);
         }
+        for k in env_remove {
+            cmd = cmd.env_remove(k);
+        }
         for (k, v) in env.iter() {
             cmd = cmd.env(k, v);
         }
@@ -130,6 +168,9 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
   # Specify command as a string:
   $ <bold>rtx exec node@20 python@3.11 --command "node -v && python -V"</bold>
 
+  # Only add node to PATH, not the other tools in the toolset:
+  $ <bold>rtx x --only node -- node ./app.js</bold>
+
   # Run a command in a different directory:
   $ <bold>rtx x -C /path/to/project node@20 -- node ./app.js</bold>
 "#
@@ -137,8 +178,10 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
 
 #[cfg(test)]
 mod tests {
-    use crate::assert_cli;
+    use insta::assert_display_snapshot;
+
     use crate::cli::tests::cli_run;
+    use crate::{assert_cli, assert_cli_err, dirs};
 
     #[test]
     fn test_exec_ok() {
@@ -156,6 +199,27 @@ mod tests {
         .unwrap_err();
     }
 
+    #[test]
+    fn test_exec_only() {
+        assert_cli!("exec", "--only", "tiny", "--", "echo");
+        let err = assert_cli_err!("exec", "--only", "nope", "--", "echo");
+        assert_display_snapshot!(err, @"nope has no version set");
+    }
+
+    #[test]
+    fn test_exec_resolve_policy() {
+        assert_cli!(
+            "exec",
+            "--resolve-policy",
+            "fallback-to-latest",
+            "tiny@2.0",
+            "--",
+            "echo"
+        );
+        // no 2.0.x is installed so 2.1.0 is used instead of installing 2.0.1
+        assert!(!dirs::INSTALLS.join("tiny/2.0.1").exists());
+    }
+
     #[test]
     fn test_exec_cd() {
         assert_cli!("exec", "-C", "/tmp", "--", "pwd");
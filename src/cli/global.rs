@@ -5,7 +5,7 @@ use color_eyre::eyre::Result;
 use crate::cli::args::tool::{ToolArg, ToolArgParser};
 use crate::cli::command::Command;
 use crate::cli::local::local;
-use crate::config::Config;
+use crate::config::{default_tool_versions_filename, Config};
 use crate::output::Output;
 use crate::plugins::PluginName;
 use crate::{dirs, env};
@@ -69,7 +69,7 @@ fn global_file() -> PathBuf {
         if *env::RTX_USE_TOML {
             dirs::CONFIG.join("config.toml")
         } else {
-            dirs::HOME.join(env::RTX_DEFAULT_TOOL_VERSIONS_FILENAME.as_str())
+            dirs::HOME.join(default_tool_versions_filename())
         }
     })
 }
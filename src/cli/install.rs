@@ -1,4 +1,7 @@
+use std::io;
+
 use color_eyre::eyre::Result;
+use itertools::Itertools;
 
 use crate::cli::args::tool::{ToolArg, ToolArgParser};
 use crate::cli::command::Command;
@@ -7,9 +10,7 @@ use crate::config::MissingRuntimeBehavior::AutoInstall;
 
 use crate::output::Output;
 
-use crate::toolset::{
-    ToolVersion, ToolVersionOptions, ToolVersionRequest, Toolset, ToolsetBuilder,
-};
+use crate::toolset::{ToolSource, ToolVersion, Toolset, ToolsetBuilder};
 use crate::ui::multi_progress_report::MultiProgressReport;
 
 /// Install a tool version
@@ -20,6 +21,8 @@ use crate::ui::multi_progress_report::MultiProgressReport;
 /// Or you can call a tool version explicitly with `rtx exec <TOOL>@<VERSION> -- <COMMAND>`.
 ///
 /// Tools will be installed in parallel. To disable, set `--jobs=1` or `RTX_JOBS=1`
+///
+/// With `-` as the tool, the versions are read from stdin in the `.tool-versions` format.
 #[derive(Debug, clap::Args)]
 #[clap(visible_alias = "i", verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
 pub struct Install {
@@ -32,6 +35,15 @@ pub struct Install {
     #[clap(long, short, requires = "tool")]
     force: bool,
 
+    /// Only install the tools declared in the nearest config file
+    /// not the ones inherited from parent directories or the global config
+    #[clap(long, conflicts_with = "tool")]
+    nearest: bool,
+
+    /// Stop at the first tool that fails to install instead of installing the rest
+    #[clap(long)]
+    fail_fast: bool,
+
     /// Show installation output
     #[clap(long, short, action = clap::ArgAction::Count)]
     verbose: u8,
@@ -42,6 +54,7 @@ impl Command for Install {
         config.settings.missing_runtime_behavior = AutoInstall;
 
         match &self.tool {
+            Some(runtime) if is_stdin(runtime) => self.install_stdin(config)?,
             Some(runtime) => self.install_runtimes(config, runtime)?,
             None => self.install_missing_runtimes(config)?,
         }
@@ -53,18 +66,23 @@ impl Command for Install {
 impl Install {
     fn install_runtimes(&self, mut config: Config, runtimes: &[ToolArg]) -> Result<()> {
         let mpr = MultiProgressReport::new(config.show_progress_bars());
+        let runtimes = ToolArg::double_tool_condition(runtimes);
         let mut ts = ToolsetBuilder::new()
+            .with_fail_fast(self.fail_fast)
+            .with_args(&runtimes)
             .with_latest_versions()
             .build(&mut config)?;
-        let tool_versions = self.get_requested_tool_versions(&mut config, &ts, runtimes, &mpr)?;
+        let tool_versions = self.get_requested_tool_versions(&mut config, &ts, &runtimes, &mpr)?;
         if tool_versions.is_empty() {
             warn!("no runtimes to install");
             warn!("specify a version with `rtx install <PLUGIN>@<VERSION>`");
             return Ok(());
         }
-        ts.install_versions(&mut config, tool_versions, &mpr, self.force)
+        ts.install_versions(&mut config, tool_versions, &mpr, self.force)?;
+        Ok(())
     }
 
+    /// the toolset already has the versions from the args, or from config for bare plugin names
     fn get_requested_tool_versions(
         &self,
         config: &mut Config,
@@ -72,38 +90,14 @@ impl Install {
         runtimes: &[ToolArg],
         mpr: &MultiProgressReport,
     ) -> Result<Vec<ToolVersion>> {
-        let mut requests = vec![];
-        for runtime in ToolArg::double_tool_condition(runtimes) {
-            let default_opts = ToolVersionOptions::new();
-            match runtime.tvr {
-                Some(tv) => requests.push((runtime.plugin, tv, default_opts.clone())),
-                None => {
-                    if runtime.tvr.is_none() {
-                        match ts.versions.get(&runtime.plugin) {
-                            Some(tvl) => {
-                                for (tvr, opts) in &tvl.requests {
-                                    requests.push((
-                                        runtime.plugin.clone(),
-                                        tvr.clone(),
-                                        opts.clone(),
-                                    ));
-                                }
-                            }
-                            None => {
-                                let tvr = ToolVersionRequest::Version(
-                                    runtime.plugin.clone(),
-                                    "latest".into(),
-                                );
-                                requests.push((runtime.plugin, tvr, default_opts.clone()));
-                            }
-                        }
-                    }
-                }
-            }
-        }
+        let plugins = runtimes.iter().map(|r| &r.plugin).unique();
+        let requests = plugins
+            .filter_map(|p| ts.versions.get(p))
+            .flat_map(|tvl| tvl.requests.iter().map(|r| (&tvl.plugin_name, r)))
+            .collect_vec();
         let mut tool_versions = vec![];
-        for (plugin_name, tvr, opts) in requests {
-            let plugin = config.get_or_create_tool(&plugin_name);
+        for (plugin_name, (tvr, opts)) in requests {
+            let plugin = config.get_or_create_tool(plugin_name);
             if !plugin.is_installed() {
                 let mut pr = mpr.add();
                 if let Err(err) = plugin.install(config, &mut pr, false) {
@@ -111,32 +105,71 @@ impl Install {
                     return Err(err)?;
                 }
             }
-            let tv = tvr.resolve(config, &plugin, opts, ts.latest_versions)?;
+            let tv = tvr.resolve(config, &plugin, opts.clone(), ts.latest_versions)?;
             tool_versions.push(tv);
         }
         Ok(tool_versions)
     }
 
+    /// installs the versions in `.tool-versions` contents piped to stdin, not the ones in config
+    fn install_stdin(&self, mut config: Config) -> Result<()> {
+        let contents = io::read_to_string(io::stdin())?;
+        let mut ts = ToolsetBuilder::new()
+            .with_fail_fast(self.fail_fast)
+            .with_tool_versions(&contents, ToolSource::Stdin)
+            .build(&mut config)?;
+        ts.versions
+            .retain(|_, tvl| matches!(tvl.source, ToolSource::Stdin));
+        if ts.list_missing_versions(&config, None).is_empty() {
+            warn!("no runtimes to install");
+        }
+        let mpr = MultiProgressReport::new(config.show_progress_bars());
+        ts.install_missing(&mut config, mpr, None)?;
+        Ok(())
+    }
+
     fn install_missing_runtimes(&self, mut config: Config) -> Result<()> {
         let mut ts = ToolsetBuilder::new()
+            .with_fail_fast(self.fail_fast)
             .with_latest_versions()
             .build(&mut config)?;
-        if ts.list_missing_versions(&config).is_empty() {
+        let scope = match self.nearest {
+            true => match config.config_files.keys().next() {
+                Some(path) => Some(path.clone()),
+                None => {
+                    warn!("no config file found");
+                    return Ok(());
+                }
+            },
+            false => None,
+        };
+        if ts
+            .list_missing_versions(&config, scope.as_deref())
+            .is_empty()
+        {
             warn!("no runtimes to install");
         }
         let mpr = MultiProgressReport::new(config.show_progress_bars());
-        ts.install_missing(&mut config, mpr)?;
+        ts.install_missing(&mut config, mpr, scope.as_deref())?;
 
         Ok(())
     }
 }
 
+/// `rtx install -`
+fn is_stdin(runtimes: &[ToolArg]) -> bool {
+    matches!(runtimes, [arg] if arg.plugin == "-" && arg.tvr.is_none())
+}
+
 static AFTER_LONG_HELP: &str = color_print::cstr!(
     r#"<bold><underline>Examples:</underline></bold>
   $ <bold>rtx install node@20.0.0</bold>  # install specific node version
   $ <bold>rtx install node@20</bold>      # install fuzzy node version
   $ <bold>rtx install node</bold>         # install version specified in .tool-versions or .rtx.toml
   $ <bold>rtx install</bold>                # installs everything specified in .tool-versions or .rtx.toml
+  $ <bold>rtx install --nearest</bold>      # only installs what the closest config file specifies
+  $ <bold>rtx install --fail-fast</bold>    # stops at the first tool that fails, e.g.: in CI
+  $ <bold>echo "node 20" | rtx install -</bold> # installs the versions piped in as .tool-versions
 "#
 );
 
@@ -174,6 +207,16 @@ mod tests {
         assert_cli!("global", "--unset", "dummy");
     }
 
+    #[test]
+    fn test_install_nearest() {
+        // dummy is only in the parent directory's config file
+        assert_cli!("uninstall", "dummy@ref:master");
+        assert_cli!("install", "--nearest");
+        assert!(!dirs::INSTALLS.join("dummy/ref-master").exists());
+        assert_cli!("install");
+        assert!(dirs::INSTALLS.join("dummy/ref-master").exists());
+    }
+
     #[test]
     fn test_install_nothing() {
         // this doesn't do anything since dummy isn't specified
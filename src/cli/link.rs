@@ -9,9 +9,9 @@ use path_absolutize::Absolutize;
 use crate::cli::args::tool::{ToolArg, ToolArgParser};
 use crate::cli::command::Command;
 use crate::config::Config;
-use crate::dirs;
 use crate::file::{make_symlink, remove_all};
 use crate::output::Output;
+use crate::tool::installs_path;
 
 /// Symlinks a tool version into rtx
 ///
@@ -52,7 +52,7 @@ impl Command for Link {
                 style(path.to_string_lossy()).cyan().for_stderr()
             );
         }
-        let target = dirs::INSTALLS.join(&self.tool.plugin).join(version);
+        let target = installs_path(&self.tool.plugin).join(version);
         if target.exists() {
             if self.force {
                 remove_all(&target)?;
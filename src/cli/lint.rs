@@ -0,0 +1,74 @@
+use std::path::PathBuf;
+
+use color_eyre::eyre::{eyre, Result};
+
+use crate::cli::command::Command;
+use crate::config::{config_file, Config};
+use crate::file::display_path;
+use crate::output::Output;
+
+/// Check config files for problems
+///
+/// .tool-versions files are checked for unknown plugins, invalid versions, and plugins listed
+/// more than once. Other config files are checked for whether they can be parsed.
+/// This does not use the network so it's safe to run in a pre-commit hook.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
+pub struct Lint {
+    /// Config files to check
+    /// defaults to the config files used in the current directory
+    #[clap(value_hint = clap::ValueHint::FilePath)]
+    files: Vec<PathBuf>,
+}
+
+impl Command for Lint {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        let files = match self.files.is_empty() {
+            true => config.config_files.keys().cloned().collect(),
+            false => self.files,
+        };
+        let mut problems = 0;
+        for path in files {
+            for issue in config_file::validate(&path, &config)? {
+                rtxprintln!(out, "{}: {}", display_path(&path), issue);
+                problems += 1;
+            }
+        }
+        match problems {
+            0 => Ok(()),
+            1 => Err(eyre!("1 problem found")),
+            n => Err(eyre!("{n} problems found")),
+        }
+    }
+}
+
+static AFTER_LONG_HELP: &str = color_print::cstr!(
+    r#"<bold><underline>Examples:</underline></bold>
+  $ <bold>rtx lint .tool-versions</bold>
+  .tool-versions: line 2: unknown plugin nodee
+"#
+);
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use insta::assert_display_snapshot;
+
+    use crate::{assert_cli, assert_cli_err, dirs};
+
+    #[test]
+    fn test_lint() {
+        assert_cli!("lint");
+
+        let path = dirs::CURRENT.join("lint.tool-versions");
+        fs::write(
+            &path,
+            "tiny 2 >=1 <<2 # comment\nnope 1.0\ntiny: 3\ndummy\nnodejs prefix: ref:main\n",
+        )
+        .unwrap();
+        let err = assert_cli_err!("lint", path.to_string_lossy().to_string());
+        assert_display_snapshot!(err, @"5 problems found");
+        fs::remove_file(&path).unwrap();
+    }
+}
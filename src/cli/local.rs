@@ -5,8 +5,7 @@ use color_eyre::eyre::{eyre, ContextCompat, Result};
 use crate::cli::args::tool::{ToolArg, ToolArgParser};
 use crate::cli::command::Command;
 use crate::config::config_file::ConfigFile;
-use crate::config::{config_file, Config};
-use crate::env::{RTX_DEFAULT_CONFIG_FILENAME, RTX_DEFAULT_TOOL_VERSIONS_FILENAME};
+use crate::config::{config_file, default_config_filename, default_tool_versions_filename, Config};
 use crate::output::Output;
 use crate::plugins::PluginName;
 use crate::ui::multi_progress_report::MultiProgressReport;
@@ -74,19 +73,20 @@ impl Command for Local {
 }
 
 fn get_path() -> PathBuf {
-    let rtx_toml = dirs::CURRENT.join(RTX_DEFAULT_CONFIG_FILENAME.as_str());
+    let rtx_toml = dirs::CURRENT.join(default_config_filename());
     if *env::RTX_USE_TOML || rtx_toml.exists() {
         rtx_toml
     } else {
-        dirs::CURRENT.join(RTX_DEFAULT_TOOL_VERSIONS_FILENAME.as_str())
+        dirs::CURRENT.join(default_tool_versions_filename())
     }
 }
 
 pub fn get_parent_path() -> Result<PathBuf> {
-    let mut filenames = vec![RTX_DEFAULT_CONFIG_FILENAME.as_str()];
+    let mut filenames = vec![default_config_filename()];
     if !*env::RTX_USE_TOML {
-        filenames.push(RTX_DEFAULT_TOOL_VERSIONS_FILENAME.as_str());
+        filenames.push(default_tool_versions_filename());
     }
+    let filenames = filenames.iter().map(|f| f.as_str()).collect::<Vec<_>>();
     file::find_up(&dirs::CURRENT, &filenames)
         .with_context(|| eyre!("no {} file found", filenames.join(" or "),))
 }
@@ -143,9 +143,9 @@ fn install_missing_runtimes(config: &mut Config, cf: &dyn ConfigFile) -> Result<
     let mut ts = cf.to_toolset().clone();
     ts.latest_versions = true;
     ts.resolve(config);
-    if !ts.list_missing_versions(config).is_empty() {
+    if !ts.list_missing_versions(config, None).is_empty() {
         let mpr = MultiProgressReport::new(config.show_progress_bars());
-        ts.install_missing(config, mpr)?;
+        ts.install_missing(config, mpr, None)?;
     }
 
     Ok(())
@@ -17,7 +17,7 @@ use crate::errors::Error::PluginNotInstalled;
 use crate::output::Output;
 use crate::plugins::{unalias_plugin, PluginName};
 use crate::tool::Tool;
-use crate::toolset::{ToolSource, ToolVersion, ToolsetBuilder};
+use crate::toolset::{ResolvedFrom, ToolSource, ToolSourceKind, ToolVersion, ToolsetBuilder};
 
 /// List installed and/or currently selected tool versions
 #[derive(Debug, clap::Args)]
@@ -54,6 +54,11 @@ pub struct Ls {
     /// Display versions matching this prefix
     #[clap(long)]
     prefix: Option<String>,
+
+    /// Only show tool versions selected by this kind of source
+    /// e.g.: `--source env` for the ones set with RTX_NODE_VERSION
+    #[clap(long, value_name = "SOURCE", verbatim_doc_comment)]
+    source: Option<ToolSourceKind>,
 }
 
 impl Command for Ls {
@@ -66,7 +71,7 @@ impl Command for Ls {
         self.verify_plugin(&config)?;
 
         let mut runtimes = self.get_runtime_list(&mut config)?;
-        if self.current {
+        if self.current || self.source.is_some() {
             runtimes.retain(|(_, _, source)| source.is_some());
         }
         if self.installed {
@@ -103,6 +108,8 @@ struct JSONToolVersion {
     source: Option<IndexMap<String, String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     symlinked_to: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resolved_from: Option<ResolvedFrom>,
 }
 
 impl Ls {
@@ -129,6 +136,7 @@ impl Ls {
                 .map(|(p, tv, source)| JSONToolVersion {
                     symlinked_to: p.symlink_path(&tv),
                     install_path: tv.install_path(),
+                    resolved_from: tv.resolved_from,
                     version: tv.version,
                     requested_version: source.as_ref().map(|_| tv.request.version()),
                     source: source.map(|source| source.as_json()),
@@ -182,7 +190,13 @@ impl Ls {
                 } else {
                     VersionStatus::Inactive(tv.version)
                 };
-                let request = source.map(|source| (source.to_string(), tv.request.version()));
+                let request = source.map(|source| {
+                    let source = match config.settings.verbose {
+                        true => format!("{:#}", source),
+                        false => source.to_string(),
+                    };
+                    (source, tv.request.version())
+                });
                 (plugin, version, request)
             })
             .collect::<Vec<_>>();
@@ -237,9 +251,14 @@ impl Ls {
             .collect();
 
         let active = ts
-            .list_current_versions(config)
+            .by_source(|s| self.source.map_or(true, |kind| s.kind() == kind))
             .into_iter()
-            .map(|(p, tv)| ((p.name.clone(), tv.version.clone()), (p, tv)))
+            .map(|(p, tv)| {
+                (
+                    (p, tv.version.clone()),
+                    (config.tools[&tv.plugin_name].clone(), tv),
+                )
+            })
             .collect::<HashMap<(PluginName, String), (Arc<Tool>, ToolVersion)>>();
 
         versions.extend(active.clone());
@@ -391,6 +410,16 @@ mod tests {
         assert_cli_snapshot!("ls", "-c");
     }
 
+    #[test]
+    fn test_ls_source() {
+        assert_cli!("install");
+        assert_str_eq!(
+            assert_cli!("ls", "--source", "config"),
+            assert_cli!("ls", "-c")
+        );
+        assert_str_eq!(assert_cli!("ls", "--source", "env"), "");
+    }
+
     #[test]
     fn test_ls_json() {
         let _ = remove_all(dirs::INSTALLS.as_path());
@@ -5,6 +5,7 @@ use crate::cli::args::tool::{ToolArg, ToolArgParser};
 use crate::cli::command::Command;
 use crate::config::Config;
 use crate::errors::Error::PluginNotInstalled;
+use crate::file::display_path;
 use crate::output::Output;
 use crate::tool::Tool;
 use crate::toolset::ToolVersionRequest;
@@ -15,6 +16,9 @@ use crate::ui::prompt;
 ///
 /// note that the results are cached for 24 hours
 /// run `rtx cache clean` to clear the cache and get fresh results
+///
+/// if the project has a versions snapshot for the plugin, its versions are
+/// listed and used to resolve versions instead, see --save-snapshot
 #[derive(Debug, clap::Args)]
 #[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP, aliases = ["list-all", "list-remote"])]
 pub struct LsRemote {
@@ -26,11 +30,20 @@ pub struct LsRemote {
     /// same as the first argument after the "@"
     #[clap(verbatim_doc_comment)]
     prefix: Option<String>,
+
+    /// Save every version to .rtx/<TOOL>/versions-snapshot.json in the project instead of printing them
+    #[clap(long, conflicts_with = "prefix")]
+    save_snapshot: bool,
 }
 
 impl Command for LsRemote {
     fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
         let plugin = self.get_plugin(&mut config)?;
+        if self.save_snapshot {
+            let path = plugin.save_versions_snapshot(&config.settings)?;
+            rtxprintln!(out, "saved {}", display_path(&path));
+            return Ok(());
+        }
 
         let prefix = match &self.plugin.tvr {
             Some(ToolVersionRequest::Version(_, v)) => Some(v),
@@ -93,6 +106,9 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
   $ <bold>rtx ls-remote node 20</bold>
   20.0.0
   20.1.0
+
+  $ <bold>rtx ls-remote node --save-snapshot</bold>
+  saved ~/myproject/.rtx/node/versions-snapshot.json
 "#
 );
 
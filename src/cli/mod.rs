@@ -15,6 +15,7 @@ mod asdf;
 mod bin_paths;
 mod cache;
 pub mod command;
+mod complete_versions;
 mod completion;
 mod current;
 mod deactivate;
@@ -30,6 +31,7 @@ mod implode;
 mod install;
 mod latest;
 mod link;
+mod lint;
 mod local;
 mod ls;
 mod ls_remote;
@@ -64,6 +66,7 @@ pub enum Commands {
     Asdf(asdf::Asdf),
     BinPaths(bin_paths::BinPaths),
     Cache(cache::Cache),
+    CompleteVersions(complete_versions::CompleteVersions),
     Completion(completion::Completion),
     Current(current::Current),
     Deactivate(deactivate::Deactivate),
@@ -78,6 +81,7 @@ pub enum Commands {
     Install(install::Install),
     Latest(latest::Latest),
     Link(link::Link),
+    Lint(lint::Lint),
     Local(local::Local),
     Ls(ls::Ls),
     LsRemote(ls_remote::LsRemote),
@@ -110,6 +114,7 @@ impl Commands {
             Self::Asdf(cmd) => cmd.run(config, out),
             Self::BinPaths(cmd) => cmd.run(config, out),
             Self::Cache(cmd) => cmd.run(config, out),
+            Self::CompleteVersions(cmd) => cmd.run(config, out),
             Self::Completion(cmd) => cmd.run(config, out),
             Self::Current(cmd) => cmd.run(config, out),
             Self::Deactivate(cmd) => cmd.run(config, out),
@@ -124,6 +129,7 @@ impl Commands {
             Self::Install(cmd) => cmd.run(config, out),
             Self::Latest(cmd) => cmd.run(config, out),
             Self::Link(cmd) => cmd.run(config, out),
+            Self::Lint(cmd) => cmd.run(config, out),
             Self::Local(cmd) => cmd.run(config, out),
             Self::Ls(cmd) => cmd.run(config, out),
             Self::LsRemote(cmd) => cmd.run(config, out),
@@ -1,15 +1,13 @@
 use std::collections::HashSet;
-use std::sync::Arc;
 
 use color_eyre::eyre::Result;
 use console::{pad_str, style, Alignment};
 
 use crate::cli::args::tool::{ToolArg, ToolArgParser};
 use crate::cli::command::Command;
-use crate::config::Config;
+use crate::config::{Config, Outdated as OutdatedVersion};
 use crate::output::Output;
-use crate::tool::Tool;
-use crate::toolset::{ToolVersion, ToolsetBuilder};
+use crate::toolset::ToolsetBuilder;
 
 /// [experimental] Shows outdated tool versions
 #[derive(Debug, clap::Args)]
@@ -39,7 +37,7 @@ impl Command for Outdated {
             .collect::<HashSet<_>>();
         ts.versions
             .retain(|_, tvl| tool_set.is_empty() || tool_set.contains(&tvl.plugin_name));
-        let outdated = ts.list_outdated_versions(&config);
+        let outdated = config.outdated(&ts);
         if outdated.is_empty() {
             info!("All tools are up to date");
         } else {
@@ -50,32 +48,27 @@ impl Command for Outdated {
     }
 }
 
-type OutputVec = Vec<(Arc<Tool>, ToolVersion, String)>;
-
 impl Outdated {
-    fn display(&self, outdated: OutputVec, out: &mut Output) {
+    fn display(&self, outdated: Vec<OutdatedVersion>, out: &mut Output) {
         // TODO: make a generic table printer in src/ui/table
         let plugins = outdated
             .iter()
-            .map(|(t, _, _)| t.name.clone())
+            .map(|o| o.plugin.clone())
             .collect::<Vec<_>>();
         let requests = outdated
             .iter()
-            .map(|(_, tv, _)| tv.request.version())
+            .map(|o| match o.floating {
+                true => format!("{} (floating)", o.requested),
+                false => o.requested.clone(),
+            })
             .collect::<Vec<_>>();
         let currents = outdated
             .iter()
-            .map(|(t, tv, _)| {
-                if t.is_version_installed(tv) {
-                    tv.version.clone()
-                } else {
-                    "MISSING".to_string()
-                }
-            })
+            .map(|o| o.current.clone().unwrap_or_else(|| "MISSING".to_string()))
             .collect::<Vec<_>>();
         let latests = outdated
             .iter()
-            .map(|(_, _, c)| c.clone())
+            .map(|o| o.latest.clone())
             .collect::<Vec<_>>();
         let plugin_width = plugins
             .iter()
@@ -132,6 +125,10 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
   $ <bold>rtx outdated node</bold>
   Plugin  Requested  Current  Latest
   node    20         20.0.0   20.1.0
+
+  $ <bold>rtx outdated ruby</bold>
+  Plugin  Requested       Current  Latest
+  ruby    lts (floating)  3.2.1    3.2.2
 "#
 );
 
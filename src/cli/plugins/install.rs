@@ -133,7 +133,7 @@ fn get_name_and_url(name: &str, git_url: &Option<String>) -> Result<(String, Opt
             false => (name.to_string(), None),
         },
         None => match name.contains("://") {
-            true => (get_name_from_url(name)?, Some(name.to_string())),
+            true => (get_name_from_url(&name)?, Some(name.to_string())),
             false => (name.to_string(), None),
         },
     })
@@ -146,7 +146,7 @@ fn get_name_from_url(url: &str) -> Result<String> {
             let name = last.strip_prefix("asdf-").unwrap_or(last);
             let name = name.strip_prefix("rtx-").unwrap_or(name);
             let name = name.strip_suffix(".git").unwrap_or(name);
-            return Ok(unalias_plugin(name).to_string());
+            return Ok(unalias_plugin(name));
         }
     }
     Err(eyre!("could not infer plugin name from url: {}", url))
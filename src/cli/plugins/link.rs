@@ -46,7 +46,7 @@ impl Command for PluginsLink {
         };
         let name = unalias_plugin(&name);
         let path = path.absolutize()?;
-        let symlink = dirs::PLUGINS.join(name);
+        let symlink = dirs::PLUGINS.join(&name);
         if symlink.exists() {
             if self.force {
                 remove_all(&symlink)?;
@@ -67,7 +67,7 @@ fn get_name_from_path(path: &Path) -> String {
     let name = path.file_name().unwrap().to_str().unwrap();
     let name = name.strip_prefix("asdf-").unwrap_or(name);
     let name = name.strip_prefix("rtx-").unwrap_or(name);
-    unalias_plugin(name).to_string()
+    unalias_plugin(name)
 }
 
 static AFTER_LONG_HELP: &str = color_print::cstr!(
@@ -17,12 +17,12 @@ pub struct PluginsUninstall {
 }
 
 impl Command for PluginsUninstall {
-    fn run(self, config: Config, _out: &mut Output) -> Result<()> {
+    fn run(self, mut config: Config, _out: &mut Output) -> Result<()> {
         let mpr = MultiProgressReport::new(config.show_progress_bars());
 
         for plugin_name in &self.plugin {
             let plugin_name = unalias_plugin(plugin_name);
-            self.uninstall_one(&config, plugin_name, &mpr)?;
+            self.uninstall_one(&mut config, &plugin_name, &mpr)?;
         }
         Ok(())
     }
@@ -31,11 +31,11 @@ impl Command for PluginsUninstall {
 impl PluginsUninstall {
     fn uninstall_one(
         &self,
-        config: &Config,
+        config: &mut Config,
         plugin_name: &str,
         mpr: &MultiProgressReport,
     ) -> Result<()> {
-        match config.tools.get(plugin_name) {
+        match config.plugin(plugin_name) {
             Some(plugin) if plugin.is_installed() => {
                 let mut pr = mpr.add();
                 plugin.decorate_progress_bar(&mut pr, None);
@@ -4,7 +4,7 @@ use console::style;
 use crate::cli::command::Command;
 use crate::config::Config;
 use crate::output::Output;
-use crate::plugins::{unalias_plugin, PluginName};
+use crate::plugins::PluginName;
 
 /// Updates a plugin to the latest version
 ///
@@ -22,32 +22,36 @@ pub struct Update {
 }
 
 impl Command for Update {
-    fn run(self, config: Config, out: &mut Output) -> Result<()> {
-        let plugins: Vec<_> = match self.plugin {
-            Some(plugins) => plugins
-                .into_iter()
-                .map(|p| {
-                    let (p, ref_) = match p.split_once('#') {
-                        Some((p, ref_)) => (p, Some(ref_.to_string())),
-                        None => (p.as_str(), None),
-                    };
-                    let p = unalias_plugin(p);
-                    let plugin = config.tools.get(p).ok_or_else(|| {
-                        eyre!("plugin {} not found", style(p).cyan().for_stderr())
-                    })?;
-                    Ok((plugin.clone(), ref_))
-                })
-                .collect::<Result<_>>()?,
-            None => config
-                .external_plugins()
-                .into_iter()
-                .map(|(_, p)| (p, None))
-                .collect::<Vec<_>>(),
+    fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
+        let results = match self.plugin {
+            Some(plugins) => {
+                let plugins = plugins
+                    .into_iter()
+                    .map(|p| {
+                        let (p, ref_) = match p.split_once('#') {
+                            Some((p, ref_)) => (p, Some(ref_.to_string())),
+                            None => (p.as_str(), None),
+                        };
+                        let plugin = config.plugin(p).ok_or_else(|| {
+                            eyre!("plugin {} not found", style(p).cyan().for_stderr())
+                        })?;
+                        Ok((plugin, ref_))
+                    })
+                    .collect::<Result<_>>()?;
+                config.update_plugins(plugins)?
+            }
+            None => config.update_all_plugins()?,
         };
 
-        for (plugin, ref_) in plugins {
-            rtxprintln!(out, "updating plugin {}", plugin.name);
-            plugin.update(ref_)?;
+        let mut errors = vec![];
+        for (plugin, result) in results {
+            match result {
+                Ok(()) => rtxprintln!(out, "updated plugin {}", plugin),
+                Err(err) => errors.push(format!("{}: {:#}", plugin, err)),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(eyre!("failed to update plugins:\n{}", errors.join("\n")));
         }
         Ok(())
     }
@@ -74,6 +78,7 @@ mod tests {
             "https://github.com/jdxcode/rtx-tiny.git"
         );
         // assert_cli!("p", "update"); tested in e2e
-        assert_cli!("plugins", "update", "tiny");
+        let stdout = assert_cli!("plugins", "update", "tiny");
+        assert_eq!(stdout, "updated plugin tiny\n");
     }
 }
@@ -19,7 +19,7 @@ pub struct SettingsGet {
 
 impl Command for SettingsGet {
     fn run(self, config: Config, out: &mut Output) -> Result<()> {
-        match config.settings.to_index_map().get(&self.key) {
+        match config.settings.all().get(&self.key) {
             Some(value) => Ok(rtxprintln!(out, "{}", value)),
             None => Err(eyre!("Unknown setting: {}", self.key)),
         }
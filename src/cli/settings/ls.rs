@@ -1,23 +1,39 @@
 use color_eyre::eyre::Result;
 
 use crate::cli::command::Command;
-use crate::config::Config;
+use crate::config::{Config, SettingValue};
 use crate::output::Output;
 
 /// Show current settings
 ///
-/// This is the contents of ~/.config/rtx/config.toml
+/// This is every setting with its effective value, including the defaults
+/// not set in ~/.config/rtx/config.toml
 ///
 /// Note that aliases are also stored in this file
 /// but managed separately with `rtx aliases`
-#[derive(Debug, clap::Args)]
+#[derive(Debug, Default, clap::Args)]
 #[clap(visible_alias = "list", after_long_help = AFTER_LONG_HELP, verbatim_doc_comment)]
-pub struct SettingsLs {}
+pub struct SettingsLs {
+    /// Show where each setting came from: a config file, an env var, or the default
+    #[clap(long)]
+    origin: bool,
+}
 
 impl Command for SettingsLs {
     fn run(self, config: Config, out: &mut Output) -> Result<()> {
-        for (key, value) in config.settings.to_index_map() {
-            rtxprintln!(out, "{} = {}", key, value);
+        for (key, value) in config.settings.all() {
+            // optional settings that aren't set have no value to show
+            if value == SettingValue::Unset {
+                rtxprintln!(out, "# {} is unset", key);
+                continue;
+            }
+            let line = format!("{} = {}", key, value);
+            if self.origin {
+                let source = config.settings.provenance(&key);
+                rtxprintln!(out, "{}  # {}", line, source);
+            } else {
+                rtxprintln!(out, "{}", line);
+            }
         }
         Ok(())
     }
@@ -27,6 +43,9 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
     r#"<bold><underline>Examples:</underline></bold>
   $ <bold>rtx settings</bold>
   legacy_version_file = false
+
+  $ <bold>rtx settings ls --origin</bold>
+  legacy_version_file = false  # ~/.config/rtx/config.toml
 "#
 );
 
@@ -43,4 +62,11 @@ mod tests {
         let stdout = assert_cli!("settings");
         assert_snapshot!(stdout);
     }
+
+    #[test]
+    fn test_settings_ls_origin() {
+        reset_config();
+        let stdout = assert_cli!("settings", "ls", "--origin");
+        assert_snapshot!(stdout);
+    }
 }
@@ -38,7 +38,9 @@ impl Commands {
 
 impl Command for Settings {
     fn run(self, config: Config, out: &mut Output) -> Result<()> {
-        let cmd = self.command.unwrap_or(Commands::Ls(ls::SettingsLs {}));
+        let cmd = self
+            .command
+            .unwrap_or(Commands::Ls(ls::SettingsLs::default()));
 
         cmd.run(config, out)
     }
@@ -2,7 +2,7 @@ use color_eyre::eyre::{eyre, Result};
 
 use crate::cli::command::Command;
 use crate::config::config_file::ConfigFile;
-use crate::config::Config;
+use crate::config::{check_install_dir_template, Config};
 use crate::output::Output;
 
 /// Add/update a setting
@@ -31,7 +31,23 @@ impl Command for SettingsSet {
             "jobs" => parse_i64(&self.value)?,
             "shorthands_file" => self.value.into(),
             "disable_default_shorthands" => parse_bool(&self.value)?,
+            "disable_version_change_warnings" => parse_bool(&self.value)?,
+            "offline" => parse_bool(&self.value)?,
             "raw" => parse_bool(&self.value)?,
+            "refresh_floating_aliases" => parse_bool(&self.value)?,
+            "prefer_installed_versions" => parse_bool(&self.value)?,
+            "strict" => parse_bool(&self.value)?,
+            "require_checksums" => parse_bool(&self.value)?,
+            "bootstrap_default_plugins" => parse_bool(&self.value)?,
+            "env_overrides_shell" => parse_bool(&self.value)?,
+            "default_config_filename" => self.value.into(),
+            "default_tool_versions_filename" => self.value.into(),
+            "install_dir_template" => {
+                if let Some(err) = check_install_dir_template(&self.value) {
+                    return Err(eyre!("invalid install_dir_template: {err}"));
+                }
+                self.value.into()
+            }
             _ => return Err(eyre!("Unknown setting: {}", self.key)),
         };
 
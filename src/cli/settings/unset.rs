@@ -46,18 +46,36 @@ mod tests {
         always_keep_download = true
         always_keep_install = true
         asdf_compat = false
+        bootstrap_default_plugins = false
+        default_config_filename = .test.rtx.toml
+        default_plugins = []
+        default_tool_versions_filename = .test-tool-versions
         disable_default_shorthands = false
         disable_tools = []
+        disable_version_change_warnings = false
+        env_overrides_shell = true
         experimental = true
+        # install_dir_template is unset
         jobs = 2
         legacy_version_file = true
         legacy_version_file_disable_tools = []
         log_level = INFO
+        # mirror_prefix is unset
         missing_runtime_behavior = autoinstall
+        offline = false
+        plugin_aliases = {}
         plugin_autoupdate_last_check_duration = 20
+        prefer_installed_versions = true
         raw = false
+        refresh_floating_aliases = false
+        require_checksums = false
+        # shorthands_file is unset
+        source_precedence = ["arg", "stdin", "env", "config"]
+        strict = false
         trusted_config_paths = []
         verbose = true
+        version_filter = {}
+        version_policy = {}
         "###);
 
         reset_config();
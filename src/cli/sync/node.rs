@@ -4,12 +4,13 @@ use color_eyre::eyre::Result;
 use itertools::sorted;
 
 use crate::cli::command::Command;
+use crate::cmd;
 use crate::config::Config;
 use crate::env::{NODENV_ROOT, NVM_DIR};
 use crate::file;
 use crate::output::Output;
 use crate::plugins::PluginName;
-use crate::{cmd, dirs};
+use crate::tool::installs_path;
 
 /// Symlinks all tool versions from an external tool into rtx
 ///
@@ -55,7 +56,7 @@ impl SyncNode {
         let tool = config.get_or_create_tool(&PluginName::from("node"));
 
         let brew_prefix = PathBuf::from(cmd!("brew", "--prefix").read()?).join("opt");
-        let installed_versions_path = dirs::INSTALLS.join("node");
+        let installed_versions_path = installs_path("node");
 
         file::remove_symlinks_with_target_prefix(&installed_versions_path, &brew_prefix)?;
 
@@ -76,7 +77,7 @@ impl SyncNode {
         let tool = config.get_or_create_tool(&PluginName::from("node"));
 
         let nvm_versions_path = NVM_DIR.join("versions").join("node");
-        let installed_versions_path = dirs::INSTALLS.join("node");
+        let installed_versions_path = installs_path("node");
 
         file::remove_symlinks_with_target_prefix(&installed_versions_path, &nvm_versions_path)?;
 
@@ -94,7 +95,7 @@ impl SyncNode {
         let tool = config.get_or_create_tool(&PluginName::from("node"));
 
         let nodenv_versions_path = NODENV_ROOT.join("versions");
-        let installed_versions_path = dirs::INSTALLS.join("node");
+        let installed_versions_path = installs_path("node");
 
         file::remove_symlinks_with_target_prefix(&installed_versions_path, &nodenv_versions_path)?;
 
@@ -3,11 +3,11 @@ use itertools::sorted;
 
 use crate::cli::command::Command;
 use crate::config::Config;
-use crate::dirs;
 use crate::env::PYENV_ROOT;
 use crate::file;
 use crate::output::Output;
 use crate::plugins::PluginName;
+use crate::tool::installs_path;
 
 /// Symlinks all tool versions from an external tool into rtx
 ///
@@ -25,7 +25,7 @@ impl Command for SyncPython {
         let python = config.get_or_create_tool(&PluginName::from("python"));
 
         let pyenv_versions_path = PYENV_ROOT.join("versions");
-        let installed_python_versions_path = dirs::INSTALLS.join("python");
+        let installed_python_versions_path = installs_path("python");
 
         file::remove_symlinks_with_target_prefix(
             &installed_python_versions_path,
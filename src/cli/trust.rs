@@ -17,6 +17,10 @@ use crate::output::Output;
 /// - environment variables
 /// - templates
 /// - `path:` plugin versions
+/// - `include` of other config files
+///
+/// The content of the file is recorded as well, so it needs to be
+/// trusted again if it changes other than by rtx itself.
 #[derive(Debug, clap::Args)]
 #[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
 pub struct Trust {
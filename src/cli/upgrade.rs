@@ -45,7 +45,11 @@ impl Command for Upgrade {
             .collect::<HashSet<_>>();
         ts.versions
             .retain(|_, tvl| tool_set.is_empty() || tool_set.contains(&tvl.plugin_name));
-        let outdated = ts.list_outdated_versions(&config);
+        let outdated = config
+            .outdated(&ts)
+            .into_iter()
+            .map(|o| (config.tools[&o.plugin].clone(), o.tv, o.latest))
+            .collect_vec();
         if outdated.is_empty() {
             info!("All tools are up to date");
         } else {
@@ -5,8 +5,7 @@ use color_eyre::eyre::Result;
 use crate::cli::args::tool::{ToolArg, ToolArgParser};
 use crate::cli::command::Command;
 use crate::cli::local::local;
-use crate::config::Config;
-use crate::env::{RTX_DEFAULT_CONFIG_FILENAME, RTX_DEFAULT_TOOL_VERSIONS_FILENAME};
+use crate::config::{default_config_filename, default_tool_versions_filename, Config};
 use crate::output::Output;
 use crate::plugins::PluginName;
 use crate::{dirs, env};
@@ -88,8 +87,8 @@ fn config_file_from_dir(p: &Path) -> PathBuf {
     if !p.is_dir() {
         return p.to_path_buf();
     }
-    let rtx_toml = p.join(&*RTX_DEFAULT_CONFIG_FILENAME);
-    let tool_versions = p.join(&*RTX_DEFAULT_TOOL_VERSIONS_FILENAME);
+    let rtx_toml = p.join(default_config_filename());
+    let tool_versions = p.join(default_tool_versions_filename());
     if tool_versions.exists() && !rtx_toml.exists() {
         tool_versions
     } else {
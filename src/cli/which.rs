@@ -33,14 +33,13 @@ impl Command for Which {
         let ts = self.get_toolset(&mut config)?;
 
         match ts.which(&config, &self.bin_name) {
-            Some((p, tv)) => {
+            Some((plugin_name, tv, path)) => {
                 if self.version {
                     rtxprintln!(out, "{}", tv.version);
                 } else if self.plugin {
-                    rtxprintln!(out, "{}", p.name);
+                    rtxprintln!(out, "{}", plugin_name);
                 } else {
-                    let path = p.which(&config, &tv, &self.bin_name)?;
-                    rtxprintln!(out, "{}", path.unwrap().display());
+                    rtxprintln!(out, "{}", path.display());
                 }
                 Ok(())
             }
@@ -0,0 +1,233 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use std::{env as std_env, fs};
+
+use color_eyre::eyre::Result;
+use itertools::Itertools;
+use serde_derive::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::cache::CacheManager;
+use crate::config::config_file::cached::CachedConfigFile;
+use crate::config::config_file::ConfigFile;
+use crate::config::{get_global_rtx_toml, settings, ConfigMap};
+use crate::{dirs, env, file};
+
+/// bump this when the format of the cache changes
+const CACHE_VERSION: u32 = 1;
+
+/// files modified this recently may be modified again without their mtime changing on
+/// filesystems with coarse timestamps, so the cache isn't written until they settle
+const RACY_WINDOW: Duration = Duration::from_secs(2);
+
+/// env vars that change between prompts without changing which config is loaded
+const IGNORED_ENV_VARS: &[&str] = &["_", "OLDPWD", "PWD", "SHLVL", "__RTX_WATCH"];
+
+/// the parsed config files from the last `Config::load()` in a directory, enabled with
+/// RTX_CONFIG_CACHE=1
+///
+/// The cache is keyed by what can change how the files are found or parsed: the rtx binary, the
+/// cwd, the environment, and the installed plugins. On read it's also checked that every file
+/// that was looked for still has the same mtime, or is still missing.
+#[derive(Debug, Serialize, Deserialize)]
+struct ConfigCache {
+    key: String,
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+    config_files: Vec<CachedConfigFile>,
+}
+
+pub struct ConfigCacheFile {
+    cache: CacheManager<ConfigCache>,
+    key: String,
+    /// the directory the config files were found from
+    dir: PathBuf,
+    racy_window: Duration,
+}
+
+impl ConfigCacheFile {
+    pub fn new() -> Self {
+        let key = cache_key();
+        let filename = format!("{}.msgpack.z", &key[..16]);
+        let path = dirs::CACHE.join("config").join(filename);
+        Self::with_path(path, key, dirs::CURRENT.clone(), RACY_WINDOW)
+    }
+
+    fn with_path(path: PathBuf, key: String, dir: PathBuf, racy_window: Duration) -> Self {
+        Self {
+            cache: CacheManager::new(path),
+            key,
+            dir,
+            racy_window,
+        }
+    }
+
+    /// the cached config files if none of the inputs changed since they were written
+    pub fn read(&self) -> Option<ConfigMap> {
+        let cache = self.cache.parse().ok()?;
+        if cache.key != self.key {
+            return None;
+        }
+        if files_changed(&cache.files) {
+            trace!("config cache is stale");
+            return None;
+        }
+        trace!("using config cache");
+        let config_files = cache.config_files.into_iter().map(|cf| {
+            let cf = cf.load_toolset();
+            (
+                cf.get_path().to_path_buf(),
+                Box::new(cf) as Box<dyn ConfigFile>,
+            )
+        });
+        Some(config_files.collect())
+    }
+
+    /// `filenames` are the names that were looked for in the cwd and its parents
+    pub fn write(&self, config_files: &ConfigMap, filenames: &[String]) -> Result<()> {
+        let files = watched_files(&self.dir, config_files, filenames);
+        let mtimes = files.iter().filter_map(|(_, mtime)| *mtime);
+        let key_paths = key_paths();
+        let mut mtimes = mtimes.chain(key_paths.iter().filter_map(|p| modified(p)));
+        if mtimes.any(|mtime| mtime.elapsed().unwrap_or_default() < self.racy_window) {
+            trace!("not writing config cache, files were modified too recently");
+            return Ok(());
+        }
+        let config_files = config_files
+            .values()
+            .map(|cf| CachedConfigFile::new(cf.as_ref()))
+            .collect();
+        self.cache.write(&ConfigCache {
+            key: self.key.clone(),
+            files,
+            config_files,
+        })
+    }
+}
+
+/// the mtimes of the files that decide which config files are loaded for `dir`: the ones that
+/// were loaded and the ones that could appear in `dir` or its parents. `filenames` are the names
+/// that were looked for.
+pub fn watched_files(
+    dir: &Path,
+    config_files: &ConfigMap,
+    filenames: &[String],
+) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let candidates = dir
+        .ancestors()
+        .flat_map(|dir| filenames.iter().map(|f| dir.join(f)))
+        .chain([dirs::HOME.join(settings::default_tool_versions_filename())]);
+    config_files
+        .values()
+        .flat_map(|cf| cf.watch_files())
+        .chain(candidates)
+        .unique()
+        .map(|f| {
+            let mtime = modified(&f);
+            (f, mtime)
+        })
+        .collect()
+}
+
+/// whether any of the files from `watched_files()` was modified, created, or removed since
+pub fn files_changed(files: &[(PathBuf, Option<SystemTime>)]) -> bool {
+    files.iter().any(|(f, mtime)| &modified(f) != mtime)
+}
+
+/// hashes the inputs instead of storing them since the environment may contain secrets
+fn cache_key() -> String {
+    let mut hasher = Sha256::new();
+    let mut add = |s: &str| {
+        hasher.update(s.as_bytes());
+        hasher.update([0]);
+    };
+    add(&CACHE_VERSION.to_string());
+    add(env!("CARGO_PKG_VERSION"));
+    add(&dirs::CURRENT.to_string_lossy());
+    let mut vars = std_env::vars_os()
+        .filter(|(k, _)| !IGNORED_ENV_VARS.iter().any(|i| k == i))
+        .collect::<Vec<_>>();
+    vars.sort();
+    for (k, v) in vars {
+        add(&k.to_string_lossy());
+        add(&v.to_string_lossy());
+    }
+    for path in key_paths() {
+        add(&path.to_string_lossy());
+        add(&format!("{:?}", modified(&path)));
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// files outside of the config files that change how they're parsed, installing or updating
+/// a plugin changes the mtime of its directory or its bin directory
+fn key_paths() -> Vec<PathBuf> {
+    let mut paths = vec![];
+    if let Ok(exe) = std_env::current_exe() {
+        paths.push(exe);
+    }
+    paths.push(get_global_rtx_toml());
+    paths.push(env::RTX_SYSTEM_CONFIG_FILE.to_path_buf());
+    paths.push(dirs::CACHE.join("trusted-configs"));
+    paths.push(dirs::PLUGINS.to_path_buf());
+    for plugin in file::dir_subdirs(&dirs::PLUGINS).unwrap_or_default() {
+        let plugin_path = dirs::PLUGINS.join(plugin);
+        paths.push(plugin_path.join("bin"));
+        paths.push(plugin_path);
+    }
+    paths.sort();
+    paths
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use filetime::{set_file_mtime, FileTime};
+    use pretty_assertions::assert_eq;
+
+    use crate::config::config_file;
+    use crate::env;
+
+    use super::*;
+
+    #[test]
+    fn test_config_cache() {
+        let root = env::temp_dir().join("rtx-test-config-cache");
+        let _ = fs::remove_dir_all(&root);
+        let dir = root.join("project");
+        fs::create_dir_all(&dir).unwrap();
+        let filename = settings::default_tool_versions_filename();
+        let tool_versions = dir.join(&filename);
+        fs::write(&tool_versions, "tiny 2\n").unwrap();
+        set_file_mtime(&tool_versions, FileTime::from_unix_time(1, 0)).unwrap();
+        let cf = config_file::parse(&tool_versions, true).unwrap();
+        let config_files: ConfigMap = [(tool_versions.clone(), cf)].into_iter().collect();
+        let path = root.join("cache.msgpack.z");
+        let cache = ConfigCacheFile::with_path(path, cache_key(), dir.clone(), Duration::ZERO);
+        cache.write(&config_files, &[filename.clone()]).unwrap();
+
+        let cached = cache.read().unwrap();
+        assert_eq!(
+            cached.keys().collect::<Vec<_>>(),
+            config_files.keys().collect::<Vec<_>>()
+        );
+        for (cf, cached) in config_files.values().zip(cached.values()) {
+            assert_eq!(cf.get_type(), cached.get_type());
+            assert_eq!(cf.env(), cached.env());
+            assert_eq!(cf.watch_files(), cached.watch_files());
+            assert_eq!(cf.to_toolset().to_string(), cached.to_toolset().to_string());
+        }
+
+        set_file_mtime(&tool_versions, FileTime::from_unix_time(0, 0)).unwrap();
+        assert!(cache.read().is_none());
+        set_file_mtime(&tool_versions, FileTime::from_unix_time(1, 0)).unwrap();
+        assert!(cache.read().is_some());
+
+        // a config file that didn't exist before
+        fs::write(root.join(&filename), "").unwrap();
+        assert!(cache.read().is_none());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{eyre, Result};
+use indexmap::IndexMap;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::config::config_file::{ConfigFile, ConfigFileType};
+use crate::config::settings::SettingsBuilder;
+use crate::config::{AliasMap, EnvPathAdd, PluginHooks};
+use crate::file::display_path;
+use crate::plugins::PluginName;
+use crate::toolset::{ToolSource, ToolVersionOptions, ToolVersionRequest, Toolset};
+
+/// a config file restored from the config cache instead of being parsed, it has everything
+/// `Config::load()` reads from a config file but can't be saved
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CachedConfigFile {
+    path: PathBuf,
+    file_type: ConfigFileType,
+    source: Option<ToolSource>,
+    requests: Vec<(PluginName, Vec<(String, ToolVersionOptions)>)>,
+    shadowed: Vec<(PluginName, Vec<String>)>,
+    plugins: HashMap<PluginName, String>,
+    env: IndexMap<String, String>,
+    env_remove: Vec<String>,
+    env_path_add: HashMap<String, EnvPathAdd>,
+    env_profiles: HashMap<String, IndexMap<String, String>>,
+    env_templates: HashMap<String, String>,
+    env_when_installed: HashMap<String, PluginName>,
+    includes: Vec<PathBuf>,
+    disable_plugins: Vec<PluginName>,
+    #[serde(default)]
+    env_isolated: bool,
+    project_name: Option<String>,
+    plugin_settings: HashMap<PluginName, IndexMap<String, String>>,
+    plugin_install_env: HashMap<PluginName, IndexMap<String, String>>,
+    plugin_install_dirs: HashMap<PluginName, PathBuf>,
+    plugin_mirror_urls: HashMap<PluginName, String>,
+    plugin_legacy_filenames: HashMap<PluginName, Vec<String>>,
+    plugin_hooks: HashMap<PluginName, PluginHooks>,
+    path_dirs: Vec<PathBuf>,
+    settings: SettingsBuilder,
+    aliases: AliasMap,
+    alias_comments: AliasMap,
+    watch_files: Vec<PathBuf>,
+    #[serde(skip)]
+    toolset: Toolset,
+}
+
+impl CachedConfigFile {
+    pub fn new(cf: &dyn ConfigFile) -> Self {
+        let ts = cf.to_toolset();
+        let requests = ts
+            .versions
+            .iter()
+            .map(|(plugin, tvl)| {
+                let requests = tvl.requests.iter();
+                let requests = requests.map(|(tvr, opts)| (tvr.version(), opts.clone()));
+                (plugin.clone(), requests.collect())
+            })
+            .collect();
+        let shadowed = ts
+            .versions
+            .iter()
+            .map(|(plugin, tvl)| {
+                let shadowed = tvl.shadowed.iter().map(|tvr| tvr.version());
+                (plugin.clone(), shadowed.collect())
+            })
+            .collect();
+        Self {
+            path: cf.get_path().to_path_buf(),
+            file_type: cf.get_type(),
+            source: ts.source.clone(),
+            requests,
+            shadowed,
+            plugins: cf.plugins(),
+            env: cf.env(),
+            env_remove: cf.env_remove(),
+            env_path_add: cf.env_path_add(),
+            env_profiles: cf.env_profiles(),
+            env_templates: cf.env_templates(),
+            env_when_installed: cf.env_when_installed(),
+            includes: cf.includes(),
+            disable_plugins: cf.disable_plugins(),
+            env_isolated: cf.env_isolated(),
+            project_name: cf.project_name(),
+            plugin_settings: cf.plugin_settings(),
+            plugin_install_env: cf.plugin_install_env(),
+            plugin_install_dirs: cf.plugin_install_dirs(),
+            plugin_mirror_urls: cf.plugin_mirror_urls(),
+            plugin_legacy_filenames: cf.plugin_legacy_filenames(),
+            plugin_hooks: cf.plugin_hooks(),
+            path_dirs: cf.path_dirs(),
+            settings: cf.settings(),
+            aliases: cf.aliases(),
+            alias_comments: cf.alias_comments(),
+            watch_files: cf.watch_files(),
+            toolset: Toolset::default(),
+        }
+    }
+
+    /// rebuilds the toolset after being deserialized
+    pub fn load_toolset(mut self) -> Self {
+        let mut ts = Toolset {
+            source: self.source.clone(),
+            ..Default::default()
+        };
+        for (plugin, requests) in &self.requests {
+            for (version, opts) in requests {
+                let tvr = ToolVersionRequest::new(plugin.clone(), version);
+                ts.add_version(tvr, opts.clone());
+            }
+        }
+        for (plugin, shadowed) in &self.shadowed {
+            if let Some(tvl) = ts.versions.get_mut(plugin) {
+                tvl.shadowed = shadowed
+                    .iter()
+                    .map(|v| ToolVersionRequest::new(plugin.clone(), v))
+                    .collect();
+            }
+        }
+        self.toolset = ts;
+        self
+    }
+}
+
+impl Display for CachedConfigFile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CachedConfigFile({})", display_path(&self.path))
+    }
+}
+
+impl ConfigFile for CachedConfigFile {
+    fn get_type(&self) -> ConfigFileType {
+        self.file_type.clone()
+    }
+
+    fn get_path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    fn plugins(&self) -> HashMap<PluginName, String> {
+        self.plugins.clone()
+    }
+
+    fn env(&self) -> IndexMap<String, String> {
+        self.env.clone()
+    }
+
+    fn env_remove(&self) -> Vec<String> {
+        self.env_remove.clone()
+    }
+
+    fn env_path_add(&self) -> HashMap<String, EnvPathAdd> {
+        self.env_path_add.clone()
+    }
+
+    fn env_profiles(&self) -> HashMap<String, IndexMap<String, String>> {
+        self.env_profiles.clone()
+    }
+
+    fn env_templates(&self) -> HashMap<String, String> {
+        self.env_templates.clone()
+    }
+
+    fn env_when_installed(&self) -> HashMap<String, PluginName> {
+        self.env_when_installed.clone()
+    }
+
+    fn includes(&self) -> Vec<PathBuf> {
+        self.includes.clone()
+    }
+
+    fn disable_plugins(&self) -> Vec<PluginName> {
+        self.disable_plugins.clone()
+    }
+
+    fn env_isolated(&self) -> bool {
+        self.env_isolated
+    }
+
+    fn project_name(&self) -> Option<String> {
+        self.project_name.clone()
+    }
+
+    fn plugin_settings(&self) -> HashMap<PluginName, IndexMap<String, String>> {
+        self.plugin_settings.clone()
+    }
+
+    fn plugin_install_env(&self) -> HashMap<PluginName, IndexMap<String, String>> {
+        self.plugin_install_env.clone()
+    }
+
+    fn plugin_install_dirs(&self) -> HashMap<PluginName, PathBuf> {
+        self.plugin_install_dirs.clone()
+    }
+
+    fn plugin_mirror_urls(&self) -> HashMap<PluginName, String> {
+        self.plugin_mirror_urls.clone()
+    }
+
+    fn plugin_legacy_filenames(&self) -> HashMap<PluginName, Vec<String>> {
+        self.plugin_legacy_filenames.clone()
+    }
+
+    fn plugin_hooks(&self) -> HashMap<PluginName, PluginHooks> {
+        self.plugin_hooks.clone()
+    }
+
+    fn path_dirs(&self) -> Vec<PathBuf> {
+        self.path_dirs.clone()
+    }
+
+    fn remove_plugin(&mut self, plugin_name: &PluginName) {
+        self.toolset.versions.remove(plugin_name);
+    }
+
+    fn replace_versions(&mut self, plugin_name: &PluginName, versions: &[String]) {
+        if let Some(tvl) = self.toolset.versions.get_mut(plugin_name) {
+            tvl.requests.clear();
+        }
+        for v in versions {
+            let tvr = ToolVersionRequest::new(plugin_name.clone(), v);
+            self.toolset.add_version(tvr, Default::default());
+        }
+    }
+
+    fn save(&self) -> Result<()> {
+        Err(eyre!(
+            "{} was loaded from the config cache and can't be saved",
+            display_path(&self.path)
+        ))
+    }
+
+    fn dump(&self) -> String {
+        fs::read_to_string(&self.path).unwrap_or_default()
+    }
+
+    fn to_toolset(&self) -> &Toolset {
+        &self.toolset
+    }
+
+    fn settings(&self) -> SettingsBuilder {
+        self.settings.clone()
+    }
+
+    fn aliases(&self) -> AliasMap {
+        self.aliases.clone()
+    }
+
+    fn alias_comments(&self) -> AliasMap {
+        self.alias_comments.clone()
+    }
+
+    fn watch_files(&self) -> Vec<PathBuf> {
+        self.watch_files.clone()
+    }
+}
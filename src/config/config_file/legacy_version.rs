@@ -3,12 +3,16 @@ use std::default::Default;
 use std::fmt::Display;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::{env, fs};
 
 use color_eyre::eyre::Result;
+use indexmap::IndexMap;
 
 use crate::config::config_file::{ConfigFile, ConfigFileType};
 use crate::config::settings::SettingsBuilder;
 use crate::config::{AliasMap, Settings};
+use crate::file;
+use crate::hash::hash_to_str;
 use crate::plugins::PluginName;
 use crate::tool::Tool;
 use crate::toolset::{ToolSource, ToolVersionRequest, Toolset};
@@ -21,10 +25,36 @@ pub struct LegacyVersionFile {
 
 impl LegacyVersionFile {
     pub fn parse(settings: &Settings, path: PathBuf, plugins: &[&Arc<Tool>]) -> Result<Self> {
+        Self::parse_file(settings, &path.clone(), path, plugins)
+    }
+
+    /// parses `body` as if it were the content of `path`, e.g.: `path` as it was at a git ref.
+    /// Plugins read legacy files themselves so it's written to a temp file with the same name.
+    pub fn parse_str(
+        settings: &Settings,
+        path: PathBuf,
+        body: &str,
+        plugins: &[&Arc<Tool>],
+    ) -> Result<Self> {
+        let dir = env::temp_dir().join(format!("rtx-legacy-{}", hash_to_str(&path)));
+        file::create_dir_all(&dir)?;
+        let file = dir.join(path.file_name().unwrap());
+        fs::write(&file, body)?;
+        let lvf = Self::parse_file(settings, &file, path, plugins);
+        file::remove_all(&dir)?;
+        lvf
+    }
+
+    fn parse_file(
+        settings: &Settings,
+        file: &Path,
+        path: PathBuf,
+        plugins: &[&Arc<Tool>],
+    ) -> Result<Self> {
         let mut toolset = Toolset::new(ToolSource::LegacyVersionFile(path.clone()));
 
         for plugin in plugins {
-            let version = plugin.parse_legacy_file(&path, settings)?;
+            let version = plugin.parse_legacy_file(file, settings)?;
             for version in version.split_whitespace() {
                 toolset.add_version(
                     ToolVersionRequest::new(plugin.name.to_string(), version),
@@ -50,8 +80,8 @@ impl ConfigFile for LegacyVersionFile {
         Default::default()
     }
 
-    fn env(&self) -> HashMap<String, String> {
-        HashMap::new()
+    fn env(&self) -> IndexMap<String, String> {
+        IndexMap::new()
     }
 
     fn path_dirs(&self) -> Vec<PathBuf> {
@@ -4,29 +4,36 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use color_eyre::eyre::{eyre, Result};
+use indexmap::IndexMap;
+use serde_derive::{Deserialize, Serialize};
 
 use tool_versions::ToolVersions;
+use tool_versions_toml::ToolVersionsToml;
 
 use crate::cli::args::tool::ToolArg;
 use crate::config::config_file::rtx_toml::RtxToml;
-use crate::config::settings::SettingsBuilder;
-use crate::config::{AliasMap, Config, Settings};
+use crate::config::settings::{self, SettingsBuilder};
+use crate::config::{AliasMap, Config, EnvPathAdd, PluginHooks, Settings};
+use crate::dirs;
 use crate::file::{display_path, replace_path};
-use crate::hash::hash_to_str;
+use crate::git::Git;
+use crate::hash::{file_hash_sha256, hash_sha256_to_str, hash_to_str};
 use crate::output::Output;
 use crate::plugins::PluginName;
 use crate::toolset::{ToolVersion, ToolVersionList, Toolset};
 use crate::ui::multi_progress_report::MultiProgressReport;
-use crate::{dirs, env};
 
+pub mod cached;
 pub mod legacy_version;
 pub mod rtx_toml;
 pub mod tool_versions;
+pub mod tool_versions_toml;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ConfigFileType {
     RtxToml,
     ToolVersions,
+    ToolVersionsToml,
     LegacyVersion,
 }
 
@@ -34,10 +41,68 @@ pub trait ConfigFile: Debug + Display + Send + Sync {
     fn get_type(&self) -> ConfigFileType;
     fn get_path(&self) -> &Path;
     fn plugins(&self) -> HashMap<PluginName, String>;
-    fn env(&self) -> HashMap<String, String>;
+    /// env vars in the order they're declared in the file
+    fn env(&self) -> IndexMap<String, String>;
     fn env_remove(&self) -> Vec<String> {
         vec![]
     }
+    /// `[env.path_add]`, these are joined with the ones from other config files
+    fn env_path_add(&self) -> HashMap<String, EnvPathAdd> {
+        HashMap::new()
+    }
+    /// `[env.profiles.<name>]`, the vars of the profile selected with RTX_PROFILE
+    fn env_profiles(&self) -> HashMap<String, IndexMap<String, String>> {
+        HashMap::new()
+    }
+    /// env vars which can only be rendered once the toolset is resolved
+    /// e.g.: `JAVA_HOME = "{{ install_path(tool='java') }}"`
+    fn env_templates(&self) -> HashMap<String, String> {
+        HashMap::new()
+    }
+    /// env templates that are only set if the plugin has an installed version in the toolset
+    fn env_when_installed(&self) -> HashMap<String, PluginName> {
+        HashMap::new()
+    }
+    /// other config files this one includes, these have lower precedence
+    fn includes(&self) -> Vec<PathBuf> {
+        vec![]
+    }
+    /// plugins ignored while in this directory
+    fn disable_plugins(&self) -> Vec<PluginName> {
+        vec![]
+    }
+    /// `env_isolated = true`, env vars from farther config files aren't used
+    fn env_isolated(&self) -> bool {
+        false
+    }
+    /// `[project] name`, only used to label the environment
+    fn project_name(&self) -> Option<String> {
+        None
+    }
+    /// `[plugins.<name>.settings]`, passed to the plugin's scripts
+    fn plugin_settings(&self) -> HashMap<PluginName, IndexMap<String, String>> {
+        HashMap::new()
+    }
+    /// `[plugins.<name>.install_env]`, only set for the plugin's install
+    fn plugin_install_env(&self) -> HashMap<PluginName, IndexMap<String, String>> {
+        HashMap::new()
+    }
+    /// `[plugins.<name>] install_dir`, already resolved against the config file's directory
+    fn plugin_install_dirs(&self) -> HashMap<PluginName, PathBuf> {
+        HashMap::new()
+    }
+    /// `[plugins.<name>] mirror_url`
+    fn plugin_mirror_urls(&self) -> HashMap<PluginName, String> {
+        HashMap::new()
+    }
+    /// `[plugins.<name>] legacy_filenames`, read in addition to the ones the plugin lists
+    fn plugin_legacy_filenames(&self) -> HashMap<PluginName, Vec<String>> {
+        HashMap::new()
+    }
+    /// `[plugins.<name>] pre_install` and `post_uninstall`
+    fn plugin_hooks(&self) -> HashMap<PluginName, PluginHooks> {
+        HashMap::new()
+    }
     fn path_dirs(&self) -> Vec<PathBuf>;
     fn remove_plugin(&mut self, plugin_name: &PluginName);
     fn replace_versions(&mut self, plugin_name: &PluginName, versions: &[String]);
@@ -46,6 +111,10 @@ pub trait ConfigFile: Debug + Display + Send + Sync {
     fn to_toolset(&self) -> &Toolset;
     fn settings(&self) -> SettingsBuilder;
     fn aliases(&self) -> AliasMap;
+    /// trailing comments on alias entries, keyed the same way as `aliases()`
+    fn alias_comments(&self) -> AliasMap {
+        AliasMap::default()
+    }
     fn watch_files(&self) -> Vec<PathBuf>;
 }
 
@@ -141,6 +210,7 @@ pub fn init(path: &Path, is_trusted: bool) -> Box<dyn ConfigFile> {
     match detect_config_file_type(path) {
         Some(ConfigFileType::RtxToml) => Box::new(RtxToml::init(path, is_trusted)),
         Some(ConfigFileType::ToolVersions) => Box::new(ToolVersions::init(path, is_trusted)),
+        Some(ConfigFileType::ToolVersionsToml) => Box::new(ToolVersionsToml::init(path)),
         _ => panic!("Unknown config file type: {}", path.display()),
     }
 }
@@ -151,12 +221,92 @@ pub fn parse(path: &Path, is_trusted: bool) -> Result<Box<dyn ConfigFile>> {
         Some(ConfigFileType::ToolVersions) => {
             Ok(Box::new(ToolVersions::from_file(path, is_trusted)?))
         }
+        Some(ConfigFileType::ToolVersionsToml) => Ok(Box::new(ToolVersionsToml::from_file(path)?)),
         #[allow(clippy::box_default)]
         _ => Ok(Box::new(RtxToml::default())),
     }
 }
 
+/// parses a config file as it was at `gitref` of the git repo it's in, `None` if it didn't exist
+/// at that ref
+pub fn parse_at_ref(
+    settings: &Settings,
+    path: &Path,
+    gitref: &str,
+) -> Result<Option<Box<dyn ConfigFile>>> {
+    let git = Git::new(path.parent().unwrap().to_path_buf());
+    let Some(body) = git.show_file(gitref, path)? else {
+        return Ok(None);
+    };
+    // trusting the file now doesn't trust what it used to contain
+    let is_trusted = is_trusted_with_hash(settings, path, &hash_sha256_to_str(&body));
+    let cf: Box<dyn ConfigFile> = match detect_config_file_type(path) {
+        Some(ConfigFileType::RtxToml) => Box::new(RtxToml::from_contents(path, &body, is_trusted)?),
+        Some(ConfigFileType::ToolVersions) => Box::new(ToolVersions::parse_str(
+            &body,
+            path.to_path_buf(),
+            is_trusted,
+        )?),
+        Some(ConfigFileType::ToolVersionsToml) => {
+            Box::new(ToolVersionsToml::parse_str(&body, path)?)
+        }
+        _ => return Err(eyre!("can't read {} from git", display_path(path))),
+    };
+    Ok(Some(cf))
+}
+
+/// a problem found by `validate()`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl Issue {
+    pub fn new(line: Option<usize>, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {line}: {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// checks a single config file without loading the rest of the config or using the network.
+/// .tool-versions files (or any non-toml file) are checked line by line, the others only for
+/// whether they parse.
+pub fn validate(path: &Path, config: &Config) -> Result<Vec<Issue>> {
+    match detect_config_file_type(path) {
+        Some(ConfigFileType::ToolVersions) | None => {
+            let body = fs::read_to_string(path)?;
+            Ok(tool_versions::validate(&body, config))
+        }
+        _ => {
+            let is_trusted = is_trusted(&config.settings, path);
+            Ok(match parse(path, is_trusted) {
+                Ok(_) => vec![],
+                Err(err) => vec![Issue::new(None, format!("{err:#}"))],
+            })
+        }
+    }
+}
+
+/// a config file is trusted if it's in `trusted_config_paths` or `rtx trust` recorded it with the
+/// same content, so a trusted file has to be trusted again once someone else changes it
 pub fn is_trusted(settings: &Settings, path: &Path) -> bool {
+    is_trusted_with_hash(settings, path, &content_hash(path))
+}
+
+/// same as `is_trusted()` but for the content with the sha256 `hash` instead of the current content
+fn is_trusted_with_hash(settings: &Settings, path: &Path, hash: &str) -> bool {
     if settings
         .trusted_config_paths
         .iter()
@@ -164,18 +314,53 @@ pub fn is_trusted(settings: &Settings, path: &Path) -> bool {
     {
         return true;
     }
-    trust_path(path.to_path_buf()).unwrap().exists()
+    let Ok(hashed_path) = trust_path(path.to_path_buf()) else {
+        return false;
+    };
+    match fs::read_to_string(hashed_path) {
+        // trusted before the content was recorded, see `migrate_legacy_trust()`
+        Ok(trusted) if trusted.is_empty() => hash == content_hash(path),
+        Ok(trusted) => trusted == hash,
+        Err(_) => false,
+    }
 }
 
 pub fn trust(path: &Path) -> Result<()> {
     let hashed_path = trust_path(path.to_path_buf())?;
-    if !hashed_path.exists() {
-        fs::create_dir_all(hashed_path.parent().unwrap())?;
-        fs::write(hashed_path, "")?;
+    fs::create_dir_all(hashed_path.parent().unwrap())?;
+    fs::write(hashed_path, content_hash(path))?;
+    Ok(())
+}
+
+/// files trusted before their content was recorded have an empty trust file, this records the
+/// current content so they become untrusted once it changes like any other trusted file
+pub fn migrate_legacy_trust(path: &Path) {
+    let Ok(hashed_path) = trust_path(path.to_path_buf()) else {
+        return;
+    };
+    if !matches!(fs::read_to_string(hashed_path), Ok(trusted) if trusted.is_empty()) {
+        return;
+    }
+    if let Err(err) = trust(path) {
+        debug!(
+            "failed to migrate trust for {}: {err:#}",
+            display_path(path)
+        );
+    }
+}
+
+/// keeps a trusted file trusted after rtx itself changes it, e.g.: with `rtx use`
+pub fn update_trust(path: &Path) -> Result<()> {
+    if trust_path(path.to_path_buf())?.exists() {
+        trust(path)?;
     }
     Ok(())
 }
 
+fn content_hash(path: &Path) -> String {
+    file_hash_sha256(path).unwrap_or_default()
+}
+
 pub fn untrust(path: &Path) -> Result<()> {
     let hashed_path = trust_path(path.to_path_buf())?;
     if hashed_path.exists() {
@@ -194,11 +379,12 @@ fn trust_path(mut path: PathBuf) -> Result<PathBuf> {
 
 fn detect_config_file_type(path: &Path) -> Option<ConfigFileType> {
     match path.file_name().unwrap().to_str().unwrap() {
-        f if f.ends_with(".toml") => Some(ConfigFileType::RtxToml),
-        f if env::RTX_DEFAULT_CONFIG_FILENAME.as_str() == f => Some(ConfigFileType::RtxToml),
-        f if env::RTX_DEFAULT_TOOL_VERSIONS_FILENAME.as_str() == f => {
-            Some(ConfigFileType::ToolVersions)
+        f if settings::default_tool_versions_toml_filename() == f => {
+            Some(ConfigFileType::ToolVersionsToml)
         }
+        f if f.ends_with(".toml") => Some(ConfigFileType::RtxToml),
+        f if settings::default_config_filename() == f => Some(ConfigFileType::RtxToml),
+        f if settings::default_tool_versions_filename() == f => Some(ConfigFileType::ToolVersions),
         _ => None,
     }
 }
@@ -206,6 +392,7 @@ fn detect_config_file_type(path: &Path) -> Option<ConfigFileType> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::env;
 
     #[test]
     fn test_detect_config_file_type() {
@@ -217,5 +404,50 @@ mod tests {
             detect_config_file_type(Path::new("/foo/bar/.tool-versions.toml")),
             Some(ConfigFileType::RtxToml)
         );
+        assert_eq!(
+            detect_config_file_type(Path::new("/foo/bar/.test-tool-versions.toml")),
+            Some(ConfigFileType::ToolVersionsToml)
+        );
+    }
+
+    #[test]
+    fn test_trust_content() {
+        let settings = Settings::default();
+        let path = env::temp_dir().join("rtx-test-trust").join(".rtx.toml");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "[env]\nFOO = \"1\"\n").unwrap();
+        assert!(!is_trusted(&settings, &path));
+        trust(&path).unwrap();
+        assert!(is_trusted(&settings, &path));
+
+        fs::write(&path, "[env]\nFOO = \"2\"\n").unwrap();
+        assert!(!is_trusted(&settings, &path));
+        update_trust(&path).unwrap();
+        assert!(is_trusted(&settings, &path));
+
+        untrust(&path).unwrap();
+        update_trust(&path).unwrap();
+        assert!(!is_trusted(&settings, &path));
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_migrate_legacy_trust() {
+        let settings = Settings::default();
+        let path = dirs::HOME.join("rtx-test-legacy-trust").join(".rtx.toml");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "[env]\nFOO = \"1\"\n").unwrap();
+        let hashed_path = trust_path(path.clone()).unwrap();
+        fs::create_dir_all(hashed_path.parent().unwrap()).unwrap();
+        fs::write(&hashed_path, "").unwrap();
+        assert!(is_trusted(&settings, &path));
+        assert_eq!(fs::read_to_string(&hashed_path).unwrap(), "");
+
+        migrate_legacy_trust(&path);
+        assert!(is_trusted(&settings, &path));
+        fs::write(&path, "[env]\nFOO = \"2\"\n").unwrap();
+        assert!(!is_trusted(&settings, &path));
+        untrust(&path).unwrap();
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
     }
 }
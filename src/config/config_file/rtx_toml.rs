@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::{Display, Formatter};
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -6,15 +6,19 @@ use std::time::Duration;
 
 use color_eyre::eyre::eyre;
 use color_eyre::{Result, Section};
+use indexmap::IndexMap;
 use log::LevelFilter;
+use regex::Regex;
 use tera::Context;
-use toml_edit::{table, value, Array, Document, Item, Value};
+use toml_edit::{table, value, Array, Document, Item, TableLike, Value};
 
 use crate::config::config_file::{ConfigFile, ConfigFileType};
-use crate::config::settings::SettingsBuilder;
-use crate::config::{config_file, AliasMap, MissingRuntimeBehavior};
+use crate::config::settings::{
+    check_install_dir_template, SettingsBuilder, VersionFilter, VersionPolicy,
+};
+use crate::config::{config_file, AliasMap, EnvPathAdd, MissingRuntimeBehavior, PluginHooks};
 use crate::errors::Error::UntrustedConfig;
-use crate::file::create_dir_all;
+use crate::file::{self, create_dir_all};
 use crate::plugins::{unalias_plugin, PluginName};
 use crate::tera::{get_tera, BASE_CONTEXT};
 use crate::toolset::{
@@ -29,13 +33,28 @@ pub struct RtxToml {
     path: PathBuf,
     toolset: Toolset,
     env_file: Option<PathBuf>,
-    env: HashMap<String, String>,
+    env: IndexMap<String, String>,
+    env_templates: HashMap<String, String>,
+    env_when_installed: HashMap<String, PluginName>,
     env_remove: Vec<String>,
+    env_path_add: HashMap<String, EnvPathAdd>,
+    env_profiles: HashMap<String, IndexMap<String, String>>,
     path_dirs: Vec<PathBuf>,
     settings: SettingsBuilder,
     alias: AliasMap,
+    alias_comments: AliasMap,
     doc: Document,
     plugins: HashMap<String, String>,
+    plugin_settings: HashMap<PluginName, IndexMap<String, String>>,
+    plugin_install_env: HashMap<PluginName, IndexMap<String, String>>,
+    plugin_install_dirs: HashMap<PluginName, PathBuf>,
+    plugin_mirror_urls: HashMap<PluginName, String>,
+    plugin_legacy_filenames: HashMap<PluginName, Vec<String>>,
+    plugin_hooks: HashMap<PluginName, PluginHooks>,
+    includes: Vec<PathBuf>,
+    disable_plugins: Vec<PluginName>,
+    env_isolated: bool,
+    project_name: Option<String>,
     is_trusted: bool,
 }
 
@@ -57,9 +76,21 @@ impl RtxToml {
 
     pub fn from_file(path: &Path, is_trusted: bool) -> Result<Self> {
         trace!("parsing: {}", path.display());
-        let mut rf = Self::init(path, is_trusted);
         let body = fs::read_to_string(path).suggestion("ensure file exists and can be read")?;
-        rf.parse(&body)?;
+        Self::from_contents(path, &body, is_trusted)
+    }
+
+    /// parses `body` as if it were the content of `path`
+    pub fn from_contents(path: &Path, body: &str, is_trusted: bool) -> Result<Self> {
+        let mut rf = Self::init(path, is_trusted);
+        rf.parse(body)?;
+        Ok(rf)
+    }
+
+    #[cfg(test)]
+    pub fn parse_str(s: &str, path: &Path) -> Result<Self> {
+        let mut rf = Self::init(path, true);
+        rf.parse(s)?;
         Ok(rf)
     }
 
@@ -84,7 +115,17 @@ impl RtxToml {
                 "alias" => self.alias = self.parse_alias(k, v)?,
                 "tools" => self.toolset = self.parse_toolset(k, v)?,
                 "settings" => self.settings = self.parse_settings(k, v)?,
-                "plugins" => self.plugins = self.parse_plugins(k, v)?,
+                "plugins" => self.parse_plugins(k, v)?,
+                "include" => self.includes = self.parse_includes(k, v)?,
+                "project" => self.parse_project(k, v)?,
+                "env_isolated" => self.env_isolated = self.parse_bool(k, v)?,
+                "disable_plugins" => {
+                    self.disable_plugins = self
+                        .parse_string_array(&k.to_string(), v)?
+                        .iter()
+                        .map(|p| unalias_plugin(p))
+                        .collect()
+                }
                 _ => Err(eyre!("unknown key: {}", k))?,
             }
         }
@@ -132,8 +173,34 @@ impl RtxToml {
             Some(table) => {
                 for (k, v) in table.iter() {
                     let key = format!("{}.{}", key, k);
+                    if k == "unset" && v.is_array() {
+                        // e.g.: `unset = ["FOO"]` removes FOO even if set by another config file
+                        let unset = self.parse_string_array(&key, v)?;
+                        self.env_remove.extend(unset);
+                        continue;
+                    }
+                    if k == "path_add" && v.is_table_like() {
+                        self.parse_env_path_add(&key, v)?;
+                        continue;
+                    }
+                    if k == "profiles" && v.is_table_like() {
+                        self.parse_env_profiles(&key, v)?;
+                        continue;
+                    }
                     let k = self.parse_template(&key, k)?;
+                    if let Some(table) = v.as_table_like() {
+                        // e.g.: `CARGO_HOME = { value = "...", when_installed = "rust" }`
+                        let (value, plugin) = self.parse_env_when_installed(&key, table)?;
+                        self.env_templates.insert(k.clone(), value);
+                        self.env_when_installed.insert(k, plugin);
+                        continue;
+                    }
                     if let Some(v) = v.as_str() {
+                        if v.contains("install_path(") {
+                            // install paths are only known after the toolset is resolved
+                            self.env_templates.insert(k, v.to_string());
+                            continue;
+                        }
                         let v = self.parse_template(&key, v)?;
                         self.env.insert(k, v);
                     } else if let Some(v) = v.as_bool() {
@@ -141,7 +208,7 @@ impl RtxToml {
                             self.env_remove.push(k);
                         }
                     } else {
-                        parse_error!(key, v, "string or bool")?;
+                        parse_error!(key, v, "string, bool, or table")?;
                     }
                 }
             }
@@ -150,24 +217,104 @@ impl RtxToml {
         Ok(())
     }
 
+    /// the value is rendered once the toolset is resolved, like values using `install_path()`
+    fn parse_env_when_installed(
+        &self,
+        key: &str,
+        table: &dyn TableLike,
+    ) -> Result<(String, PluginName)> {
+        let mut value = None;
+        let mut plugin = None;
+        for (name, v) in table.iter() {
+            let k = format!("{}.{}", key, name);
+            match (name, v.as_str()) {
+                ("value", Some(v)) => value = Some(v.to_string()),
+                ("when_installed", Some(p)) => plugin = Some(unalias_plugin(p)),
+                ("value" | "when_installed", None) => parse_error!(k, v, "string")?,
+                _ => Err(eyre!("unknown key: {}", k))?,
+            }
+        }
+        match (value, plugin) {
+            (Some(value), Some(plugin)) => Ok((value, plugin)),
+            _ => Err(eyre!("{key} needs both value and when_installed")),
+        }
+    }
+
+    /// e.g.: `[env.profiles.prod]` with `API_URL = "https://..."`, only the vars of the profile
+    /// selected with RTX_PROFILE are set
+    fn parse_env_profiles(&mut self, key: &str, v: &Item) -> Result<()> {
+        for (name, v) in v.as_table_like().unwrap().iter() {
+            let key = format!("{}.{}", key, name);
+            let Some(table) = v.as_table_like() else {
+                return parse_error!(key, v, "table");
+            };
+            let mut vars = IndexMap::new();
+            for (k, v) in table.iter() {
+                let key = format!("{}.{}", key, k);
+                let k = self.parse_template(&key, k)?;
+                let v = self.parse_string(&key, v)?;
+                vars.insert(k, self.parse_template(&key, &v)?);
+            }
+            self.env_profiles.insert(name.to_string(), vars);
+        }
+        Ok(())
+    }
+
+    /// e.g.: `MANPATH = ["./man", "/opt/man"]` or `CLASSPATH = { paths = ["./lib"], separator = ";" }`
+    fn parse_env_path_add(&mut self, key: &str, v: &Item) -> Result<()> {
+        for (k, v) in v.as_table_like().unwrap().iter() {
+            let key = format!("{}.{}", key, k);
+            let mut separator = None;
+            let paths = match v.as_table_like() {
+                Some(table) => {
+                    let mut paths = vec![];
+                    for (name, v) in table.iter() {
+                        let k = format!("{}.{}", key, name);
+                        match name {
+                            "paths" => paths = self.parse_path_env(&k, v)?,
+                            "separator" => separator = Some(self.parse_string(&k, v)?),
+                            _ => Err(eyre!("unknown key: {}", k))?,
+                        }
+                    }
+                    paths
+                }
+                None if v.is_str() => {
+                    let s = self.parse_template(&key, v.as_str().unwrap())?;
+                    vec![self.expand_path(&s)]
+                }
+                None => self.parse_path_env(&key, v)?,
+            };
+            let paths = paths
+                .iter()
+                .map(|p| p.to_string_lossy().to_string())
+                .collect();
+            let k = self.parse_template(&key, k)?;
+            self.env_path_add.insert(k, EnvPathAdd { paths, separator });
+        }
+        Ok(())
+    }
+
+    /// paths starting with `./` are relative to the config file, not PWD
+    fn expand_path(&self, s: &str) -> PathBuf {
+        match s.strip_prefix("./") {
+            Some(s) => self.path.parent().unwrap().join(s),
+            None => match s.strip_prefix("~/") {
+                Some(s) => dirs::HOME.join(s),
+                None => s.into(),
+            },
+        }
+    }
+
     fn parse_path_env(&mut self, k: &str, v: &Item) -> Result<Vec<PathBuf>> {
         self.trust_check()?;
         match v.as_array() {
             Some(array) => {
                 let mut path = Vec::new();
-                let config_root = self.path.parent().unwrap().to_path_buf();
                 for v in array {
                     match v.as_str() {
                         Some(s) => {
                             let s = self.parse_template(k, s)?;
-                            let s = match s.strip_prefix("./") {
-                                Some(s) => config_root.join(s),
-                                None => match s.strip_prefix("~/") {
-                                    Some(s) => dirs::HOME.join(s),
-                                    None => s.into(),
-                                },
-                            };
-                            path.push(s);
+                            path.push(self.expand_path(&s));
                         }
                         _ => parse_error!(k, v, "string")?,
                     }
@@ -192,6 +339,12 @@ impl RtxToml {
                                     Some(s) => {
                                         let from = self.parse_template(&k, from)?;
                                         let s = self.parse_template(&k, s)?;
+                                        if let Some(comment) = parse_trailing_comment(to) {
+                                            self.alias_comments
+                                                .entry(plugin.into())
+                                                .or_default()
+                                                .insert(from.clone(), comment);
+                                        }
                                         plugin_aliases.insert(from, s);
                                     }
                                     _ => parse_error!(format!("{}.{}", k, from), to, "string")?,
@@ -207,29 +360,116 @@ impl RtxToml {
         }
     }
 
-    fn parse_plugins(&mut self, key: &str, v: &Item) -> Result<HashMap<String, String>> {
+    fn parse_includes(&mut self, k: &str, v: &Item) -> Result<Vec<PathBuf>> {
         self.trust_check()?;
-        self.parse_hashmap(key, v)
+        let root = self.path.parent().unwrap();
+        Ok(self
+            .parse_string_array(&k.to_string(), v)?
+            .into_iter()
+            .map(|f| root.join(f))
+            .collect())
     }
 
-    fn parse_hashmap(&mut self, key: &str, v: &Item) -> Result<HashMap<String, String>> {
-        match v.as_table_like() {
-            Some(table) => {
-                let mut env = HashMap::new();
-                for (k, v) in table.iter() {
-                    match v.as_str() {
-                        Some(s) => {
-                            let k = self.parse_template(key, k)?;
-                            let s = self.parse_template(key, s)?;
-                            env.insert(k, s);
-                        }
-                        _ => parse_error!(key, v, "string")?,
+    /// `[project]`, only `name` is accepted, see `Config::project_name`
+    fn parse_project(&mut self, key: &str, v: &Item) -> Result<()> {
+        let table = match v.as_table_like() {
+            Some(table) => table,
+            None => return parse_error!(key, v, "table"),
+        };
+        for (k, v) in table.iter() {
+            let pk = format!("{}.{}", key, k);
+            match k {
+                "name" => self.project_name = Some(self.parse_string(&pk, v)?),
+                _ => Err(eyre!("unknown key: {}", pk))?,
+            }
+        }
+        Ok(())
+    }
+
+    /// `name = "url"` or a table with an optional `url` and plugin-defined `settings`
+    fn parse_plugins(&mut self, key: &str, v: &Item) -> Result<()> {
+        self.trust_check()?;
+        let table = match v.as_table_like() {
+            Some(table) => table,
+            None => return parse_error!(key, v, "table"),
+        };
+        for (plugin, v) in table.iter() {
+            let k = format!("{}.{}", key, plugin);
+            let plugin_name = unalias_plugin(plugin);
+            if v.as_str().is_some() {
+                let url = self.parse_string(&k, v)?;
+                self.plugins.insert(plugin.to_string(), url);
+                continue;
+            }
+            let Some(plugin_table) = v.as_table_like() else {
+                return parse_error!(k, v, "string or table");
+            };
+            for (name, v) in plugin_table.iter() {
+                let pk = format!("{}.{}", k, name);
+                match name {
+                    "url" => {
+                        let url = self.parse_string(&pk, v)?;
+                        self.plugins.insert(plugin.to_string(), url);
+                    }
+                    "settings" => {
+                        let settings = self.parse_plugin_settings(&pk, v)?;
+                        self.plugin_settings.insert(plugin_name.clone(), settings);
+                    }
+                    "install_env" => {
+                        let env = self.parse_plugin_settings(&pk, v)?;
+                        self.plugin_install_env.insert(plugin_name.clone(), env);
+                    }
+                    "install_dir" => {
+                        let dir = self.parse_path(&pk, v)?;
+                        let dir = match dir.strip_prefix("~") {
+                            Ok(dir) => dirs::HOME.join(dir),
+                            Err(_) => self.path.parent().unwrap().join(dir),
+                        };
+                        self.plugin_install_dirs.insert(plugin_name.clone(), dir);
+                    }
+                    "mirror_url" => {
+                        let url = self.parse_string(&pk, v)?;
+                        self.plugin_mirror_urls.insert(plugin_name.clone(), url);
+                    }
+                    "legacy_filenames" => {
+                        let filenames = self.parse_string_array(&pk, v)?;
+                        self.plugin_legacy_filenames
+                            .insert(plugin_name.clone(), filenames);
+                    }
+                    "pre_install" => {
+                        let cmd = self.parse_string(&pk, v)?;
+                        let hooks = self.plugin_hooks.entry(plugin_name.clone()).or_default();
+                        hooks.pre_install = Some(cmd);
+                    }
+                    "post_uninstall" => {
+                        let cmd = self.parse_string(&pk, v)?;
+                        let hooks = self.plugin_hooks.entry(plugin_name.clone()).or_default();
+                        hooks.post_uninstall = Some(cmd);
                     }
+                    _ => Err(eyre!("unknown key: {}", pk))?,
                 }
-                Ok(env)
             }
-            _ => parse_error!(key, v, "table"),
         }
+        Ok(())
+    }
+
+    /// plugins define their own settings so any key is allowed
+    fn parse_plugin_settings(&mut self, key: &str, v: &Item) -> Result<IndexMap<String, String>> {
+        let Some(table) = v.as_table_like() else {
+            return parse_error!(key, v, "table");
+        };
+        let mut settings = IndexMap::new();
+        for (k, v) in table.iter() {
+            let v = match v.as_value() {
+                Some(Value::String(s)) => self.parse_template(key, s.value())?,
+                Some(Value::Boolean(b)) => b.value().to_string(),
+                Some(Value::Integer(i)) => i.value().to_string(),
+                Some(Value::Float(f)) => f.value().to_string(),
+                _ => parse_error!(format!("{}.{}", key, k), v, "string, boolean, or number")?,
+            };
+            settings.insert(k.to_string(), v);
+        }
+        Ok(settings)
     }
 
     fn parse_toolset(&mut self, key: &str, v: &Item) -> Result<Toolset> {
@@ -239,8 +479,12 @@ impl RtxToml {
             Some(table) => {
                 for (plugin, v) in table.iter() {
                     let k = format!("{}.{}", key, plugin);
-                    let plugin_name = unalias_plugin(plugin).to_string();
-                    let tvl = self.parse_tool_version_list(&k, v, &plugin_name)?;
+                    let plugin_name = unalias_plugin(plugin);
+                    let mut tvl = self.parse_tool_version_list(&k, v, &plugin_name)?;
+                    if let Some(prev) = toolset.versions.get(&plugin_name) {
+                        let requests = prev.requests.iter().map(|(tvr, _)| tvr);
+                        tvl.shadowed = prev.shadowed.iter().chain(requests).cloned().collect();
+                    }
                     toolset.versions.insert(plugin_name, tvl);
                 }
                 Ok(toolset)
@@ -318,7 +562,8 @@ impl RtxToml {
                     match path.as_str() {
                         Some(s) => {
                             let s = self.parse_template(key, s)?;
-                            tv = ToolVersionRequest::Path(plugin_name.clone(), s.into());
+                            let s = format!("path:{s}");
+                            tv = ToolVersionRequest::new(plugin_name.clone(), &s);
                         }
                         _ => parse_error!(format!("{}.path", key), v, "string")?,
                     }
@@ -326,7 +571,8 @@ impl RtxToml {
                     match prefix.as_str() {
                         Some(s) => {
                             let s = self.parse_template(key, s)?;
-                            tv = ToolVersionRequest::Prefix(plugin_name.clone(), s);
+                            let s = format!("prefix:{s}");
+                            tv = ToolVersionRequest::new(plugin_name.clone(), &s);
                         }
                         _ => parse_error!(format!("{}.prefix", key), v, "string")?,
                     }
@@ -334,7 +580,8 @@ impl RtxToml {
                     match r.as_str() {
                         Some(s) => {
                             let s = self.parse_template(key, s)?;
-                            tv = ToolVersionRequest::Ref(plugin_name.clone(), s);
+                            let s = format!("ref:{s}");
+                            tv = ToolVersionRequest::new(plugin_name.clone(), &s);
                         }
                         _ => parse_error!(format!("{}.ref", key), v, "string")?,
                     }
@@ -362,7 +609,7 @@ impl RtxToml {
             },
         }
 
-        Ok((tv, opts))
+        Ok((tv.relative_to(self.path.parent().unwrap()), opts))
     }
 
     fn parse_tool_version_request(
@@ -420,6 +667,14 @@ impl RtxToml {
                         "shorthands_file" => {
                             settings.shorthands_file = Some(self.parse_path(&k, v)?)
                         }
+                        "mirror_prefix" => settings.mirror_prefix = Some(self.parse_string(&k, v)?),
+                        "install_dir_template" => {
+                            let template = self.parse_string(&k, v)?;
+                            if let Some(err) = check_install_dir_template(&template) {
+                                Err(eyre!("invalid {k}: {err}"))?;
+                            }
+                            settings.install_dir_template = Some(template)
+                        }
                         "disable_default_shorthands" => {
                             settings.disable_default_shorthands = Some(self.parse_bool(&k, v)?)
                         }
@@ -428,7 +683,50 @@ impl RtxToml {
                                 self.parse_string_array(&k, v)?.into_iter().collect()
                         }
                         "log_level" => settings.log_level = Some(self.parse_log_level(&k, v)?),
+                        "disable_version_change_warnings" => {
+                            settings.disable_version_change_warnings = Some(self.parse_bool(&k, v)?)
+                        }
+                        "offline" => settings.offline = Some(self.parse_bool(&k, v)?),
                         "raw" => settings.raw = Some(self.parse_bool(&k, v)?),
+                        "refresh_floating_aliases" => {
+                            settings.refresh_floating_aliases = Some(self.parse_bool(&k, v)?)
+                        }
+                        "prefer_installed_versions" => {
+                            settings.prefer_installed_versions = Some(self.parse_bool(&k, v)?)
+                        }
+                        "source_precedence" => {
+                            settings.source_precedence = Some(self.parse_string_array(&k, v)?)
+                        }
+                        "strict" => settings.strict = Some(self.parse_bool(&k, v)?),
+                        "require_checksums" => {
+                            settings.require_checksums = Some(self.parse_bool(&k, v)?)
+                        }
+                        "default_plugins" => {
+                            settings.default_plugins =
+                                self.parse_string_array(&k, v)?.into_iter().collect()
+                        }
+                        "bootstrap_default_plugins" => {
+                            settings.bootstrap_default_plugins = Some(self.parse_bool(&k, v)?)
+                        }
+                        "env_overrides_shell" => {
+                            settings.env_overrides_shell = Some(self.parse_bool(&k, v)?)
+                        }
+                        "default_config_filename" => {
+                            settings.default_config_filename = Some(self.parse_string(&k, v)?)
+                        }
+                        "default_tool_versions_filename" => {
+                            settings.default_tool_versions_filename =
+                                Some(self.parse_string(&k, v)?)
+                        }
+                        "plugin_aliases" => {
+                            settings.plugin_aliases = self.parse_string_map(&k, v)?
+                        }
+                        "version_policy" => {
+                            settings.version_policy = self.parse_version_policy(&k, v)?
+                        }
+                        "version_filter" => {
+                            settings.version_filter = self.parse_version_filter(&k, v)?
+                        }
                         _ => Err(eyre!("Unknown config setting: {}", k))?,
                     };
                 }
@@ -460,6 +758,9 @@ impl RtxToml {
         if let Some(aliases) = self.doc.get_mut("alias").and_then(|v| v.as_table_mut()) {
             if let Some(plugin_aliases) = aliases.get_mut(plugin).and_then(|v| v.as_table_mut()) {
                 self.alias.get_mut(plugin).unwrap().remove(from);
+                if let Some(comments) = self.alias_comments.get_mut(plugin) {
+                    comments.remove(from);
+                }
                 plugin_aliases.remove(from);
                 if plugin_aliases.is_empty() {
                     aliases.remove(plugin);
@@ -480,7 +781,7 @@ impl RtxToml {
         }
     }
 
-    fn parse_bool(&mut self, k: &str, v: &Item) -> Result<bool> {
+    fn parse_bool(&self, k: &str, v: &Item) -> Result<bool> {
         match v.as_value().map(|v| v.as_bool()) {
             Some(Some(v)) => Ok(v),
             _ => parse_error!(k, v, "boolean")?,
@@ -544,6 +845,91 @@ impl RtxToml {
         }
     }
 
+    fn parse_string_map(&self, k: &str, v: &Item) -> Result<BTreeMap<String, String>> {
+        match v.as_table_like() {
+            Some(table) => {
+                let mut map = BTreeMap::new();
+                for (key, v) in table.iter() {
+                    match v.as_str() {
+                        Some(s) => map.insert(key.to_string(), s.to_string()),
+                        _ => parse_error!(format!("{}.{}", k, key), v, "string")?,
+                    };
+                }
+                Ok(map)
+            }
+            _ => parse_error!(k, v, "table")?,
+        }
+    }
+
+    fn parse_version_policy(
+        &self,
+        k: &str,
+        v: &Item,
+    ) -> Result<BTreeMap<PluginName, VersionPolicy>> {
+        let mut policies = BTreeMap::new();
+        match v.as_table_like() {
+            Some(table) => {
+                for (plugin, v) in table.iter() {
+                    let k = format!("{k}.{plugin}");
+                    let mut policy = VersionPolicy::default();
+                    match v.as_table_like() {
+                        Some(table) => {
+                            for (key, v) in table.iter() {
+                                let k = format!("{k}.{key}");
+                                match key {
+                                    "deny_prerelease" => {
+                                        policy.deny_prerelease = self.parse_bool(&k, v)?
+                                    }
+                                    "require_semver" => {
+                                        policy.require_semver = self.parse_bool(&k, v)?
+                                    }
+                                    "allow" => policy.allow = self.parse_string_array(&k, v)?,
+                                    "deny" => policy.deny = self.parse_string_array(&k, v)?,
+                                    _ => Err(eyre!("Unknown version policy: {}", k))?,
+                                }
+                            }
+                        }
+                        None => parse_error!(k, v, "table")?,
+                    }
+                    policies.insert(unalias_plugin(plugin), policy);
+                }
+            }
+            None => parse_error!(k, v, "table")?,
+        }
+        Ok(policies)
+    }
+
+    fn parse_version_filter(
+        &mut self,
+        k: &str,
+        v: &Item,
+    ) -> Result<BTreeMap<PluginName, VersionFilter>> {
+        let mut filters = BTreeMap::new();
+        let Some(table) = v.as_table_like() else {
+            return parse_error!(k, v, "table");
+        };
+        for (plugin, v) in table.iter() {
+            let k = format!("{k}.{plugin}");
+            let Some(table) = v.as_table_like() else {
+                return parse_error!(k, v, "table");
+            };
+            let mut filter = VersionFilter::default();
+            for (key, v) in table.iter() {
+                let k = format!("{k}.{key}");
+                let re = match key {
+                    "include" => &mut filter.include,
+                    "exclude" => &mut filter.exclude,
+                    _ => Err(eyre!("Unknown version filter: {}", k))?,
+                };
+                let s = self.parse_string(&k, v)?;
+                let regex = Regex::new(&s).map_err(|err| eyre!("invalid regex for {k}: {err}"))?;
+                *re = Some(regex);
+            }
+            filters.insert(unalias_plugin(plugin), filter);
+        }
+        Ok(filters)
+    }
+
     fn parse_missing_runtime_behavior(
         &mut self,
         k: &str,
@@ -666,6 +1052,16 @@ impl RtxToml {
     }
 }
 
+/// returns the text of a comment trailing a value, e.g.: `lts = "18" # pinned for project X`
+fn parse_trailing_comment(v: &Item) -> Option<String> {
+    let suffix = v.as_value()?.decor().suffix()?.as_str()?;
+    let comment = suffix.trim().strip_prefix('#')?.trim();
+    match comment.is_empty() {
+        true => None,
+        false => Some(comment.to_string()),
+    }
+}
+
 impl Display for RtxToml {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.dump())
@@ -685,14 +1081,30 @@ impl ConfigFile for RtxToml {
         self.plugins.clone()
     }
 
-    fn env(&self) -> HashMap<String, String> {
+    fn env(&self) -> IndexMap<String, String> {
         self.env.clone()
     }
 
+    fn env_templates(&self) -> HashMap<String, String> {
+        self.env_templates.clone()
+    }
+
+    fn env_when_installed(&self) -> HashMap<String, PluginName> {
+        self.env_when_installed.clone()
+    }
+
     fn env_remove(&self) -> Vec<String> {
         self.env_remove.clone()
     }
 
+    fn env_path_add(&self) -> HashMap<String, EnvPathAdd> {
+        self.env_path_add.clone()
+    }
+
+    fn env_profiles(&self) -> HashMap<String, IndexMap<String, String>> {
+        self.env_profiles.clone()
+    }
+
     fn path_dirs(&self) -> Vec<PathBuf> {
         self.path_dirs.clone()
     }
@@ -744,7 +1156,8 @@ impl ConfigFile for RtxToml {
         if let Some(parent) = self.path.parent() {
             create_dir_all(parent)?;
         }
-        Ok(fs::write(&self.path, contents)?)
+        file::write_atomic(&self.path, contents)?;
+        config_file::update_trust(&self.path)
     }
 
     fn dump(&self) -> String {
@@ -763,6 +1176,50 @@ impl ConfigFile for RtxToml {
         self.alias.clone()
     }
 
+    fn alias_comments(&self) -> AliasMap {
+        self.alias_comments.clone()
+    }
+
+    fn includes(&self) -> Vec<PathBuf> {
+        self.includes.clone()
+    }
+
+    fn disable_plugins(&self) -> Vec<PluginName> {
+        self.disable_plugins.clone()
+    }
+
+    fn env_isolated(&self) -> bool {
+        self.env_isolated
+    }
+
+    fn project_name(&self) -> Option<String> {
+        self.project_name.clone()
+    }
+
+    fn plugin_settings(&self) -> HashMap<PluginName, IndexMap<String, String>> {
+        self.plugin_settings.clone()
+    }
+
+    fn plugin_install_env(&self) -> HashMap<PluginName, IndexMap<String, String>> {
+        self.plugin_install_env.clone()
+    }
+
+    fn plugin_install_dirs(&self) -> HashMap<PluginName, PathBuf> {
+        self.plugin_install_dirs.clone()
+    }
+
+    fn plugin_mirror_urls(&self) -> HashMap<PluginName, String> {
+        self.plugin_mirror_urls.clone()
+    }
+
+    fn plugin_legacy_filenames(&self) -> HashMap<PluginName, Vec<String>> {
+        self.plugin_legacy_filenames.clone()
+    }
+
+    fn plugin_hooks(&self) -> HashMap<PluginName, PluginHooks> {
+        self.plugin_hooks.clone()
+    }
+
     fn watch_files(&self) -> Vec<PathBuf> {
         match &self.env_file {
             Some(env_file) => vec![self.path.clone(), env_file.clone()],
@@ -773,7 +1230,7 @@ impl ConfigFile for RtxToml {
 
 #[cfg(test)]
 mod tests {
-    use indoc::formatdoc;
+    use indoc::{formatdoc, indoc};
     use insta::{assert_debug_snapshot, assert_display_snapshot, assert_snapshot};
 
     use crate::dirs;
@@ -812,6 +1269,132 @@ mod tests {
         assert_display_snapshot!(cf);
     }
 
+    #[test]
+    fn test_env_when_installed() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.parse(&formatdoc! {r#"
+        [env]
+        CARGO_HOME = {{ value = "{{{{ config_root }}}}/cargo", when_installed = "rust" }}
+        "#})
+            .unwrap();
+        assert_eq!(
+            cf.env_templates(),
+            HashMap::from([("CARGO_HOME".into(), "{{ config_root }}/cargo".into())])
+        );
+        assert_eq!(
+            cf.env_when_installed(),
+            HashMap::from([("CARGO_HOME".into(), "rust".into())])
+        );
+        assert!(cf.env().is_empty());
+
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        let err = cf.parse("[env]\nCARGO_HOME = { value = 'x' }").unwrap_err();
+        assert_display_snapshot!(err, @"env.CARGO_HOME needs both value and when_installed");
+    }
+
+    #[test]
+    fn test_env_profiles() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.parse(&formatdoc! {r#"
+        [env]
+        API_URL = "http://localhost"
+        [env.profiles.prod]
+        API_URL = "https://example.com"
+        DATA_DIR = "{{{{ config_root }}}}/data"
+        "#})
+            .unwrap();
+        assert_eq!(
+            cf.env_profiles()["prod"],
+            IndexMap::<String, String>::from([
+                ("API_URL".into(), "https://example.com".into()),
+                ("DATA_DIR".into(), "/tmp/data".into())
+            ])
+        );
+        assert_eq!(cf.env()["API_URL"], "http://localhost");
+    }
+
+    #[test]
+    fn test_env_unset() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.parse(&formatdoc! {r#"
+        [env]
+        foo="bar"
+        unset=["BAZ", "QUX"]
+        "#})
+            .unwrap();
+
+        assert_debug_snapshot!(cf.env(), @r###"
+        {
+            "foo": "bar",
+        }
+        "###);
+        assert_debug_snapshot!(cf.env_remove(), @r###"
+        [
+            "BAZ",
+            "QUX",
+        ]
+        "###);
+    }
+
+    #[test]
+    fn test_plugins() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.parse(&formatdoc! {r#"
+        [plugins]
+        tiny = "https://github.com/jdxcode/rtx-tiny"
+        [plugins.nodejs]
+        url = "https://github.com/asdf-vm/asdf-nodejs"
+        [plugins.nodejs.settings]
+        corepack = true
+        mirror = "https://example.com"
+        [plugins.python]
+        install_dir = "sdks/python"
+        [plugins.python.install_env]
+        PYTHON_CONFIGURE_OPTS = "--enable-optimizations"
+        [plugins.java]
+        install_dir = "/mnt/sdks/java"
+        legacy_filenames = [".jdk-version"]
+        pre_install = "df -h /mnt/sdks"
+        post_uninstall = "rm -f ~/.jdk-current"
+        "#})
+            .unwrap();
+
+        assert_debug_snapshot!(cf.plugins().get("nodejs"), @r###"
+        Some(
+            "https://github.com/asdf-vm/asdf-nodejs",
+        )
+        "###);
+        assert_debug_snapshot!(cf.plugin_settings(), @r###"
+        {
+            "node": {
+                "corepack": "true",
+                "mirror": "https://example.com",
+            },
+        }
+        "###);
+        assert_eq!(
+            cf.plugin_install_env()["python"]["PYTHON_CONFIGURE_OPTS"],
+            "--enable-optimizations"
+        );
+        let install_dirs = cf.plugin_install_dirs();
+        assert_eq!(install_dirs["python"], PathBuf::from("/tmp/sdks/python"));
+        assert_eq!(install_dirs["java"], PathBuf::from("/mnt/sdks/java"));
+        assert_eq!(
+            cf.plugin_legacy_filenames()["java"],
+            vec![".jdk-version".to_string()]
+        );
+        assert_debug_snapshot!(cf.plugin_hooks()["java"], @r###"
+        PluginHooks {
+            pre_install: Some(
+                "df -h /mnt/sdks",
+            ),
+            post_uninstall: Some(
+                "rm -f ~/.jdk-current",
+            ),
+        }
+        "###);
+    }
+
     #[test]
     fn test_path_dirs() {
         let p = dirs::HOME.join("fixtures/.rtx.toml");
@@ -850,6 +1433,25 @@ mod tests {
         assert_display_snapshot!(cf);
     }
 
+    #[test]
+    fn test_alias_comments() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.parse(&formatdoc! {r#"
+        [alias.node]
+        lts = "18" # pinned for project X
+        20 = "20.0.0"
+        "#})
+            .unwrap();
+
+        assert_debug_snapshot!(cf.alias_comments(), @r###"
+        {
+            "node": {
+                "lts": "pinned for project X",
+            },
+        }
+        "###);
+    }
+
     #[test]
     fn test_remove_alias() {
         let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
@@ -900,6 +1502,74 @@ mod tests {
         assert_display_snapshot!(cf);
     }
 
+    #[test]
+    fn test_version_policy() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.parse(&formatdoc! {r#"
+        [settings.version_policy.nodejs]
+        deny_prerelease = true
+        allow = ["20"]
+        "#})
+            .unwrap();
+        let policy = cf.settings().version_policy.remove("node").unwrap();
+        assert!(policy.deny_prerelease);
+        assert!(!policy.require_semver);
+        assert_eq!(policy.allow, vec!["20"]);
+
+        let err = cf
+            .parse(&formatdoc! {r#"
+        [settings.version_policy.node]
+        deny_rc = true
+        "#})
+            .unwrap_err();
+        assert_snapshot!(err.to_string(), @"Unknown version policy: settings.version_policy.node.deny_rc");
+    }
+
+    #[test]
+    fn test_install_dir_template() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.parse(indoc! {r#"
+        [settings]
+        install_dir_template = "/sdks/{plugin}/{version}"
+        "#})
+            .unwrap();
+        assert_eq!(
+            cf.settings().install_dir_template,
+            Some("/sdks/{plugin}/{version}".into())
+        );
+
+        let err = cf
+            .parse(indoc! {r#"
+        [settings]
+        install_dir_template = "/sdks/{plugin}"
+        "#})
+            .unwrap_err();
+        assert_snapshot!(err.to_string(), @"invalid settings.install_dir_template: it must end with /{version}");
+    }
+
+    #[test]
+    fn test_version_filter() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.parse(&formatdoc! {r#"
+        [settings.version_filter.nodejs]
+        exclude = "musl"
+        "#})
+            .unwrap();
+        let filter = cf.settings().version_filter.remove("node").unwrap();
+        assert!(filter.include.is_none());
+        assert_eq!(filter.exclude.unwrap().as_str(), "musl");
+
+        let err = cf
+            .parse(&formatdoc! {r#"
+        [settings.version_filter.node]
+        include = "^(18"
+        "#})
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid regex for settings.version_filter.node.include"));
+    }
+
     #[test]
     fn test_update_setting() {
         let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
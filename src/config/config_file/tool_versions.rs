@@ -1,19 +1,19 @@
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
-use std::fs;
 use std::fs::read_to_string;
 use std::path::{Path, PathBuf};
 
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 use console::{measure_text_width, pad_str, Alignment};
 use indexmap::IndexMap;
 use itertools::Itertools;
 use tera::Context;
 
-use crate::config::config_file::{ConfigFile, ConfigFileType};
+use crate::config::config_file::{ConfigFile, ConfigFileType, Issue};
 use crate::config::settings::SettingsBuilder;
-use crate::config::AliasMap;
-use crate::file::display_path;
+use crate::config::{config_file, AliasMap, Config};
+use crate::dirs;
+use crate::file::{self, display_path};
 use crate::plugins::{unalias_plugin, PluginName};
 use crate::tera::{get_tera, BASE_CONTEXT};
 use crate::toolset::{ToolSource, ToolVersionRequest, Toolset};
@@ -38,6 +38,10 @@ struct ToolVersionPlugin {
     orig_name: String,
     versions: Vec<String>,
     post: String,
+    /// from a `# plugin=<url>` annotation, where the plugin is installed from if it's missing
+    url: Option<String>,
+    /// versions from earlier lines for the same plugin, which this line replaces
+    shadowed: Vec<String>,
 }
 
 impl ToolVersions {
@@ -79,6 +83,20 @@ impl ToolVersions {
         Ok(cf)
     }
 
+    /// parses `.tool-versions` contents that aren't in a file (RTX_TOOL_VERSIONS or stdin), relative
+    /// paths are relative to the cwd. A plugin without a version is an error instead of being
+    /// ignored since there's no file to fix it in.
+    pub fn parse_toolset(s: &str, source: ToolSource) -> Result<Toolset> {
+        let mut cf = Self::init(&dirs::CURRENT.join(".tool-versions"), false);
+        cf.plugins = Self::parse_plugins(s)?;
+        if let Some((plugin, _)) = cf.plugins.iter().find(|(_, tvp)| tvp.versions.is_empty()) {
+            return Err(eyre!("invalid {source}: no version for {plugin}"));
+        }
+        cf.toolset = Toolset::new(source);
+        cf.populate_toolset();
+        Ok(cf.toolset)
+    }
+
     fn get_or_create_plugin(&mut self, plugin: &str) -> &mut ToolVersionPlugin {
         self.plugins
             .entry(plugin.to_string())
@@ -86,6 +104,8 @@ impl ToolVersions {
                 orig_name: plugin.to_string(),
                 versions: vec![],
                 post: "".into(),
+                url: None,
+                shadowed: vec![],
             })
     }
 
@@ -100,21 +120,21 @@ impl ToolVersions {
                 continue;
             }
             let (line, post) = line.split_once('#').unwrap_or((line, ""));
-            let mut parts = line.split_whitespace();
-            if let Some(plugin) = parts.next() {
-                // handle invalid trailing colons in `.tool-versions` files
-                // note that this method will cause the colons to be removed
-                // permanently if saving the file again, but I think that's fine
-                let orig_plugin = plugin.trim_end_matches(':');
+            if let Some((orig_plugin, versions)) = parse_line(line) {
                 let plugin = unalias_plugin(orig_plugin);
-
+                let shadowed = match plugins.get(&plugin) {
+                    Some(prev) => [&prev.shadowed[..], &prev.versions[..]].concat(),
+                    None => vec![],
+                };
                 let tvp = ToolVersionPlugin {
+                    shadowed,
                     orig_name: orig_plugin.to_string(),
-                    versions: parts.map(|v| v.to_string()).collect(),
+                    versions,
                     post: match post {
                         "" => String::from("\n"),
                         _ => [" #", post, "\n"].join(""),
                     },
+                    url: parse_plugin_url(post),
                 };
                 plugins.insert(plugin.to_string(), tvp);
             }
@@ -131,11 +151,72 @@ impl ToolVersions {
     fn populate_toolset(&mut self) {
         for (plugin, tvp) in &self.plugins {
             for version in &tvp.versions {
-                let tvr = ToolVersionRequest::new(plugin.clone(), version);
+                let tvr = ToolVersionRequest::new(plugin.clone(), version)
+                    .relative_to(self.path.parent().unwrap());
                 self.toolset.add_version(tvr, Default::default())
             }
+            if let Some(tvl) = self.toolset.versions.get_mut(plugin) {
+                for version in &tvp.shadowed {
+                    let tvr = ToolVersionRequest::new(plugin.clone(), version)
+                        .relative_to(self.path.parent().unwrap());
+                    tvl.shadowed.push(tvr);
+                }
+            }
+        }
+    }
+}
+
+/// splits a line (without its comment) like `node 20 18` into the plugin and its versions
+pub fn parse_line(line: &str) -> Option<(&str, Vec<String>)> {
+    let mut parts = line.split_whitespace();
+    // handle invalid trailing colons in `.tool-versions` files
+    // note that this method will cause the colons to be removed
+    // permanently if saving the file again, but I think that's fine
+    let plugin = parts.next()?.trim_end_matches(':');
+    Some((plugin, parts.map(|v| v.to_string()).collect()))
+}
+
+/// the url from a `plugin=<url>` annotation in a line's comment, e.g.:
+/// `node 20 # plugin=https://github.com/org/asdf-node`
+fn parse_plugin_url(comment: &str) -> Option<String> {
+    comment
+        .split_whitespace()
+        .find_map(|s| s.strip_prefix("plugin="))
+        .filter(|url| !url.is_empty())
+        .map(|url| url.to_string())
+}
+
+/// checks each line of a .tool-versions file for unknown plugins, bad versions, and plugins
+/// listed more than once
+pub fn validate(s: &str, config: &Config) -> Vec<Issue> {
+    let mut issues = vec![];
+    let mut seen: HashMap<PluginName, usize> = HashMap::new();
+    for (i, line) in s.lines().enumerate() {
+        let line_no = i + 1;
+        let issue = |message: String| Issue::new(Some(line_no), message);
+        let line = line.split_once('#').map_or(line, |(line, _)| line);
+        let Some((orig_plugin, versions)) = parse_line(line) else {
+            continue;
+        };
+        let plugin = unalias_plugin(orig_plugin);
+        if let Some(first) = seen.insert(plugin.clone(), line_no) {
+            issues.push(issue(format!(
+                "duplicate plugin {orig_plugin}, first listed on line {first}"
+            )));
+        }
+        if !config.tools.contains_key(&plugin) && !config.get_shorthands().contains_key(&plugin) {
+            issues.push(issue(format!("unknown plugin {orig_plugin}")));
+        }
+        if versions.is_empty() {
+            issues.push(issue(format!("no versions listed for {orig_plugin}")));
+        }
+        for v in versions {
+            if let Err(err) = ToolVersionRequest::new(plugin.clone(), &v).validate() {
+                issues.push(issue(format!("{orig_plugin}@{v}: {err}")));
+            }
         }
     }
+    issues
 }
 
 impl Display for ToolVersions {
@@ -163,12 +244,26 @@ impl ConfigFile for ToolVersions {
         self.path.as_path()
     }
 
+    /// plugin urls from `# plugin=<url>` annotations, they're ignored in untrusted files like the
+    /// `[plugins]` section of an untrusted .rtx.toml would be
     fn plugins(&self) -> HashMap<PluginName, String> {
-        Default::default()
+        let urls = self
+            .plugins
+            .iter()
+            .filter_map(|(plugin, tvp)| Some((plugin.clone(), tvp.url.clone()?)));
+        match self.is_trusted {
+            true => urls.collect(),
+            false => {
+                for (plugin, _) in urls {
+                    debug!("ignoring plugin url for {plugin} in untrusted {self}");
+                }
+                HashMap::new()
+            }
+        }
     }
 
-    fn env(&self) -> HashMap<PluginName, String> {
-        HashMap::new()
+    fn env(&self) -> IndexMap<String, String> {
+        IndexMap::new()
     }
 
     fn path_dirs(&self) -> Vec<PathBuf> {
@@ -188,7 +283,8 @@ impl ConfigFile for ToolVersions {
 
     fn save(&self) -> Result<()> {
         let s = self.dump();
-        Ok(fs::write(&self.path, s)?)
+        file::write_atomic(&self.path, s)?;
+        config_file::update_trust(&self.path)
     }
 
     fn dump(&self) -> String {
@@ -257,6 +353,48 @@ pub(crate) mod tests {
         assert_eq!(tv.dump(), orig);
     }
 
+    #[test]
+    fn test_parse_plugin_url() {
+        let orig = indoc! {"
+        node   20 # plugin=https://github.com/org/asdf-node
+        tiny   3 # fork plugin=https://github.com/org/rtx-tiny.git#v1
+        python 3.11 # plugin=
+        "};
+        let path = dirs::CURRENT.join(".test-tool-versions");
+        let tv = ToolVersions::parse_str(orig, path.clone(), true).unwrap();
+        assert_eq!(
+            tv.plugins(),
+            HashMap::from([
+                ("node".into(), "https://github.com/org/asdf-node".into()),
+                (
+                    "tiny".into(),
+                    "https://github.com/org/rtx-tiny.git#v1".into()
+                ),
+            ])
+        );
+        assert_eq!(tv.dump(), orig);
+
+        let tv = ToolVersions::parse_str(orig, path, false).unwrap();
+        assert!(tv.plugins().is_empty());
+    }
+
+    #[test]
+    fn test_parse_shadowed() {
+        let orig = indoc! {"
+        tiny 2
+        dummy 1
+        tiny 3
+        "};
+        let path = dirs::CURRENT.join(".test-tool-versions");
+        let tv = ToolVersions::parse_str(orig, path, false).unwrap();
+        assert_display_snapshot!(tv.to_toolset(), @"tiny@3, dummy@1");
+        let tvl = &tv.to_toolset().versions["tiny"];
+        assert_eq!(
+            tvl.shadowed,
+            vec![ToolVersionRequest::new("tiny".into(), "2")]
+        );
+    }
+
     #[test]
     fn test_parse_colon() {
         let orig = indoc! {"
@@ -292,4 +430,24 @@ pub(crate) mod tests {
         let tv = ToolVersions::parse_str(orig, path, true).unwrap();
         assert_display_snapshot!(tv.to_toolset(), @"ruby@3.0.5 ruby@3.1");
     }
+
+    #[test]
+    fn test_validate() {
+        let config = Config::load().unwrap();
+        let orig = indoc! {"
+        tiny 2 >=1 <<2 # comment
+        nope 1.0
+        tiny: 3
+        dummy
+        nodejs prefix: ref:main
+        "};
+        let issues = validate(orig, &config).into_iter().join("\n");
+        assert_snapshot!(issues, @r###"
+        line 1: tiny@<<2: invalid version range: <<2
+        line 2: unknown plugin nope
+        line 3: duplicate plugin tiny, first listed on line 1
+        line 4: no versions listed for dummy
+        line 5: nodejs@prefix:: missing value after ':'
+        "###);
+    }
 }
@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{eyre, Result};
+use color_eyre::Section;
+use indexmap::IndexMap;
+use itertools::Itertools;
+use toml_edit::{value, Array, Document, Item, Value};
+
+use crate::config::config_file::{ConfigFile, ConfigFileType};
+use crate::config::settings::SettingsBuilder;
+use crate::config::AliasMap;
+use crate::file::{self, display_path};
+use crate::parse_error;
+use crate::plugins::{unalias_plugin, PluginName};
+use crate::toolset::{ToolSource, ToolVersionRequest, Toolset};
+
+// node = ["20", "18"]
+// python = "3.11"
+
+/// represents a .tool-versions.toml file, the same data as .tool-versions but in TOML
+#[derive(Debug, Default)]
+pub struct ToolVersionsToml {
+    path: PathBuf,
+    toolset: Toolset,
+    doc: Document,
+}
+
+impl ToolVersionsToml {
+    pub fn init(path: &Path) -> Self {
+        Self {
+            toolset: Toolset::new(ToolSource::ToolVersions(path.to_path_buf())),
+            path: path.to_path_buf(),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self> {
+        trace!("parsing tool-versions toml: {}", path.display());
+        let body = fs::read_to_string(path).suggestion("ensure file exists and can be read")?;
+        Self::parse_str(&body, path)
+    }
+
+    pub fn parse_str(s: &str, path: &Path) -> Result<Self> {
+        let mut cf = Self::init(path);
+        let doc: Document = s.parse().suggestion("ensure file is valid TOML")?;
+        for (plugin, v) in doc.iter() {
+            let plugin_name = unalias_plugin(plugin);
+            for version in parse_versions(plugin, v)? {
+                let tvr = ToolVersionRequest::new(plugin_name.clone(), &version)
+                    .relative_to(path.parent().unwrap());
+                cf.toolset.add_version(tvr, Default::default());
+            }
+        }
+        cf.doc = doc;
+        Ok(cf)
+    }
+}
+
+fn parse_versions(k: &str, v: &Item) -> Result<Vec<String>> {
+    if let Some(s) = v.as_str() {
+        return Ok(vec![s.to_string()]);
+    }
+    match v.as_array() {
+        Some(array) => {
+            let mut versions = vec![];
+            for v in array {
+                match v.as_str() {
+                    Some(s) => versions.push(s.to_string()),
+                    _ => parse_error!(k, v, "string")?,
+                }
+            }
+            Ok(versions)
+        }
+        _ => parse_error!(k, v, "string or array of strings")?,
+    }
+}
+
+impl Display for ToolVersionsToml {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let plugins = &self
+            .toolset
+            .versions
+            .iter()
+            .map(|(p, tvl)| {
+                let versions = tvl.requests.iter().map(|(tvr, _)| tvr.version());
+                format!("{}@{}", p, versions.collect_vec().join("|"))
+            })
+            .collect_vec();
+        write!(
+            f,
+            "ToolVersionsToml({}): {}",
+            display_path(&self.path),
+            plugins.join(", ")
+        )
+    }
+}
+
+impl ConfigFile for ToolVersionsToml {
+    fn get_type(&self) -> ConfigFileType {
+        ConfigFileType::ToolVersionsToml
+    }
+
+    fn get_path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    fn plugins(&self) -> HashMap<PluginName, String> {
+        Default::default()
+    }
+
+    fn env(&self) -> IndexMap<String, String> {
+        IndexMap::new()
+    }
+
+    fn path_dirs(&self) -> Vec<PathBuf> {
+        vec![]
+    }
+
+    fn remove_plugin(&mut self, plugin_name: &PluginName) {
+        self.toolset.versions.remove(plugin_name);
+        self.doc.as_table_mut().remove(plugin_name);
+    }
+
+    fn replace_versions(&mut self, plugin_name: &PluginName, versions: &[String]) {
+        if let Some(tvl) = self.toolset.versions.get_mut(plugin_name) {
+            tvl.requests.clear();
+        }
+        for v in versions {
+            let tvr = ToolVersionRequest::new(plugin_name.clone(), v);
+            self.toolset.add_version(tvr, Default::default());
+        }
+        if versions.len() == 1 {
+            self.doc.insert(plugin_name, value(versions[0].clone()));
+        } else {
+            let mut arr = Array::new();
+            for v in versions {
+                arr.push(v);
+            }
+            self.doc.insert(plugin_name, Item::Value(Value::Array(arr)));
+        }
+    }
+
+    fn save(&self) -> Result<()> {
+        file::write_atomic(&self.path, self.dump())
+    }
+
+    fn dump(&self) -> String {
+        self.doc.to_string()
+    }
+
+    fn to_toolset(&self) -> &Toolset {
+        &self.toolset
+    }
+
+    fn settings(&self) -> SettingsBuilder {
+        SettingsBuilder::default()
+    }
+
+    fn aliases(&self) -> AliasMap {
+        AliasMap::default()
+    }
+
+    fn watch_files(&self) -> Vec<PathBuf> {
+        vec![self.path.clone()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use insta::{assert_display_snapshot, assert_snapshot};
+
+    use crate::dirs;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let orig = indoc! {r#"
+        nodejs = ["20", "18"]
+        python = "3.11"
+        "#};
+        let path = dirs::CURRENT.join(".test-tool-versions.toml");
+        let cf = ToolVersionsToml::parse_str(orig, &path).unwrap();
+        assert_display_snapshot!(cf, @"ToolVersionsToml(~/cwd/.test-tool-versions.toml): node@20|18, python@3.11");
+        assert_display_snapshot!(cf.to_toolset(), @"node@20 node@18, python@3.11");
+    }
+
+    #[test]
+    fn test_replace_versions() {
+        let orig = indoc! {r#"
+        node = "18"
+        "#};
+        let path = dirs::CURRENT.join(".test-tool-versions.toml");
+        let mut cf = ToolVersionsToml::parse_str(orig, &path).unwrap();
+        cf.replace_versions(&"node".into(), &["20".into(), "18".into()]);
+        cf.replace_versions(&"python".into(), &["3.11".into()]);
+        assert_snapshot!(cf.dump(), @r###"
+        node = ["20", "18"]
+        python = "3.11"
+        "###);
+    }
+}
@@ -0,0 +1,237 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+use console::style;
+use itertools::Itertools;
+
+use crate::config::config_file;
+use crate::config::tracking::Tracker;
+use crate::config::{AliasMap, Config};
+use crate::file::display_path;
+use crate::shorthands::parse_shorthands_file;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+/// a potential problem found by `Config::doctor()`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoctorFinding {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl DoctorFinding {
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for DoctorFinding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let prefix = match self.severity {
+            Severity::Info => style("[INFO]").cyan(),
+            Severity::Warning => style("[WARN]").yellow(),
+            Severity::Error => style("[ERROR]").red(),
+        };
+        write!(f, "{} {}", prefix.for_stderr(), self.message)
+    }
+}
+
+impl Config {
+    /// checks the setup for problems, the most severe first
+    pub fn doctor(&self) -> Vec<DoctorFinding> {
+        let mut findings = vec![];
+        findings.extend(self.doctor_plugins());
+        findings.extend(self.doctor_tracked_config_files());
+        findings.extend(find_alias_cycles(&self.aliases));
+        findings.extend(self.doctor_shorthands());
+        findings.extend(self.doctor_env_collisions());
+        findings.sort_by_key(|f| Reverse(f.severity));
+        findings
+    }
+
+    fn doctor_plugins(&self) -> Vec<DoctorFinding> {
+        let mut findings = vec![];
+        for tool in self.tools.values() {
+            if !tool.is_installed() {
+                let msg = format!("plugin {} is not installed", &tool.name);
+                findings.push(DoctorFinding::new(Severity::Warning, msg));
+                continue;
+            }
+            if let Some(msg) = tool.unsupported_platform() {
+                findings.push(DoctorFinding::new(Severity::Warning, msg));
+            }
+            let report = match tool.plugin.health_check() {
+                Ok(report) => report,
+                Err(err) => {
+                    let msg = format!("failed to check plugin {}: {err:#}", &tool.name);
+                    findings.push(DoctorFinding::new(Severity::Error, msg));
+                    continue;
+                }
+            };
+            if !report.missing_required.is_empty() {
+                let msg = format!(
+                    "plugin {} is broken, missing {}",
+                    &tool.name,
+                    report.missing_required.join(", ")
+                );
+                findings.push(DoctorFinding::new(Severity::Error, msg));
+            }
+            if !report.not_executable.is_empty() {
+                let msg = format!(
+                    "plugin {} has scripts that are not executable: {}",
+                    &tool.name,
+                    report.not_executable.join(", ")
+                );
+                findings.push(DoctorFinding::new(Severity::Error, msg));
+            }
+            if !report.missing_optional.is_empty() {
+                let msg = format!(
+                    "plugin {} does not have optional scripts: {}",
+                    &tool.name,
+                    report.missing_optional.join(", ")
+                );
+                findings.push(DoctorFinding::new(Severity::Info, msg));
+            }
+        }
+        findings
+    }
+
+    /// config files from other directories rtx has seen, the current ones already parsed or
+    /// rtx would have failed to start
+    fn doctor_tracked_config_files(&self) -> Vec<DoctorFinding> {
+        let paths = match Tracker::new().list_all() {
+            Ok(paths) => paths,
+            Err(err) => {
+                let msg = format!("failed to list tracked config files: {err:#}");
+                return vec![DoctorFinding::new(Severity::Warning, msg)];
+            }
+        };
+        paths
+            .into_iter()
+            .filter(|path| !self.config_files.contains_key(path))
+            .filter_map(|path| {
+                let is_trusted = config_file::is_trusted(&self.settings, &path);
+                let err = config_file::parse(&path, is_trusted).err()?;
+                let msg = format!("failed to parse {}: {err:#}", display_path(&path));
+                Some(DoctorFinding::new(Severity::Error, msg))
+            })
+            .collect()
+    }
+
+    fn doctor_shorthands(&self) -> Vec<DoctorFinding> {
+        let mut findings = vec![];
+        let Some(f) = &self.settings.shorthands_file else {
+            return findings;
+        };
+        if let Err(err) = parse_shorthands_file(f.clone()) {
+            let msg = format!("failed to read shorthands file {}: {err:#}", f.display());
+            findings.push(DoctorFinding::new(Severity::Error, msg));
+        }
+        for name in self.get_shorthand_collisions() {
+            let msg = format!(
+                "shorthand '{name}' overridden by custom source: {}",
+                f.display()
+            );
+            findings.push(DoctorFinding::new(Severity::Info, msg));
+        }
+        findings
+    }
+
+    /// env vars set to different values by multiple config files, the closest one wins
+    fn doctor_env_collisions(&self) -> Vec<DoctorFinding> {
+        let mut env: BTreeMap<String, Vec<(&PathBuf, String)>> = BTreeMap::new();
+        for (path, cf) in &self.config_files {
+            for (k, v) in cf.env() {
+                env.entry(k).or_default().push((path, v));
+            }
+        }
+        env.into_iter()
+            .filter(|(_, values)| values.iter().map(|(_, v)| v).unique().count() > 1)
+            .map(|(k, values)| {
+                let paths = values.iter().map(|(p, _)| display_path(p)).join(", ");
+                let msg = format!("env var {k} is set in multiple config files: {paths}");
+                DoctorFinding::new(Severity::Info, msg)
+            })
+            .collect()
+    }
+}
+
+/// aliases that never resolve to a version like `a -> b -> a`
+fn find_alias_cycles(aliases: &AliasMap) -> Vec<DoctorFinding> {
+    let mut findings = vec![];
+    for (plugin, plugin_aliases) in aliases {
+        let mut reported = BTreeSet::new();
+        for start in plugin_aliases.keys() {
+            if reported.contains(start) {
+                continue;
+            }
+            let mut chain = vec![start];
+            let mut cur = start;
+            while let Some(next) = plugin_aliases.get(cur) {
+                if next == start {
+                    reported.extend(chain.iter().cloned());
+                    chain.push(next);
+                    let msg = format!("alias cycle for {plugin}: {}", chain.iter().join(" -> "));
+                    findings.push(DoctorFinding::new(Severity::Error, msg));
+                    break;
+                }
+                if chain.contains(&next) {
+                    // a cycle that doesn't include start, it's reported from one of its members
+                    break;
+                }
+                chain.push(next);
+                cur = next;
+            }
+        }
+    }
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_display_snapshot;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_find_alias_cycles() {
+        let aliases: AliasMap = [(
+            "tiny".to_string(),
+            [("a", "b"), ("b", "a"), ("lts", "3"), ("c", "c")]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        )]
+        .into_iter()
+        .collect();
+        let findings = find_alias_cycles(&aliases);
+        let messages = findings.iter().map(|f| f.message.as_str()).collect_vec();
+        assert_eq!(
+            messages,
+            vec![
+                "alias cycle for tiny: a -> b -> a",
+                "alias cycle for tiny: c -> c"
+            ]
+        );
+        assert_display_snapshot!(findings[0], @"[ERROR] alias cycle for tiny: a -> b -> a");
+    }
+
+    #[test]
+    fn test_doctor() {
+        let config = Config::load().unwrap();
+        for finding in config.doctor() {
+            assert_ne!(finding.severity, Severity::Error, "{}", finding);
+        }
+    }
+}
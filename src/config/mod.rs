@@ -1,34 +1,110 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt::{Display, Formatter};
-use std::path::PathBuf;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::{Duration, SystemTime};
 
 use color_eyre::eyre::{eyre, Result};
+use color_eyre::Section;
 use indexmap::IndexMap;
 use itertools::Itertools;
 use once_cell::sync::OnceCell;
 use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+use serde_derive::{Deserialize, Serialize};
 
-pub use settings::{MissingRuntimeBehavior, Settings};
+pub use doctor::{DoctorFinding, Severity};
+pub use outdated::Outdated;
+pub use settings::{
+    check_install_dir_template, default_config_filename, default_tool_versions_filename,
+    ensure_online, is_offline, MissingRuntimeBehavior, SettingValue, Settings,
+};
 
+use crate::config::config_cache::ConfigCacheFile;
 use crate::config::config_file::legacy_version::LegacyVersionFile;
 use crate::config::config_file::rtx_toml::RtxToml;
 use crate::config::config_file::{ConfigFile, ConfigFileType};
+use crate::config::settings::SettingsBuilder;
 use crate::config::tracking::Tracker;
+pub use crate::config::warnings::Warnings;
 use crate::file::display_path;
+use crate::hash::hash_parts_sha256;
 use crate::plugins::core::{CORE_PLUGINS, EXPERIMENTAL_CORE_PLUGINS};
-use crate::plugins::{ExternalPlugin, Plugin, PluginName, PluginType};
-use crate::shorthands::{get_shorthands, Shorthands};
-use crate::tool::Tool;
-use crate::{dirs, env, file, hook_env};
+use crate::plugins::{
+    self, unalias_plugin, ExternalPlugin, Plugin, PluginCapability, PluginName, PluginType,
+};
+use crate::shorthands::{load_shorthands, Shorthands};
+use crate::tool::{self, Tool};
+use crate::toolset::Toolset;
+use crate::ui::multi_progress_report::MultiProgressReport;
+use crate::{dirs, env, file, hook_env, toolset};
 
+mod config_cache;
 pub mod config_file;
+mod doctor;
+mod outdated;
 mod settings;
 mod tracking;
+mod warnings;
+
+/// an `[env]` value that is rendered after the toolset has been resolved
+#[derive(Debug, Clone)]
+pub struct EnvTemplate {
+    pub path: PathBuf,
+    pub template: String,
+    /// the var is left out unless this plugin has an installed version in the toolset
+    pub when_installed: Option<PluginName>,
+}
+
+/// an `[env.path_add]` value, entries from every config file are joined with `separator` instead
+/// of the closest one overwriting the others
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvPathAdd {
+    pub paths: Vec<String>,
+    /// defaults to ":", the closest config file that sets it wins
+    pub separator: Option<String>,
+}
+
+/// `[plugins.<name>] pre_install` and `post_uninstall`, shell commands run around installing and
+/// uninstalling a version of the plugin, see `Tool::run_hook()`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginHooks {
+    pub pre_install: Option<String>,
+    pub post_uninstall: Option<String>,
+}
+
+/// what one config file contributes to an env var, see `Config::env_layers()`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvLayer {
+    pub path: PathBuf,
+    pub value: EnvLayerValue,
+    /// a value `load_env()` applies later replaces this one, or a closer file has
+    /// `env_isolated`, so it isn't used
+    pub overridden: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvLayerValue {
+    Set(String),
+    PathAdd(Vec<String>),
+    Template(String),
+    Remove,
+}
+
+impl Display for EnvLayerValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Set(v) => write!(f, "{v}"),
+            Self::PathAdd(paths) => write!(f, "path_add [{}]", paths.join(", ")),
+            Self::Template(t) => write!(f, "{t}"),
+            Self::Remove => write!(f, "(unset)"),
+        }
+    }
+}
 
 type AliasMap = BTreeMap<PluginName, BTreeMap<String, String>>;
-type ConfigMap = IndexMap<PathBuf, Box<dyn ConfigFile>>;
+pub type ConfigMap = IndexMap<PathBuf, Box<dyn ConfigFile>>;
 type ToolMap = BTreeMap<PluginName, Arc<Tool>>;
 
 #[derive(Debug, Default)]
@@ -36,54 +112,103 @@ pub struct Config {
     pub settings: Settings,
     pub global_config: RtxToml,
     pub config_files: ConfigMap,
+    /// the loaded plugins, with `load_lazy()` only the core plugins and the ones used so far
     pub tools: ToolMap,
-    pub env: BTreeMap<String, String>,
+    /// false after `load_lazy()` until `load_all_tools()`
+    all_tools_loaded: bool,
+    /// where `plugin()` and `load_all_tools()` find external plugins, dirs::PLUGINS unless a
+    /// test sets it
+    plugins_dir: Option<PathBuf>,
+    /// env vars from farther config files first, a closer file overrides the value but the var
+    /// keeps its position
+    pub env: IndexMap<String, String>,
+    pub env_remove: BTreeSet<String>,
+    pub env_templates: BTreeMap<String, EnvTemplate>,
+    env_layers: BTreeMap<String, Vec<EnvLayer>>,
     pub path_dirs: Vec<PathBuf>,
     pub aliases: AliasMap,
+    pub alias_comments: AliasMap,
     pub all_aliases: OnceCell<AliasMap>,
     pub should_exit_early: bool,
+    /// non-fatal problems found while loading, rendered by the CLI at the end of the command
+    pub warnings: Warnings,
+    env_fingerprint: String,
     pub project_root: Option<PathBuf>,
-    shorthands: OnceCell<HashMap<String, String>>,
+    shorthands: OnceCell<(Shorthands, Vec<String>)>,
     repo_urls: HashMap<PluginName, String>,
+    plugin_settings: HashMap<PluginName, IndexMap<String, String>>,
+    plugin_install_env: HashMap<PluginName, IndexMap<String, String>>,
+    plugin_mirror_urls: HashMap<PluginName, String>,
+    plugin_hooks: HashMap<PluginName, PluginHooks>,
+    /// plugins disabled by `disable_plugins` and the config file that disabled them
+    pub disabled_plugins: BTreeMap<PluginName, PathBuf>,
+    /// config files found from other directories, see `config_files_for_path()`
+    path_config_files: Mutex<HashMap<PathBuf, PathConfigFiles>>,
+}
+
+/// the config files for a directory other than the cwd and the mtimes of the files that decide
+/// which ones are found
+#[derive(Debug)]
+struct PathConfigFiles {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+    config_files: Arc<ConfigMap>,
 }
 
 impl Config {
     pub fn load() -> Result<Self> {
+        let mut config = Self::load_lazy()?;
+        config.load_all_tools()?;
+        Ok(config)
+    }
+
+    /// like `load()` but without listing every installed plugin, for commands that only need the
+    /// plugins they're given or find in the config files like `rtx exec node@18` and shims. Only
+    /// the core plugins and the external plugins that can read legacy version files are loaded,
+    /// the rest are loaded on demand by `plugin()` and `get_or_create_tool()`.
+    pub fn load_lazy() -> Result<Self> {
+        let warnings = Warnings::default();
         let global_config = load_rtxrc()?;
-        let mut settings_b = global_config.settings();
-        let settings = settings_b.build();
-        let config_filenames = load_config_filenames(&settings, &BTreeMap::new());
-        let tools = load_tools(&settings)?;
-        let config_files = load_all_config_files(
-            &settings_b.build(),
-            &config_filenames,
-            &tools,
-            &BTreeMap::new(),
-            ConfigMap::new(),
-        )?;
-        for cf in config_files.values() {
-            settings_b.merge(cf.settings());
-        }
-        let settings = settings_b.build();
+        let system_config = load_system_config(&env::RTX_SYSTEM_CONFIG_FILE, &warnings);
+        let settings_b = base_settings(system_config.as_ref(), &global_config);
+        // plugin aliases are needed to parse the plugin names in config files
+        plugins::set_plugin_aliases(settings_b.plugin_aliases.clone());
+        let global_settings = settings_b.build();
+        global_settings.set_default_filenames();
+        let mut tools = load_tools(&global_settings)?;
+        let config_cache = env::RTX_CONFIG_CACHE.then(ConfigCacheFile::new);
+        let config_files = match config_cache.as_ref().and_then(|c| c.read()) {
+            Some(config_files) => config_files,
+            None => {
+                let dir = discovery_dir(&dirs::CURRENT, &warnings);
+                let (config_files, filenames) =
+                    load_config_files(&dir, &settings_b, &tools, system_config, &warnings)?;
+                if let Some(config_cache) = &config_cache {
+                    if let Err(err) = config_cache.write(&config_files, &filenames) {
+                        warnings.push("config cache", format!("failed to write: {err:#}"));
+                    }
+                }
+                config_files
+            }
+        };
+        let disabled_plugins = load_disabled_plugins(&config_files);
+        tools.retain(|name, _| !disabled_plugins.contains_key(name));
+        let settings = load_settings(settings_b, &config_files, &warnings);
+        settings.validate()?;
+        settings.set_offline();
         trace!("Settings: {:#?}", settings);
+        let config_track =
+            track_config_files(&config_files.keys().cloned().collect_vec(), &warnings);
 
-        let legacy_files = load_legacy_files(&settings, &tools);
-        let config_filenames = load_config_filenames(&settings, &legacy_files);
-        let config_track = track_config_files(&config_filenames);
-
-        let config_files = load_all_config_files(
-            &settings,
-            &config_filenames,
-            &tools,
-            &legacy_files,
-            config_files,
-        );
-        let config_files = config_files?;
+        tool::set_install_dirs(load_plugin_install_dirs(&config_files));
+        tool::set_install_dir_template(settings.install_dir_template.clone());
+        let project_root = get_project_root(&config_files);
+        tool::set_versions_snapshot_dir(project_root.clone());
         let watch_files = config_files
             .values()
             .flat_map(|cf| cf.watch_files())
             .collect_vec();
-        let should_exit_early = hook_env::should_exit_early(&watch_files);
+        let env_fingerprint = toolset::env_fingerprint(env::vars());
+        let should_exit_early = hook_env::should_exit_early(&watch_files, &env_fingerprint);
 
         let mut repo_urls = HashMap::new();
         for cf in config_files.values() {
@@ -93,63 +218,361 @@ impl Config {
         }
         config_track.join().unwrap();
 
-        let config = Self {
-            env: load_env(&config_files),
+        let profile = env::RTX_PROFILE.as_deref();
+        check_env_profile(&config_files, profile, &warnings);
+        let (env, env_remove, env_templates) = load_env(&config_files, profile);
+        let mut config = Self {
+            plugin_settings: load_plugin_settings(&config_files),
+            plugin_install_env: load_plugin_install_env(&config_files),
+            plugin_mirror_urls: load_plugin_mirror_urls(&config_files),
+            plugin_hooks: load_plugin_hooks(&config_files),
+            disabled_plugins,
+            path_config_files: Mutex::new(HashMap::new()),
+            env,
+            env_remove,
+            env_templates,
+            env_layers: load_env_layers(&config_files, profile),
             path_dirs: load_path_dirs(&config_files),
             aliases: load_aliases(&config_files),
+            alias_comments: load_alias_comments(&config_files),
             all_aliases: OnceCell::new(),
             shorthands: OnceCell::new(),
-            project_root: get_project_root(&config_files),
+            project_root,
             config_files,
             settings,
             global_config,
             tools,
+            all_tools_loaded: false,
+            plugins_dir: None,
             should_exit_early,
+            env_fingerprint,
+            warnings,
             repo_urls,
         };
 
+        config.bootstrap_default_plugins();
+        config.check_strict()?;
+
         debug!("{}", &config);
 
         Ok(config)
     }
 
+    /// every config file's value for each env var before they're combined, closest file first
+    pub fn env_layers(&self) -> &BTreeMap<String, Vec<EnvLayer>> {
+        &self.env_layers
+    }
+
+    /// with the strict setting the warnings collected so far are turned into an error, so CI fails
+    /// on a misconfigured project instead of carrying on
+    pub fn check_strict(&self) -> Result<()> {
+        if !self.settings.strict {
+            return Ok(());
+        }
+        let warnings = self.warnings.take();
+        if warnings.is_empty() {
+            return Ok(());
+        }
+        let warnings = warnings.iter().unique().join("\n");
+        Err(
+            eyre!("strict mode is enabled, failing on warnings:\n{warnings}")
+                .suggestion("unset RTX_STRICT or the strict setting to only warn"),
+        )
+    }
+
+    /// installs the plugins from `default_plugins` that are missing, failures are warnings so
+    /// the config still loads without network access. A plugin that failed isn't retried for a
+    /// day so every prompt doesn't try to clone it again.
+    fn bootstrap_default_plugins(&mut self) {
+        if !self.settings.bootstrap_default_plugins || is_offline() {
+            return;
+        }
+        let missing = self
+            .settings
+            .default_plugins
+            .iter()
+            .map(|p| unalias_plugin(p))
+            .filter(|p| !self.tools.contains_key(p) && !dirs::PLUGINS.join(p).is_dir())
+            .filter(|p| !self.disabled_plugins.contains_key(p))
+            .filter(|p| {
+                let failed = file::modified_duration(&bootstrap_failed_path(p));
+                !matches!(failed, Ok(d) if d < BOOTSTRAP_RETRY_DURATION)
+            })
+            .collect_vec();
+        if missing.is_empty() {
+            return;
+        }
+        let mpr = MultiProgressReport::new(self.show_progress_bars());
+        for name in missing {
+            let tool = Tool::new(name.clone(), Box::new(ExternalPlugin::new(&name)));
+            let mut pr = mpr.add();
+            match tool.install(self, &mut pr, false) {
+                Ok(()) => {
+                    let _ = file::remove_all(bootstrap_failed_path(&name));
+                    self.tools.insert(name, Arc::new(tool));
+                }
+                Err(err) => {
+                    pr.error(err.to_string());
+                    if let Err(err) = record_bootstrap_failure(&name) {
+                        debug!("failed to record failed bootstrap: {err:#}");
+                    }
+                    self.warnings
+                        .push(name, format!("failed to install default plugin: {err:#}"));
+                }
+            }
+        }
+    }
+
+    /// the config files that apply to `path` (a file or directory) as if rtx was run from its
+    /// directory, e.g.: for an editor that needs the toolset of the file being edited. They're
+    /// cached per directory until one of them changes or a new one is added.
+    pub fn config_files_for_path(&self, path: &Path) -> Result<Arc<ConfigMap>> {
+        let path = dirs::CURRENT.join(file::replace_path(path));
+        let dir = match path.is_dir() {
+            true => path,
+            false => path.parent().map(Path::to_path_buf).unwrap_or(path),
+        };
+        let mut cache = self.path_config_files.lock().unwrap();
+        if let Some(cached) = cache.get(&dir) {
+            if !config_cache::files_changed(&cached.files) {
+                return Ok(cached.config_files.clone());
+            }
+            trace!("config files for {} changed", display_path(&dir));
+        }
+        let system_config = load_system_config(&env::RTX_SYSTEM_CONFIG_FILE, &self.warnings);
+        let settings_b = base_settings(system_config.as_ref(), &self.global_config);
+        let (config_files, filenames) = load_config_files(
+            &dir,
+            &settings_b,
+            &self.tools,
+            system_config,
+            &self.warnings,
+        )?;
+        let files = config_cache::watched_files(&dir, &config_files, &filenames);
+        let config_files = Arc::new(config_files);
+        let cached = PathConfigFiles {
+            files,
+            config_files: config_files.clone(),
+        };
+        cache.insert(dir, cached);
+        Ok(config_files)
+    }
+
+    /// runs `f` with the `[env]` vars and PATH dirs from the config files for `path` instead of
+    /// the cwd's, so `Toolset::env_with_path()` returns the env as if rtx was run from there
+    pub fn with_env_for_path<T, F>(&mut self, path: &Path, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Self) -> Result<T>,
+    {
+        let config_files = self.config_files_for_path(path)?;
+        let (env, env_remove, env_templates) = load_env(&config_files, env::RTX_PROFILE.as_deref());
+        let cwd_env = (
+            std::mem::replace(&mut self.env, env),
+            std::mem::replace(&mut self.env_remove, env_remove),
+            std::mem::replace(&mut self.env_templates, env_templates),
+            std::mem::replace(&mut self.path_dirs, load_path_dirs(&config_files)),
+        );
+        let result = f(self);
+        (
+            self.env,
+            self.env_remove,
+            self.env_templates,
+            self.path_dirs,
+        ) = cwd_env;
+        result
+    }
+
+    /// runs `f` with the aliases from the config files for `path` instead of the cwd's, so the
+    /// versions in those files resolve with the aliases they define
+    pub fn with_aliases_for_path<T, F>(&mut self, path: &Path, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let config_files = self.config_files_for_path(path)?;
+        let cwd_aliases = std::mem::replace(&mut self.aliases, load_aliases(&config_files));
+        self.all_aliases.take();
+        let result = f(self);
+        self.aliases = cwd_aliases;
+        self.all_aliases.take();
+        Ok(result)
+    }
+
     pub fn get_shorthands(&self) -> &Shorthands {
+        &self.load_shorthands().0
+    }
+
+    /// default shorthands that were overridden by the custom shorthands file
+    pub fn get_shorthand_collisions(&self) -> &[String] {
+        &self.load_shorthands().1
+    }
+
+    fn load_shorthands(&self) -> &(Shorthands, Vec<String>) {
         self.shorthands
-            .get_or_init(|| get_shorthands(&self.settings))
+            .get_or_init(|| load_shorthands(&self.settings))
+    }
+
+    /// plugin-defined settings from `[plugins.<name>.settings]`
+    pub fn plugin_settings(&self, plugin_name: &str) -> IndexMap<String, String> {
+        self.plugin_settings
+            .get(&unalias_plugin(plugin_name))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// env vars from `[plugins.<name>.install_env]`, only set while the plugin installs a version
+    /// and not in the toolset's env
+    pub fn plugin_install_env(&self, plugin_name: &str) -> IndexMap<String, String> {
+        self.plugin_install_env
+            .get(&unalias_plugin(plugin_name))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// `[plugins.<name>] pre_install` and `post_uninstall`
+    pub fn plugin_hooks(&self, plugin_name: &str) -> PluginHooks {
+        self.plugin_hooks
+            .get(&unalias_plugin(plugin_name))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    #[cfg(test)]
+    pub fn set_plugin_hooks(&mut self, plugin_name: &str, hooks: PluginHooks) {
+        self.plugin_hooks.insert(plugin_name.to_string(), hooks);
+    }
+
+    /// where a plugin should download from, `[plugins.<name>] mirror_url` or else the
+    /// `mirror_prefix` setting followed by the plugin name
+    pub fn mirror_url(&self, plugin_name: &str) -> Option<String> {
+        let plugin_name = unalias_plugin(plugin_name);
+        match self.plugin_mirror_urls.get(&plugin_name) {
+            Some(url) => Some(url.clone()),
+            None => self
+                .settings
+                .mirror_prefix
+                .as_ref()
+                .map(|prefix| format!("{}/{}", prefix.trim_end_matches('/'), plugin_name)),
+        }
+    }
+
+    /// env vars passed to a plugin's install so it downloads from its mirror, the variables the
+    /// plugin is known to read and RTX_PLUGIN_MIRROR_URL for any other plugin
+    pub fn mirror_env(&self, plugin_name: &str) -> Vec<(String, String)> {
+        let Some(url) = self.mirror_url(plugin_name) else {
+            return vec![];
+        };
+        plugins::mirror_env_vars(&unalias_plugin(plugin_name))
+            .iter()
+            .chain(["RTX_PLUGIN_MIRROR_URL"].iter())
+            .map(|k| (k.to_string(), url.clone()))
+            .collect()
     }
 
     pub fn get_repo_url(&self, plugin_name: &PluginName) -> Option<String> {
         match self.repo_urls.get(plugin_name) {
             Some(url) => Some(url),
-            None => self.get_shorthands().get(plugin_name),
+            None => {
+                let shorthands = self.get_shorthands();
+                shorthands.get(plugin_name).or_else(|| {
+                    let aliases = plugins::plugin_aliases_of(plugin_name);
+                    aliases.iter().find_map(|alias| shorthands.get(alias))
+                })
+            }
         }
         .cloned()
     }
 
-    pub fn get_all_aliases(&self) -> &AliasMap {
-        self.all_aliases.get_or_init(|| self.load_all_aliases())
+    /// `[project] name` from the closest config file that has one, the global config isn't a
+    /// project so it's skipped
+    pub fn project_name(&self) -> Option<String> {
+        let global = get_global_rtx_toml();
+        self.config_files
+            .iter()
+            .filter(|(path, _)| **path != global)
+            .find_map(|(_, cf)| cf.project_name())
+    }
+
+    /// the config and plugin aliases, fails if a plugin's alias script does with the strict
+    /// setting, otherwise the plugin is warned about and has no aliases
+    pub fn get_all_aliases(&self) -> Result<&AliasMap> {
+        self.all_aliases.get_or_try_init(|| self.load_all_aliases())
+    }
+
+    pub fn get_alias_comment(&self, plugin_name: &PluginName, alias: &str) -> Option<&String> {
+        self.alias_comments.get(plugin_name)?.get(alias)
     }
 
     pub fn is_activated(&self) -> bool {
         env::var("__RTX_DIFF").is_ok()
     }
 
+    /// a hash of the RTX_<PLUGIN>_VERSION env vars, the shell hook compares it to the previous
+    /// run to tell if the versions need to be resolved again
+    pub fn env_fingerprint(&self) -> &str {
+        &self.env_fingerprint
+    }
+
+    /// a hash of the effective configuration: the plugins, env, PATH dirs, aliases, and the
+    /// versions resolved in `ts`. Maps are sorted first so it only changes when one of them
+    /// does, not when config files list them in a different order.
+    pub fn content_hash(&self, ts: &Toolset) -> Result<String> {
+        let mut parts = vec![ts.content_hash(self)];
+        parts.extend(self.tools.keys().map(|p| format!("plugin {p}")));
+        let env = self.env.iter().sorted();
+        parts.extend(env.map(|(k, v)| format!("env {k}={v}")));
+        parts.extend(self.env_remove.iter().map(|k| format!("env_remove {k}")));
+        parts.extend(self.env_templates.iter().map(|(k, t)| {
+            let when_installed = t.when_installed.clone().unwrap_or_default();
+            format!("env_template {k}={} {when_installed}", t.template)
+        }));
+        parts.extend(
+            self.path_dirs
+                .iter()
+                .map(|p| format!("path {}", p.display())),
+        );
+        for (plugin, aliases) in self.get_all_aliases()? {
+            parts.extend(
+                aliases
+                    .iter()
+                    .map(|(k, v)| format!("alias {plugin} {k}={v}")),
+            );
+        }
+        Ok(hash_parts_sha256(parts))
+    }
+
     pub fn show_progress_bars(&self) -> bool {
         self.settings.verbose || !console::user_attended_stderr()
     }
 
+    /// follows aliases through config and plugin aliases until it reaches a version, so
+    /// `mine = "lts"` in a config file can point to the plugin's `lts` alias
     pub fn resolve_alias(&self, plugin_name: &PluginName, v: &str) -> Result<String> {
-        if let Some(plugin_aliases) = self.aliases.get(plugin_name) {
-            if let Some(alias) = plugin_aliases.get(v) {
-                return Ok(alias.clone());
-            }
-        }
-        if let Some(plugin) = self.tools.get(plugin_name) {
-            if let Some(alias) = plugin.get_aliases(&self.settings)?.get(v) {
-                return Ok(alias.clone());
+        let config_aliases = self.aliases.get(plugin_name);
+        let plugin_aliases = match self.tools.get(plugin_name) {
+            Some(plugin) => plugin.list_aliases(&self.settings)?,
+            None => IndexMap::new(),
+        };
+        let mut chain = vec![v.to_string()];
+        loop {
+            let cur = chain.last().unwrap();
+            let next = config_aliases
+                .and_then(|aliases| aliases.get(cur))
+                .or_else(|| plugin_aliases.get(cur));
+            match next {
+                // an alias pointing to itself is just a version
+                Some(next) if next != cur => {
+                    if chain.contains(next) {
+                        chain.push(next.clone());
+                        return Err(eyre!(
+                            "alias cycle for {plugin_name}: {}",
+                            chain.join(" -> ")
+                        ));
+                    }
+                    chain.push(next.clone());
+                }
+                _ => return Ok(chain.pop().unwrap()),
             }
         }
-        Ok(v.to_string())
     }
 
     pub fn external_plugins(&self) -> Vec<(&PluginName, Arc<Tool>)> {
@@ -160,7 +583,50 @@ impl Config {
             .collect()
     }
 
+    /// looks up an installed plugin by name or alias (e.g.: nodejs), loading it if `load_lazy()`
+    /// skipped it
+    pub fn plugin(&mut self, plugin_name: &str) -> Option<Arc<Tool>> {
+        let name = unalias_plugin(plugin_name);
+        if !self.all_tools_loaded && !self.tools.contains_key(&name) && !self.is_disabled(&name) {
+            let tool = Tool::find_in(self.plugins_dir(), &name)?;
+            self.tools.insert(name.clone(), Arc::new(tool));
+            self.all_aliases.take();
+        }
+        self.tools.get(&name).cloned()
+    }
+
+    /// loads the installed plugins `load_lazy()` skipped, for commands that work with all of them
+    pub fn load_all_tools(&mut self) -> Result<()> {
+        if self.all_tools_loaded {
+            return Ok(());
+        }
+        for tool in Tool::list_in(self.plugins_dir())? {
+            if !self.tools.contains_key(&tool.name) && !self.is_disabled(&tool.name) {
+                self.tools.insert(tool.name.clone(), Arc::new(tool));
+            }
+        }
+        self.all_aliases.take();
+        self.all_tools_loaded = true;
+        Ok(())
+    }
+
+    fn plugins_dir(&self) -> &Path {
+        self.plugins_dir.as_deref().unwrap_or(&dirs::PLUGINS)
+    }
+
+    fn is_disabled(&self, plugin_name: &PluginName) -> bool {
+        self.disabled_plugins.contains_key(plugin_name)
+            || self
+                .settings
+                .disable_tools
+                .iter()
+                .any(|t| &unalias_plugin(t) == plugin_name)
+    }
+
     pub fn get_or_create_tool(&mut self, plugin_name: &PluginName) -> Arc<Tool> {
+        if !self.all_tools_loaded && !self.tools.contains_key(plugin_name) {
+            self.all_aliases.take();
+        }
         self.tools
             .entry(plugin_name.clone())
             .or_insert_with(|| {
@@ -170,23 +636,57 @@ impl Config {
             .clone()
     }
 
-    fn load_all_aliases(&self) -> AliasMap {
+    /// updates all external plugins in parallel, returning the result for each
+    pub fn update_all_plugins(&self) -> Result<Vec<(PluginName, Result<()>)>> {
+        let plugins = self
+            .external_plugins()
+            .into_iter()
+            .map(|(_, p)| (p, None))
+            .collect();
+        self.update_plugins(plugins)
+    }
+
+    /// updates plugins (optionally to a specific git ref) using up to `jobs` threads
+    pub fn update_plugins(
+        &self,
+        plugins: Vec<(Arc<Tool>, Option<String>)>,
+    ) -> Result<Vec<(PluginName, Result<()>)>> {
+        let results = ThreadPoolBuilder::new()
+            .num_threads(self.settings.jobs)
+            .build()?
+            .install(|| {
+                plugins
+                    .into_par_iter()
+                    .map(|(plugin, gitref)| (plugin.name.clone(), plugin.update(gitref)))
+                    .collect()
+            });
+        Ok(results)
+    }
+
+    pub fn complete_versions(&mut self, plugin_name: &PluginName, prefix: &str) -> Vec<String> {
+        let tool = self.get_or_create_tool(plugin_name);
+        tool.complete_versions(&self.settings, prefix)
+    }
+
+    fn load_all_aliases(&self) -> Result<AliasMap> {
         let mut aliases: AliasMap = self.aliases.clone();
-        let plugin_aliases: Vec<_> = self
+        let plugin_aliases = self
             .tools
             .values()
             .par_bridge()
             .map(|plugin| {
-                let aliases = match plugin.get_aliases(&self.settings) {
+                let aliases = match plugin.list_aliases(&self.settings) {
                     Ok(aliases) => aliases,
+                    Err(err) if self.settings.strict => return Err(err),
                     Err(err) => {
-                        eprintln!("Error: {err}");
-                        BTreeMap::new()
+                        let msg = format!("failed to load aliases: {err:#}");
+                        self.warnings.push(plugin.name.clone(), msg);
+                        IndexMap::new()
                     }
                 };
-                (plugin.name.clone(), aliases)
+                Ok((plugin.name.clone(), aliases))
             })
-            .collect();
+            .collect::<Result<Vec<_>>>()?;
         for (plugin, plugin_aliases) in plugin_aliases {
             for (from, to) in plugin_aliases {
                 aliases
@@ -205,7 +705,7 @@ impl Config {
             }
         }
 
-        aliases
+        Ok(aliases)
     }
 
     pub fn get_tracked_config_files(&self) -> Result<ConfigMap> {
@@ -239,12 +739,14 @@ impl Config {
 
 fn get_project_root(config_files: &ConfigMap) -> Option<PathBuf> {
     for (p, cf) in config_files.into_iter() {
-        if p == &get_global_rtx_toml() {
-            // ~/.config/rtx/config.toml is not a project config file
+        if p == &get_global_rtx_toml() || p == &*env::RTX_SYSTEM_CONFIG_FILE {
+            // ~/.config/rtx/config.toml and /etc/rtx/config.toml are not project config files
             continue;
         }
         match cf.get_type() {
-            ConfigFileType::RtxToml | ConfigFileType::ToolVersions => {
+            ConfigFileType::RtxToml
+            | ConfigFileType::ToolVersions
+            | ConfigFileType::ToolVersionsToml => {
                 return Some(p.parent()?.to_path_buf());
             }
             _ => {}
@@ -280,18 +782,146 @@ fn load_rtxrc() -> Result<RtxToml> {
     }
 }
 
+/// the machine-wide config at RTX_SYSTEM_CONFIG_FILE (/etc/rtx/config.toml by default), for
+/// baseline settings, env, and tool versions on shared systems. It has the lowest precedence,
+/// below the global config. A missing file is skipped and an unreadable one is a warning.
+fn load_system_config(path: &Path, warnings: &Warnings) -> Option<RtxToml> {
+    if !path.exists() {
+        trace!("system config does not exist {:?}", path);
+        return None;
+    }
+    // only admins can write to it so it doesn't need to be trusted
+    match RtxToml::from_file(path, true) {
+        Ok(cf) => Some(cf),
+        Err(err) => {
+            warnings.push(display_path(path), format!("failed to read: {err:#}"));
+            None
+        }
+    }
+}
+
+/// the settings used to find config files: the system config's overridden by the global config's
+fn base_settings(system_config: Option<&RtxToml>, global_config: &RtxToml) -> SettingsBuilder {
+    let mut settings_b = SettingsBuilder::default();
+    if let Some(cf) = system_config {
+        settings_b.merge_from(cf.settings(), cf.get_path());
+    }
+    settings_b.merge_from(global_config.settings(), global_config.get_path());
+    settings_b
+}
+
+/// merges settings from config files farthest first so closer files override them
+fn load_settings(
+    settings_b: SettingsBuilder,
+    config_files: &ConfigMap,
+    warnings: &Warnings,
+) -> Settings {
+    merge_settings(settings_b, config_files, |path, key| {
+        warnings.push(
+            display_path(path),
+            format!("setting {key} can only be set in the global config, ignoring it"),
+        );
+    })
+}
+
+/// same as `load_settings` but without warnings, for the settings used to find config files
+fn build_settings(settings_b: SettingsBuilder, config_files: &ConfigMap) -> Settings {
+    merge_settings(settings_b, config_files, |_, _| {})
+}
+
+fn merge_settings(
+    mut settings_b: SettingsBuilder,
+    config_files: &ConfigMap,
+    on_global_only: impl Fn(&PathBuf, &str),
+) -> Settings {
+    let global_config = get_global_rtx_toml();
+    for (path, cf) in config_files.iter().rev() {
+        let mut settings = cf.settings();
+        if path != &global_config && path != &*env::RTX_SYSTEM_CONFIG_FILE {
+            for key in settings.remove_global_only() {
+                on_global_only(path, key);
+            }
+        }
+        settings_b.merge_from(settings, path);
+    }
+    settings_b.build()
+}
+
+/// the directory to find config files from: `dir`, or HOME if `dir` can't be read, e.g.: the cwd
+/// was deleted out from under a long-running shell
+fn discovery_dir(dir: &Path, warnings: &Warnings) -> PathBuf {
+    if dir.is_dir() {
+        return dir.to_path_buf();
+    }
+    let dir = match dir.as_os_str().is_empty() {
+        true => "the current directory".to_string(),
+        false => display_path(dir),
+    };
+    warnings.push(
+        "config",
+        format!(
+            "{dir} does not exist or can't be read, loading the config from {} instead",
+            display_path(&dirs::HOME)
+        ),
+    );
+    dirs::HOME.clone()
+}
+
+/// finds and parses the config files, first without legacy files since the config files can
+/// disable the plugins that would parse them. The system config, if any, goes last. Also returns
+/// the filenames that were searched for.
+fn load_config_files(
+    dir: &Path,
+    settings_b: &SettingsBuilder,
+    tools: &ToolMap,
+    system_config: Option<RtxToml>,
+    warnings: &Warnings,
+) -> Result<(ConfigMap, Vec<String>)> {
+    let settings = settings_b.build();
+    let config_filenames = discover_config_files(dir, &settings);
+    for path in &config_filenames {
+        config_file::migrate_legacy_trust(path);
+    }
+    let config_files = load_all_config_files(
+        &settings,
+        &config_filenames,
+        tools,
+        &BTreeMap::new(),
+        ConfigMap::new(),
+    )?;
+    let disabled_plugins = load_disabled_plugins(&config_files);
+    let mut tools = tools.clone();
+    tools.retain(|name, _| !disabled_plugins.contains_key(name));
+    let settings = build_settings(settings_b.clone(), &config_files);
+
+    let legacy_files = load_legacy_files(&settings, &tools, &config_files, warnings);
+    let filenames = config_names(&settings, &legacy_files);
+    let config_filenames = find_config_files(dir, &settings, &legacy_files);
+    let config_files = load_all_config_files(
+        &settings,
+        &config_filenames,
+        &tools,
+        &legacy_files,
+        config_files,
+    )?;
+    let mut config_files = config_files;
+    if let Some(cf) = system_config {
+        config_files.insert(cf.get_path().to_path_buf(), Box::new(cf));
+    }
+    Ok((config_files, filenames))
+}
+
 fn load_tools(settings: &Settings) -> Result<ToolMap> {
     let mut tools = CORE_PLUGINS.clone();
     if settings.experimental {
         tools.extend(EXPERIMENTAL_CORE_PLUGINS.clone());
     }
-    let plugins = Tool::list()?
-        .into_par_iter()
-        .map(|p| (p.name.clone(), Arc::new(p)))
-        .collect::<Vec<_>>();
-    tools.extend(plugins);
+    // the other external plugins are loaded by `Config::load_all_tools()` or on demand, these
+    // are needed to find the legacy version files
+    let plugins = Tool::list_with_legacy_files()?;
+    tools.extend(plugins.into_iter().map(|t| (t.name.clone(), Arc::new(t))));
     for tool in &settings.disable_tools {
-        tools.remove(tool);
+        tools.remove(&unalias_plugin(tool));
     }
     Ok(tools)
 }
@@ -300,7 +930,13 @@ fn build_tool(name: PluginName, plugin: Box<dyn Plugin>) -> Arc<Tool> {
     Arc::new(Tool::new(name, plugin))
 }
 
-fn load_legacy_files(settings: &Settings, tools: &ToolMap) -> BTreeMap<String, Vec<PluginName>> {
+/// the legacy filenames the plugins list plus the ones from `[plugins.<name>] legacy_filenames`
+fn load_legacy_files(
+    settings: &Settings,
+    tools: &ToolMap,
+    config_files: &ConfigMap,
+    warnings: &Warnings,
+) -> BTreeMap<String, Vec<PluginName>> {
     if !settings.legacy_version_file {
         return BTreeMap::new();
     }
@@ -308,11 +944,8 @@ fn load_legacy_files(settings: &Settings, tools: &ToolMap) -> BTreeMap<String, V
         .values()
         .collect_vec()
         .into_par_iter()
-        .filter(|tool| {
-            !settings
-                .legacy_version_file_disable_tools
-                .contains(&tool.name)
-        })
+        .filter(|tool| is_legacy_file_enabled(settings, &tool.name))
+        .filter(|tool| tool.supports(PluginCapability::LegacyFiles))
         .filter_map(|tool| match tool.legacy_filenames(settings) {
             Ok(filenames) => Some(
                 filenames
@@ -321,7 +954,8 @@ fn load_legacy_files(settings: &Settings, tools: &ToolMap) -> BTreeMap<String, V
                     .collect_vec(),
             ),
             Err(err) => {
-                eprintln!("Error: {err}");
+                let msg = format!("failed to load legacy filenames: {err:#}");
+                warnings.push(tool.name.clone(), msg);
                 None
             }
         })
@@ -337,30 +971,75 @@ fn load_legacy_files(settings: &Settings, tools: &ToolMap) -> BTreeMap<String, V
             .or_insert_with(Vec::new)
             .push(plugin);
     }
+    for (path, cf) in config_files.iter().rev() {
+        for (plugin, filenames) in cf.plugin_legacy_filenames() {
+            if !tools.contains_key(&plugin) || !is_legacy_file_enabled(settings, &plugin) {
+                continue;
+            }
+            for filename in filenames {
+                let plugins: &mut Vec<_> = legacy_filenames.entry(filename.clone()).or_default();
+                if plugins.contains(&plugin) {
+                    continue;
+                }
+                if !plugins.is_empty() {
+                    let others = plugins.join(", ");
+                    let msg =
+                        format!("legacy file {filename} for {plugin} is also read by {others}");
+                    warnings.push(display_path(path), msg);
+                }
+                plugins.push(plugin.clone());
+            }
+        }
+    }
     legacy_filenames
 }
 
-fn load_config_filenames(
+/// false for plugins in the `legacy_version_file_disable_tools` setting
+fn is_legacy_file_enabled(settings: &Settings, plugin: &PluginName) -> bool {
+    !settings
+        .legacy_version_file_disable_tools
+        .iter()
+        .any(|t| &unalias_plugin(t) == plugin)
+}
+
+/// the names of the files to look for in the cwd and its parents
+fn config_names(
     settings: &Settings,
     legacy_filenames: &BTreeMap<String, Vec<PluginName>>,
-) -> Vec<PathBuf> {
+) -> Vec<String> {
     let mut filenames = legacy_filenames.keys().cloned().collect_vec();
-    filenames.push(env::RTX_DEFAULT_TOOL_VERSIONS_FILENAME.clone());
-    filenames.push(env::RTX_DEFAULT_CONFIG_FILENAME.clone());
-    if settings.experimental && *env::RTX_DEFAULT_CONFIG_FILENAME == ".rtx.toml" {
+    filenames.push(settings.default_tool_versions_filename.clone());
+    filenames.push(format!("{}.toml", settings.default_tool_versions_filename));
+    filenames.push(settings.default_config_filename.clone());
+    if settings.experimental && settings.default_config_filename == ".rtx.toml" {
         filenames.push(".rtx.local.toml".to_string());
         if let Some(env) = &*env::RTX_ENV {
             filenames.push(format!(".rtx.{}.toml", env));
             filenames.push(format!(".rtx.{}.local.toml", env));
         }
     }
+    filenames
+}
 
-    let mut config_files = file::FindUp::new(&dirs::CURRENT, &filenames).collect::<Vec<_>>();
+/// the config files that would be loaded from `dir` without parsing any of them: the ones found
+/// in `dir` and its parents closest first, then the home and global config. Legacy version files
+/// are left out since their names come from the plugins.
+pub fn discover_config_files(dir: &Path, settings: &Settings) -> Vec<PathBuf> {
+    find_config_files(dir, settings, &BTreeMap::new())
+}
+
+fn find_config_files(
+    dir: &Path,
+    settings: &Settings,
+    legacy_filenames: &BTreeMap<String, Vec<PluginName>>,
+) -> Vec<PathBuf> {
+    let filenames = config_names(settings, legacy_filenames);
+    let mut config_files = file::FindUp::new(dir, &filenames).collect::<Vec<_>>();
 
     if env::RTX_CONFIG_FILE.is_none() && !*env::RTX_USE_TOML {
         // only add ~/.tool-versions if RTX_CONFIG_FILE is not set
         // because that's how the user overrides the default
-        let home_config = dirs::HOME.join(env::RTX_DEFAULT_TOOL_VERSIONS_FILENAME.as_str());
+        let home_config = dirs::HOME.join(&settings.default_tool_versions_filename);
         if home_config.is_file() {
             config_files.push(home_config);
         }
@@ -387,7 +1066,7 @@ fn load_all_config_files(
     legacy_filenames: &BTreeMap<String, Vec<PluginName>>,
     mut existing: ConfigMap,
 ) -> Result<ConfigMap> {
-    Ok(config_filenames
+    let config_files: ConfigMap = config_filenames
         .iter()
         .unique()
         .map(|f| (f.clone(), existing.shift_remove(f)))
@@ -406,7 +1085,115 @@ fn load_all_config_files(
         .into_iter()
         .collect::<Result<Vec<_>>>()?
         .into_iter()
-        .collect())
+        .collect();
+    load_includes(
+        settings,
+        tools,
+        legacy_filenames,
+        config_files,
+        &mut existing,
+    )
+}
+
+const MAX_INCLUDE_DEPTH: usize = 10;
+
+const BOOTSTRAP_RETRY_DURATION: Duration = Duration::from_secs(60 * 60 * 24);
+
+/// touched when a default plugin fails to bootstrap, see `Config::bootstrap_default_plugins`
+fn bootstrap_failed_path(plugin_name: &str) -> PathBuf {
+    dirs::CACHE.join(plugin_name).join("bootstrap_failed")
+}
+
+fn record_bootstrap_failure(plugin_name: &str) -> Result<()> {
+    let path = bootstrap_failed_path(plugin_name);
+    file::create_dir_all(path.parent().unwrap())?;
+    file::write_atomic(&path, "")
+}
+
+/// adds config files referenced with `include = [...]` directly after the file including them
+/// so they have lower precedence than it
+fn load_includes(
+    settings: &Settings,
+    tools: &ToolMap,
+    legacy_filenames: &BTreeMap<String, Vec<PluginName>>,
+    config_files: ConfigMap,
+    existing: &mut ConfigMap,
+) -> Result<ConfigMap> {
+    let mut loader = IncludeLoader {
+        settings,
+        tools,
+        legacy_filenames,
+        existing,
+        config_files: ConfigMap::new(),
+        loaded: HashSet::new(),
+        stack: vec![],
+    };
+    for (path, cf) in config_files {
+        loader.add(path, cf)?;
+    }
+    Ok(loader.config_files)
+}
+
+struct IncludeLoader<'a> {
+    settings: &'a Settings,
+    tools: &'a ToolMap,
+    legacy_filenames: &'a BTreeMap<String, Vec<PluginName>>,
+    existing: &'a mut ConfigMap,
+    config_files: ConfigMap,
+    /// canonical paths of the files added so far, the same file can be reached through `..`
+    loaded: HashSet<PathBuf>,
+    stack: Vec<PathBuf>,
+}
+
+impl IncludeLoader<'_> {
+    fn add(&mut self, path: PathBuf, cf: Box<dyn ConfigFile>) -> Result<()> {
+        let canonical = path.canonicalize().unwrap_or_else(|_| path.clone());
+        if !self.loaded.insert(canonical.clone()) {
+            return Ok(());
+        }
+        let includes = cf.includes();
+        self.config_files.insert(path.clone(), cf);
+        if includes.is_empty() {
+            return Ok(());
+        }
+        if self.stack.len() >= MAX_INCLUDE_DEPTH {
+            return Err(eyre!(
+                "config includes nested more than {} levels deep: {}",
+                MAX_INCLUDE_DEPTH,
+                display_path(&path)
+            ));
+        }
+        self.stack.push(canonical);
+        for include in includes {
+            if !include.is_file() {
+                return Err(eyre!(
+                    "included config file not found: {} (included from {})",
+                    display_path(&include),
+                    display_path(&path)
+                ));
+            }
+            let include = include.canonicalize()?;
+            if self.stack.contains(&include) {
+                return Err(eyre!(
+                    "config include cycle: {} includes {}",
+                    display_path(&path),
+                    display_path(&include)
+                ));
+            }
+            let cf = match self.existing.shift_remove(&include) {
+                Some(cf) => cf,
+                None => {
+                    parse_config_file(&include, self.settings, self.legacy_filenames, self.tools)
+                        .map_err(|err| {
+                            eyre!("error reading config: {}\n{:#}", include.display(), err)
+                        })?
+                }
+            };
+            self.add(include, cf)?;
+        }
+        self.stack.pop();
+        Ok(())
+    }
 }
 
 fn parse_config_file(
@@ -430,16 +1217,227 @@ fn parse_config_file(
     }
 }
 
-fn load_env(config_files: &ConfigMap) -> BTreeMap<String, String> {
-    let mut env = BTreeMap::new();
-    for cf in config_files.values().rev() {
-        env.extend(cf.env());
+/// `[env]` values by key that are rendered once the toolset is resolved
+type EnvTemplates = BTreeMap<String, EnvTemplate>;
+
+/// the same values `load_env()` combines but kept per config file, closest file first. A value
+/// is overridden by anything `load_env()` applies after it except `[env.path_add]`, which joins
+/// with a farther plain value and other `path_add` values instead.
+fn load_env_layers(
+    config_files: &ConfigMap,
+    profile: Option<&str>,
+) -> BTreeMap<String, Vec<EnvLayer>> {
+    let mut layers: BTreeMap<String, Vec<EnvLayer>> = BTreeMap::new();
+    // farthest first, in the same order as `load_env()`
+    for (path, cf) in config_files.iter().rev() {
+        if cf.env_isolated() {
+            layers
+                .values_mut()
+                .flatten()
+                .for_each(|l| l.overridden = true);
+        }
+        let env = cf
+            .env()
+            .into_iter()
+            .map(|(k, v)| (k, EnvLayerValue::Set(v)));
+        let path_add = cf.env_path_add().into_iter();
+        let path_add = path_add.map(|(k, add)| (k, EnvLayerValue::PathAdd(add.paths)));
+        let templates = cf.env_templates().into_iter();
+        let templates = templates.map(|(k, t)| (k, EnvLayerValue::Template(t)));
+        let remove = cf
+            .env_remove()
+            .into_iter()
+            .map(|k| (k, EnvLayerValue::Remove));
+        let profile_env = profile
+            .and_then(|p| cf.env_profiles().remove(p))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(k, v)| (k, EnvLayerValue::Set(v)));
+        let values = env.chain(path_add).chain(templates).chain(remove);
+        for (k, value) in values.chain(profile_env) {
+            let layers = layers.entry(env::normalize_key(&k)).or_default();
+            for l in layers.iter_mut() {
+                l.overridden |= match (&value, &l.value) {
+                    (EnvLayerValue::PathAdd(_), EnvLayerValue::PathAdd(_)) => false,
+                    (EnvLayerValue::PathAdd(_), EnvLayerValue::Set(_)) => false,
+                    _ => true,
+                };
+            }
+            layers.push(EnvLayer {
+                path: path.clone(),
+                value,
+                overridden: false,
+            });
+        }
+    }
+    for layers in layers.values_mut() {
+        layers.reverse();
+    }
+    layers
+}
+
+/// warns if RTX_PROFILE is set to a profile that no config file has
+fn check_env_profile(config_files: &ConfigMap, profile: Option<&str>, warnings: &Warnings) {
+    let Some(profile) = profile else {
+        return;
+    };
+    if !config_files
+        .values()
+        .any(|cf| cf.env_profiles().contains_key(profile))
+    {
+        let msg = format!("no config file has [env.profiles.{profile}]");
+        warnings.push("RTX_PROFILE", msg);
+    }
+}
+
+/// returns the env vars to set and the env vars to unset
+/// config files closer to the cwd take precedence, so a closer file can unset a var set by a
+/// farther one and vice versa
+/// `profile` is the `[env.profiles.<name>]` to set the vars of after the `[env]` of each file
+/// a file with `env_isolated` drops everything the farther files set
+/// keys are merged case-insensitively on windows, see `env::normalize_key()`
+fn load_env(
+    config_files: &ConfigMap,
+    profile: Option<&str>,
+) -> (IndexMap<String, String>, BTreeSet<String>, EnvTemplates) {
+    let mut env = IndexMap::new();
+    let mut env_remove = BTreeSet::new();
+    let mut env_templates = EnvTemplates::new();
+    let mut env_path_add: BTreeMap<String, EnvPathAdd> = BTreeMap::new();
+    for (path, cf) in config_files.iter().rev() {
+        if cf.env_isolated() {
+            env.clear();
+            env_remove.clear();
+            env_templates.clear();
+            env_path_add.clear();
+        }
+        for (k, v) in cf.env() {
+            let k = env::normalize_key(&k);
+            env_remove.remove(&k);
+            env_templates.remove(&k);
+            env_path_add.remove(&k);
+            env.insert(k, v);
+        }
+        for (k, mut add) in cf.env_path_add() {
+            let k = env::normalize_key(&k);
+            env_remove.remove(&k);
+            env_templates.remove(&k);
+            // closer files go first, a plain value from a farther file is kept at the end
+            if let Some(farther) = env_path_add.remove(&k) {
+                add.paths.extend(farther.paths);
+                add.separator = add.separator.or(farther.separator);
+            } else if let Some(v) = env.shift_remove(&k) {
+                add.paths.push(v);
+            }
+            env_path_add.insert(k, add);
+        }
+        let mut when_installed: HashMap<_, _> = cf
+            .env_when_installed()
+            .into_iter()
+            .map(|(k, plugin)| (env::normalize_key(&k), plugin))
+            .collect();
+        for (k, template) in cf.env_templates() {
+            let k = env::normalize_key(&k);
+            env_remove.remove(&k);
+            env.shift_remove(&k);
+            env_path_add.remove(&k);
+            let t = EnvTemplate {
+                path: path.clone(),
+                template,
+                when_installed: when_installed.remove(&k),
+            };
+            env_templates.insert(k, t);
+        }
         for k in cf.env_remove() {
-            // remove values set to "false"
-            env.remove(&k);
+            let k = env::normalize_key(&k);
+            // remove values set to "false" or listed in `unset`
+            env.shift_remove(&k);
+            env_templates.remove(&k);
+            env_path_add.remove(&k);
+            env_remove.insert(k);
         }
+        if let Some(profile_env) = profile.and_then(|p| cf.env_profiles().remove(p)) {
+            for (k, v) in profile_env {
+                let k = env::normalize_key(&k);
+                env_remove.remove(&k);
+                env_templates.remove(&k);
+                env_path_add.remove(&k);
+                env.insert(k, v);
+            }
+        }
+    }
+    for (k, add) in env_path_add {
+        let paths = add.paths.into_iter().unique().collect_vec();
+        env.insert(k, paths.join(add.separator.as_deref().unwrap_or(":")));
+    }
+    (env, env_remove, env_templates)
+}
+
+/// closer config files override individual settings from farther ones
+fn load_plugin_settings(config_files: &ConfigMap) -> HashMap<PluginName, IndexMap<String, String>> {
+    let mut plugin_settings: HashMap<PluginName, IndexMap<String, String>> = HashMap::new();
+    for cf in config_files.values().rev() {
+        for (plugin_name, settings) in cf.plugin_settings() {
+            plugin_settings
+                .entry(plugin_name)
+                .or_default()
+                .extend(settings);
+        }
+    }
+    plugin_settings
+}
+
+/// closer config files override individual vars from farther ones
+fn load_plugin_install_env(
+    config_files: &ConfigMap,
+) -> HashMap<PluginName, IndexMap<String, String>> {
+    let mut install_env: HashMap<PluginName, IndexMap<String, String>> = HashMap::new();
+    for cf in config_files.values().rev() {
+        for (plugin_name, env) in cf.plugin_install_env() {
+            install_env.entry(plugin_name).or_default().extend(env);
+        }
+    }
+    install_env
+}
+
+/// the closest config file disabling a plugin is the one reported
+fn load_disabled_plugins(config_files: &ConfigMap) -> BTreeMap<PluginName, PathBuf> {
+    let mut disabled = BTreeMap::new();
+    for (path, cf) in config_files.iter().rev() {
+        for plugin_name in cf.disable_plugins() {
+            disabled.insert(plugin_name, path.clone());
+        }
+    }
+    disabled
+}
+
+fn load_plugin_install_dirs(config_files: &ConfigMap) -> HashMap<PluginName, PathBuf> {
+    let mut install_dirs = HashMap::new();
+    for cf in config_files.values().rev() {
+        install_dirs.extend(cf.plugin_install_dirs());
+    }
+    install_dirs
+}
+
+fn load_plugin_mirror_urls(config_files: &ConfigMap) -> HashMap<PluginName, String> {
+    let mut mirror_urls = HashMap::new();
+    for cf in config_files.values().rev() {
+        mirror_urls.extend(cf.plugin_mirror_urls());
     }
-    env
+    mirror_urls
+}
+
+/// closer config files override individual hooks from farther ones
+fn load_plugin_hooks(config_files: &ConfigMap) -> HashMap<PluginName, PluginHooks> {
+    let mut plugin_hooks: HashMap<PluginName, PluginHooks> = HashMap::new();
+    for cf in config_files.values().rev() {
+        for (plugin_name, hooks) in cf.plugin_hooks() {
+            let merged = plugin_hooks.entry(plugin_name).or_default();
+            merged.pre_install = hooks.pre_install.or(merged.pre_install.take());
+            merged.post_uninstall = hooks.post_uninstall.or(merged.post_uninstall.take());
+        }
+    }
+    plugin_hooks
 }
 
 fn load_path_dirs(config_files: &ConfigMap) -> Vec<PathBuf> {
@@ -450,25 +1448,41 @@ fn load_path_dirs(config_files: &ConfigMap) -> Vec<PathBuf> {
     path_dirs
 }
 
+/// closer config files override farther ones, an alias set to "" removes it
 fn load_aliases(config_files: &ConfigMap) -> AliasMap {
     let mut aliases: AliasMap = AliasMap::new();
 
-    for config_file in config_files.values() {
+    for config_file in config_files.values().rev() {
         for (plugin, plugin_aliases) in config_file.aliases() {
+            let aliases = aliases.entry(plugin.clone()).or_insert_with(BTreeMap::new);
             for (from, to) in plugin_aliases {
-                aliases
-                    .entry(plugin.clone())
-                    .or_insert_with(BTreeMap::new)
-                    .insert(from, to);
+                match to.is_empty() {
+                    true => aliases.remove(&from),
+                    false => aliases.insert(from, to),
+                };
             }
         }
     }
+    aliases.retain(|_, aliases| !aliases.is_empty());
 
     aliases
 }
 
-fn track_config_files(config_filenames: &[PathBuf]) -> thread::JoinHandle<()> {
+fn load_alias_comments(config_files: &ConfigMap) -> AliasMap {
+    let mut comments: AliasMap = AliasMap::new();
+
+    for config_file in config_files.values() {
+        for (plugin, plugin_comments) in config_file.alias_comments() {
+            comments.entry(plugin).or_default().extend(plugin_comments);
+        }
+    }
+
+    comments
+}
+
+fn track_config_files(config_filenames: &[PathBuf], warnings: &Warnings) -> thread::JoinHandle<()> {
     let config_filenames = config_filenames.to_vec();
+    let warnings = warnings.clone();
     let track = move || -> Result<()> {
         let mut tracker = Tracker::new();
         for config_file in &config_filenames {
@@ -478,7 +1492,7 @@ fn track_config_files(config_filenames: &[PathBuf]) -> thread::JoinHandle<()> {
     };
     thread::spawn(move || {
         if let Err(err) = track() {
-            warn!("tracking config files: {:#}", err);
+            warnings.push("config tracking", format!("{err:#}"));
         }
     })
 }
@@ -496,20 +1510,780 @@ impl Display for Config {
             .iter()
             .map(|(p, _)| display_path(p))
             .collect::<Vec<_>>();
+        let install_dirs = self
+            .tools
+            .iter()
+            .filter_map(|(p, t)| Some(format!("{p}={}", display_path(&t.custom_installs_path()?))))
+            .collect::<Vec<_>>();
         writeln!(f, "Files: {}", config_files.join(", "))?;
+        if !install_dirs.is_empty() {
+            writeln!(f, "Install Dirs: {}", install_dirs.join(", "))?;
+        }
         write!(f, "Installed Plugins: {}", plugins.join(", "))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use insta::assert_display_snapshot;
+    use std::fs;
+
+    use indoc::indoc;
+    use insta::{assert_debug_snapshot, assert_display_snapshot};
 
     use super::*;
+    use crate::cli::args::tool::ToolArg;
+    use crate::config::warnings::Warning;
+
+    /// parses `body` as an `.rtx.toml` at `path` for tests that build a `ConfigMap` by hand
+    fn parse(path: impl Into<PathBuf>, body: &str) -> (PathBuf, Box<dyn ConfigFile>) {
+        let path = path.into();
+        let cf = RtxToml::parse_str(body, &path).unwrap();
+        (path, Box::new(cf) as Box<dyn ConfigFile>)
+    }
 
     #[test]
     fn test_load() {
         let config = Config::load().unwrap();
         assert_display_snapshot!(config);
     }
+
+    #[test]
+    fn test_load_lazy() {
+        // a plugins dir of its own so listing the real one in other tests doesn't see the plugin
+        let plugins_dir = std::env::temp_dir().join("rtx-test-load-lazy");
+        let _ = fs::remove_dir_all(&plugins_dir);
+        fs::create_dir_all(plugins_dir.join("rtx-lazy-plugin/bin")).unwrap();
+        let mut config = Config::load_lazy().unwrap();
+        config.plugins_dir = Some(plugins_dir.clone());
+        // tiny can read legacy version files so it's always loaded
+        assert!(config.tools.contains_key("tiny"));
+        assert!(!config.tools.contains_key("rtx-lazy-plugin"));
+        assert_eq!(
+            config.plugin("rtx-lazy-plugin").unwrap().name,
+            "rtx-lazy-plugin"
+        );
+        assert!(config.plugin("not-a-plugin").is_none());
+
+        let mut config = Config::load_lazy().unwrap();
+        config.plugins_dir = Some(plugins_dir.clone());
+        config.load_all_tools().unwrap();
+        assert!(config.tools.contains_key("rtx-lazy-plugin"));
+        fs::remove_dir_all(plugins_dir).unwrap();
+    }
+
+    #[test]
+    fn test_system_config() {
+        let warnings = Warnings::default();
+        let path = dirs::CACHE.join("test-system-config.toml");
+        assert!(load_system_config(&path, &warnings).is_none());
+        fs::write(
+            &path,
+            indoc! {r#"
+            [env]
+            RTX_TEST_SYSTEM_VAR = "system"
+            [alias.tiny]
+            system-alias = "1.0.0"
+            [settings]
+            asdf_compat = true
+            jobs = 1
+            "#},
+        )
+        .unwrap();
+        let system_config = load_system_config(&path, &warnings).unwrap();
+        let global_config =
+            RtxToml::parse_str("[settings]\njobs = 2", &dirs::CONFIG.join("config.toml")).unwrap();
+        let settings = base_settings(Some(&system_config), &global_config).build();
+        assert!(settings.asdf_compat);
+        assert_eq!(settings.jobs, 2);
+        let provenance = |key| settings.provenance(key).to_string();
+        assert_eq!(provenance("asdf_compat"), display_path(&path));
+        assert_eq!(provenance("jobs"), display_path(global_config.get_path()));
+
+        let tools = ToolMap::new();
+        let settings_b = SettingsBuilder::default();
+        let (config_files, _) = load_config_files(
+            &dirs::HOME,
+            &settings_b,
+            &tools,
+            Some(system_config),
+            &warnings,
+        )
+        .unwrap();
+        assert_eq!(config_files.keys().last().unwrap(), &path);
+        assert_eq!(load_aliases(&config_files)["tiny"]["system-alias"], "1.0.0");
+
+        fs::write(&path, "[settings").unwrap();
+        assert!(load_system_config(&path, &warnings).is_none());
+        let warnings = warnings.take();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].source, display_path(&path));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_content_hash() {
+        let mut config = Config::load().unwrap();
+        let ts = toolset::ToolsetBuilder::new().build(&mut config).unwrap();
+        let hash = config.content_hash(&ts).unwrap();
+        assert_eq!(hash, config.content_hash(&ts).unwrap());
+
+        config.env.insert("A".into(), "1".into());
+        config.env.insert("B".into(), "2".into());
+        let with_env = config.content_hash(&ts).unwrap();
+        assert_ne!(hash, with_env);
+        config.env.remove("A");
+        config.env.remove("B");
+        config.env.insert("B".into(), "2".into());
+        config.env.insert("A".into(), "1".into());
+        assert_eq!(with_env, config.content_hash(&ts).unwrap());
+
+        let args = [ToolArg::parse("tiny@2.1.0")];
+        let ts = toolset::ToolsetBuilder::new()
+            .with_args(&args)
+            .build(&mut config)
+            .unwrap();
+        assert_ne!(with_env, config.content_hash(&ts).unwrap());
+    }
+
+    #[test]
+    fn test_plugin() {
+        let mut config = Config::load().unwrap();
+        assert_eq!(config.plugin("tiny").unwrap().name, "tiny");
+        assert_eq!(config.plugin("nodejs").unwrap().name, "node");
+        assert!(config.plugin("not-a-plugin").is_none());
+    }
+
+    #[test]
+    fn test_load_plugin_settings() {
+        let config_files: ConfigMap = [
+            parse("/a/b/.rtx.toml", "[plugins.node.settings]\nfoo = \"close\""),
+            parse(
+                "/a/.rtx.toml",
+                "[plugins.node.settings]\nfoo = \"far\"\nbar = 1",
+            ),
+        ]
+        .into_iter()
+        .collect();
+        let plugin_settings = load_plugin_settings(&config_files);
+        let node = plugin_settings.get("node").unwrap();
+        assert_eq!(node.get("foo").unwrap(), "close");
+        assert_eq!(node.get("bar").unwrap(), "1");
+    }
+
+    #[test]
+    fn test_load_plugin_hooks() {
+        let config_files: ConfigMap = [
+            parse(
+                "/a/b/.rtx.toml",
+                "[plugins.nodejs]\npre_install = \"close\"",
+            ),
+            parse(
+                "/a/.rtx.toml",
+                "[plugins.node]\npre_install = \"far\"\npost_uninstall = \"far\"",
+            ),
+        ]
+        .into_iter()
+        .collect();
+        let hooks = load_plugin_hooks(&config_files);
+        assert_eq!(hooks["node"].pre_install.as_deref(), Some("close"));
+        assert_eq!(hooks["node"].post_uninstall.as_deref(), Some("far"));
+    }
+
+    #[test]
+    fn test_load_plugin_install_env() {
+        let config_files: ConfigMap = [
+            parse("/a/b/.rtx.toml", "[plugins.python.install_env]\nCFLAGS = \"-O3\""),
+            parse(
+                "/a/.rtx.toml",
+                "[plugins.python.install_env]\nCFLAGS = \"-O2\"\nPYTHON_CONFIGURE_OPTS = \"--enable-shared\"",
+            ),
+        ]
+        .into_iter()
+        .collect();
+        let install_env = load_plugin_install_env(&config_files);
+        let python = install_env.get("python").unwrap();
+        assert_eq!(python.get("CFLAGS").unwrap(), "-O3");
+        assert_eq!(
+            python.get("PYTHON_CONFIGURE_OPTS").unwrap(),
+            "--enable-shared"
+        );
+        let (env, _, _) = load_env(&config_files, None);
+        assert!(env.is_empty());
+    }
+
+    #[test]
+    fn test_mirror_url() {
+        let mut config = Config::load().unwrap();
+        assert_eq!(config.mirror_url("node"), None);
+        assert!(config.mirror_env("node").is_empty());
+        config.settings.mirror_prefix = Some("https://mirror.example.com/".into());
+        config
+            .plugin_mirror_urls
+            .insert("python".into(), "https://python.example.com".into());
+        assert_eq!(
+            config.mirror_url("nodejs").unwrap(),
+            "https://mirror.example.com/node"
+        );
+        assert_eq!(
+            config.mirror_env("python"),
+            vec![
+                (
+                    "PYTHON_BUILD_MIRROR_URL".into(),
+                    "https://python.example.com".into()
+                ),
+                (
+                    "RTX_PLUGIN_MIRROR_URL".into(),
+                    "https://python.example.com".into()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_load_all_aliases_failure() {
+        env::set_var("RTX_FAILURE", "1");
+        let _ = fs::remove_dir_all(dirs::CACHE.join("dummy"));
+        let mut config = Config::default();
+        let plugin_name = PluginName::from("dummy");
+        let plugin = ExternalPlugin::new(&plugin_name);
+        let tool = Tool::new(plugin_name.clone(), Box::new(plugin));
+        config.tools.insert(plugin_name, Arc::new(tool));
+        env::remove_var("RTX_FAILURE");
+
+        let aliases = config.load_all_aliases().unwrap();
+        assert!(!aliases.contains_key("dummy"));
+        let warnings = config.warnings.take();
+        assert_eq!(warnings.len(), 1);
+        assert_display_snapshot!(warnings[0], @r###"
+        dummy: failed to load aliases: Failed fetching aliases for plugin dummy: error running list-aliases: exited with code 1
+        error: RTX_FAILURE set
+        "###);
+
+        config.settings.strict = true;
+        let err = config.load_all_aliases().unwrap_err();
+        assert_display_snapshot!(format!("{err:#}"), @r###"
+        Failed fetching aliases for plugin dummy: error running list-aliases: exited with code 1
+        error: RTX_FAILURE set
+        "###);
+        assert!(config.warnings.take().is_empty());
+    }
+
+    #[test]
+    fn test_resolve_alias() {
+        let mut config = Config::load().unwrap();
+        let tiny = PluginName::from("tiny");
+        let aliases = config.aliases.entry(tiny.clone()).or_default();
+        aliases.insert("mine".into(), "lts".into());
+        aliases.insert("a".into(), "b".into());
+        aliases.insert("b".into(), "a".into());
+        aliases.insert("self".into(), "self".into());
+        assert_eq!(config.resolve_alias(&tiny, "mine").unwrap(), "3.1.0");
+        assert_eq!(config.resolve_alias(&tiny, "lts").unwrap(), "3.1.0");
+        assert_eq!(config.resolve_alias(&tiny, "self").unwrap(), "self");
+        assert_eq!(config.resolve_alias(&tiny, "1.0.0").unwrap(), "1.0.0");
+        let err = config.resolve_alias(&tiny, "a").unwrap_err();
+        assert_eq!(err.to_string(), "alias cycle for tiny: a -> b -> a");
+    }
+
+    #[test]
+    fn test_load_env_unset() {
+        // set in a farther file, then unset in a closer one
+        let config_files: ConfigMap = [
+            parse("/a/b/.rtx.toml", "[env]\nunset = [\"FOO\"]"),
+            parse("/a/.rtx.toml", "[env]\nFOO = \"far\""),
+        ]
+        .into_iter()
+        .collect();
+        let (env, env_remove, _) = load_env(&config_files, None);
+        assert_eq!(env.get("FOO"), None);
+        assert!(env_remove.contains("FOO"));
+
+        // unset in a farther file, then set in a closer one
+        let config_files: ConfigMap = [
+            parse("/a/b/.rtx.toml", "[env]\nFOO = \"close\""),
+            parse("/a/.rtx.toml", "[env]\nunset = [\"FOO\"]"),
+        ]
+        .into_iter()
+        .collect();
+        let (env, env_remove, _) = load_env(&config_files, None);
+        assert_eq!(env.get("FOO").unwrap(), "close");
+        assert!(!env_remove.contains("FOO"));
+    }
+
+    #[test]
+    fn test_load_env_layers() {
+        let config_files: ConfigMap = [
+            parse(
+                "/a/b/.rtx.toml",
+                "[env]\nNODE_ENV = \"production\"\n[env.path_add]\nMANPATH = \"/opt/man\"",
+            ),
+            parse(
+                "/a/.rtx.toml",
+                "[env]\nNODE_ENV = false\nMANPATH = \"/usr/man\"",
+            ),
+            parse("/.rtx.toml", "[env]\nNODE_ENV = \"development\""),
+        ]
+        .into_iter()
+        .collect();
+        let layers = load_env_layers(&config_files, None);
+        let layers = layers
+            .iter()
+            .flat_map(|(k, layers)| {
+                layers.iter().map(move |l| {
+                    let path = l.path.display();
+                    format!("{k}={} {path} overridden={}", l.value, l.overridden)
+                })
+            })
+            .join("\n");
+        assert_display_snapshot!(layers, @r###"
+        MANPATH=path_add [/opt/man] /a/b/.rtx.toml overridden=false
+        MANPATH=/usr/man /a/.rtx.toml overridden=false
+        NODE_ENV=production /a/b/.rtx.toml overridden=false
+        NODE_ENV=(unset) /a/.rtx.toml overridden=true
+        NODE_ENV=development /.rtx.toml overridden=true
+        "###);
+    }
+
+    #[test]
+    fn test_load_env_layers_profile() {
+        let config_files: ConfigMap = [parse(
+            "/a/.rtx.toml",
+            "[env]\nAPI_URL = \"local\"\n[env.profiles.prod]\nAPI_URL = \"prod\"",
+        )]
+        .into_iter()
+        .collect();
+        let (env, _, _) = load_env(&config_files, Some("prod"));
+        assert_eq!(env.get("API_URL").unwrap(), "prod");
+
+        let layers = &load_env_layers(&config_files, Some("prod"))["API_URL"];
+        let effective = layers.iter().find(|l| !l.overridden).unwrap();
+        assert_eq!(effective.value, EnvLayerValue::Set("prod".into()));
+        assert_eq!(layers.iter().filter(|l| l.overridden).count(), 1);
+    }
+
+    #[test]
+    fn test_load_env_isolated() {
+        let config_files: ConfigMap = [
+            parse(
+                "/a/b/.rtx.toml",
+                "env_isolated = true\n[env]\nFOO = \"close\"",
+            ),
+            parse("/a/.rtx.toml", "[env]\nFOO = \"far\"\nBAR = \"far\""),
+        ]
+        .into_iter()
+        .collect();
+        let (env, _, _) = load_env(&config_files, None);
+        assert_eq!(env.get("FOO").unwrap(), "close");
+        assert!(!env.contains_key("BAR"));
+
+        let layers = load_env_layers(&config_files, None);
+        let overridden = |k: &str| layers[k].iter().map(|l| l.overridden).collect_vec();
+        assert_eq!(overridden("FOO"), vec![false, true]);
+        assert_eq!(overridden("BAR"), vec![true]);
+    }
+
+    #[test]
+    fn test_check_strict() {
+        let mut config = Config::default();
+        config
+            .warnings
+            .push("~/.rtx.toml", "tiny@9 is not installed");
+        config.check_strict().unwrap();
+        assert_eq!(config.warnings.take().len(), 1);
+
+        config.settings.strict = true;
+        config.check_strict().unwrap();
+        config
+            .warnings
+            .push("~/.rtx.toml", "tiny@9 is not installed");
+        config
+            .warnings
+            .push("~/.rtx.toml", "tiny@9 is not installed");
+        let err = config.check_strict().unwrap_err();
+        assert_display_snapshot!(err, @r###"
+        strict mode is enabled, failing on warnings:
+        ~/.rtx.toml: tiny@9 is not installed
+        "###);
+        assert!(config.warnings.take().is_empty());
+    }
+
+    #[test]
+    fn test_bootstrap_default_plugins() {
+        let mut config = Config::load().unwrap();
+        config.warnings.take();
+        config.settings.bootstrap_default_plugins = true;
+        config.settings.default_plugins = ["tiny".into(), "rtx-missing-plugin".into()].into();
+        config.bootstrap_default_plugins();
+        assert!(!config.tools.contains_key("rtx-missing-plugin"));
+        let warnings = config.warnings.take();
+        assert_eq!(warnings.len(), 1);
+        assert_display_snapshot!(warnings[0], @"rtx-missing-plugin: failed to install default plugin: No repository found for plugin rtx-missing-plugin");
+
+        // the failed plugin isn't retried right away
+        config.bootstrap_default_plugins();
+        assert!(config.warnings.take().is_empty());
+        file::remove_all(bootstrap_failed_path("rtx-missing-plugin")).unwrap();
+
+        // nothing is missing the second time
+        config.settings.default_plugins = ["tiny".into()].into();
+        config.bootstrap_default_plugins();
+        assert!(config.warnings.take().is_empty());
+    }
+
+    #[test]
+    fn test_load_disabled_plugins() {
+        let config_files: ConfigMap = [
+            parse("/a/b/.rtx.toml", "disable_plugins = [\"nodejs\"]"),
+            parse("/a/.rtx.toml", "disable_plugins = [\"node\", \"python\"]"),
+        ]
+        .into_iter()
+        .collect();
+        let disabled = load_disabled_plugins(&config_files);
+        assert_eq!(
+            disabled,
+            BTreeMap::from([
+                ("node".into(), PathBuf::from("/a/b/.rtx.toml")),
+                ("python".into(), PathBuf::from("/a/.rtx.toml")),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_project_name() {
+        let mut config = Config {
+            config_files: [
+                parse("/a/b/.rtx.toml", ""),
+                parse("/a/.rtx.toml", "[project]\nname = \"a\""),
+                parse("/.rtx.toml", "[project]\nname = \"root\""),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+        assert_eq!(config.project_name(), Some("a".into()));
+        config
+            .config_files
+            .shift_remove(&PathBuf::from("/a/.rtx.toml"));
+        assert_eq!(config.project_name(), Some("root".into()));
+        config
+            .config_files
+            .shift_remove(&PathBuf::from("/.rtx.toml"));
+        assert_eq!(config.project_name(), None);
+    }
+
+    #[test]
+    fn test_load_env_path_add() {
+        let config_files: ConfigMap = [
+            parse(
+                "/a/b/.rtx.toml",
+                "[env.path_add]\nMANPATH = [\"./man\", \"/opt/man\"]\nCLASSPATH = \"./lib\"",
+            ),
+            parse(
+                "/a/.rtx.toml",
+                "[env.path_add]\nMANPATH = \"/opt/man\"\nCLASSPATH = { paths = [\"./lib\"], separator = \";\" }",
+            ),
+            parse("/.rtx.toml", "[env]\nMANPATH = \"/usr/share/man\"\nCLASSPATH = \"/base\""),
+        ]
+        .into_iter()
+        .collect();
+        let (env, _, _) = load_env(&config_files, None);
+        assert_eq!(env["MANPATH"], "/a/b/man:/opt/man:/usr/share/man");
+        assert_eq!(env["CLASSPATH"], "/a/b/lib;/a/lib;/base");
+    }
+
+    #[test]
+    fn test_load_env_order() {
+        let config_files: ConfigMap = [
+            parse("/a/.rtx.toml", "[env]\nM = \"2\"\nZ = \"close\"\nB = \"3\""),
+            parse("/.rtx.toml", "[env]\nZ = \"far\"\nA = \"1\"\nC = \"4\""),
+        ]
+        .into_iter()
+        .collect();
+        let (env, _, _) = load_env(&config_files, None);
+        // farther files first, a closer value keeps the farther position
+        assert_debug_snapshot!(env, @r###"
+        {
+            "Z": "close",
+            "A": "1",
+            "C": "4",
+            "M": "2",
+            "B": "3",
+        }
+        "###);
+    }
+
+    #[test]
+    fn test_load_env_profile() {
+        let config_files: ConfigMap = [
+            parse(
+                "/a/.rtx.toml",
+                "[env]\nA = \"close\"\n[env.profiles.prod]\nB = \"close-prod\"",
+            ),
+            parse(
+                "/.rtx.toml",
+                "[env]\nA = \"far\"\nB = \"far\"\n[env.profiles.prod]\nA = \"far-prod\"",
+            ),
+        ]
+        .into_iter()
+        .collect();
+        let (env, _, _) = load_env(&config_files, None);
+        assert_debug_snapshot!(env, @r###"
+        {
+            "A": "close",
+            "B": "far",
+        }
+        "###);
+        // a profile overrides the [env] of its own file, closer files still win
+        let (env, _, _) = load_env(&config_files, Some("prod"));
+        assert_debug_snapshot!(env, @r###"
+        {
+            "A": "close",
+            "B": "close-prod",
+        }
+        "###);
+
+        let warnings = Warnings::default();
+        check_env_profile(&config_files, Some("prod"), &warnings);
+        assert!(warnings.take().is_empty());
+        check_env_profile(&config_files, Some("dev"), &warnings);
+        let warnings = warnings.take().into_iter().join("\n");
+        assert_display_snapshot!(warnings, @"RTX_PROFILE: no config file has [env.profiles.dev]");
+    }
+
+    #[test]
+    fn test_load_aliases_removal() {
+        let load = |project: &str, global: &str| {
+            let config_files: ConfigMap = [
+                parse(dirs::CURRENT.join(".rtx.toml"), project),
+                parse(get_global_rtx_toml(), global),
+            ]
+            .into_iter()
+            .collect();
+            load_aliases(&config_files)
+        };
+
+        // added globally, removed by the project
+        let aliases = load(
+            "[alias.tiny]\nmine = ''\nother = '2'",
+            "[alias.tiny]\nmine = '1'\nother = '1'\n[alias.dummy]\nmine = '1'",
+        );
+        assert_debug_snapshot!(aliases, @r###"
+        {
+            "dummy": {
+                "mine": "1",
+            },
+            "tiny": {
+                "other": "2",
+            },
+        }
+        "###);
+
+        // removed globally, added back by the project
+        let aliases = load("[alias.tiny]\nmine = '2'", "[alias.tiny]\nmine = ''");
+        assert_debug_snapshot!(aliases, @r###"
+        {
+            "tiny": {
+                "mine": "2",
+            },
+        }
+        "###);
+    }
+
+    #[test]
+    fn test_load_settings_project_override() {
+        let config_files: ConfigMap = [
+            parse(
+                dirs::CURRENT.join(".rtx.toml"),
+                "[settings]\nlegacy_version_file = false\njobs = 9",
+            ),
+            parse(
+                get_global_rtx_toml(),
+                "[settings]\nlegacy_version_file = true\njobs = 2",
+            ),
+        ]
+        .into_iter()
+        .collect();
+        let warnings = Warnings::default();
+        let settings = load_settings(SettingsBuilder::default(), &config_files, &warnings);
+        assert!(!settings.legacy_version_file);
+        assert_eq!(settings.jobs, 2);
+        assert_eq!(
+            warnings.take(),
+            vec![Warning {
+                source: "~/cwd/.rtx.toml".into(),
+                message: "setting jobs can only be set in the global config, ignoring it".into(),
+            }]
+        );
+
+        let tools = load_tools(&settings).unwrap();
+        assert!(load_legacy_files(&settings, &tools, &config_files, &warnings).is_empty());
+    }
+
+    #[test]
+    fn test_load_settings_project_mirror_prefix() {
+        // an untrusted project must not be able to send downloads to its own host
+        let config_files: ConfigMap = [parse(
+            dirs::CURRENT.join(".rtx.toml"),
+            "[settings]\nmirror_prefix = \"https://evil.example.com\"",
+        )]
+        .into_iter()
+        .collect();
+        let warnings = Warnings::default();
+        let settings = load_settings(SettingsBuilder::default(), &config_files, &warnings);
+        assert_eq!(settings.mirror_prefix, None);
+        assert_eq!(
+            warnings.take(),
+            vec![Warning {
+                source: "~/cwd/.rtx.toml".into(),
+                message: "setting mirror_prefix can only be set in the global config, ignoring it"
+                    .into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_load_legacy_files_disable_tools() {
+        let settings = Settings {
+            legacy_version_file: true,
+            legacy_version_file_disable_tools: BTreeSet::from(["dummy".into()]),
+            ..Settings::default()
+        };
+        let tools = load_tools(&settings).unwrap();
+        let warnings = Warnings::default();
+        let legacy_files = load_legacy_files(&settings, &tools, &ConfigMap::new(), &warnings);
+        assert_eq!(
+            legacy_files.get(".tiny-version"),
+            Some(&vec!["tiny".into()])
+        );
+        assert!(!legacy_files.contains_key(".dummy-version"));
+        assert!(!legacy_files.contains_key(".dummyrc"));
+    }
+
+    #[test]
+    fn test_load_legacy_files_from_config() {
+        let path = get_global_rtx_toml();
+        let body = "[plugins.tiny]\nlegacy_filenames = [\".tiny-ver\", \".dummyrc\"]\n\
+                    [plugins.dummy]\nlegacy_filenames = [\".dummy-ver\"]";
+        let cf = RtxToml::parse_str(body, &path).unwrap();
+        let config_files: ConfigMap = [(path, Box::new(cf) as Box<dyn ConfigFile>)].into();
+        let settings = Settings {
+            legacy_version_file: true,
+            ..Settings::default()
+        };
+        let tools = load_tools(&settings).unwrap();
+        let warnings = Warnings::default();
+        let legacy_files = load_legacy_files(&settings, &tools, &config_files, &warnings);
+        assert_eq!(legacy_files[".tiny-ver"], vec!["tiny"]);
+        assert_eq!(legacy_files[".dummy-ver"], vec!["dummy"]);
+        assert_eq!(legacy_files[".dummyrc"], vec!["dummy", "tiny"]);
+        assert_eq!(
+            warnings.take(),
+            vec![Warning {
+                source: "~/config/config.toml".into(),
+                message: "legacy file .dummyrc for tiny is also read by dummy".into(),
+            }]
+        );
+
+        let settings = Settings {
+            legacy_version_file: true,
+            legacy_version_file_disable_tools: BTreeSet::from(["dummy".into()]),
+            ..Settings::default()
+        };
+        let legacy_files = load_legacy_files(&settings, &tools, &config_files, &warnings);
+        assert!(!legacy_files.contains_key(".dummy-ver"));
+        assert_eq!(legacy_files[".dummyrc"], vec!["tiny"]);
+        assert!(warnings.take().is_empty());
+    }
+
+    #[test]
+    fn test_discover_config_files() {
+        let files = discover_config_files(&dirs::CURRENT, &Settings::default());
+        let files = files.iter().map(|f| display_path(f)).collect_vec();
+        assert_debug_snapshot!(files, @r###"
+        [
+            "~/cwd/.test-tool-versions",
+            "~/.test-tool-versions",
+            "~/config/config.toml",
+        ]
+        "###);
+    }
+
+    #[test]
+    fn test_discovery_dir() {
+        let warnings = Warnings::default();
+        assert_eq!(discovery_dir(&dirs::CURRENT, &warnings), *dirs::CURRENT);
+        assert!(warnings.take().is_empty());
+
+        // a shell's cwd that was removed
+        let dir = dirs::CURRENT.join("deleted");
+        fs::create_dir_all(&dir).unwrap();
+        fs::remove_dir(&dir).unwrap();
+        assert_eq!(discovery_dir(&dir, &warnings), *dirs::HOME);
+        let warnings = warnings.take().into_iter().join("\n");
+        assert_display_snapshot!(warnings, @"config: ~/cwd/deleted does not exist or can't be read, loading the config from ~ instead");
+    }
+
+    #[test]
+    fn test_discover_config_files_custom_filenames() {
+        let home_config = dirs::HOME.join(".test-alt-tool-versions");
+        fs::write(&home_config, "tiny 2\n").unwrap();
+        let settings = Settings {
+            default_tool_versions_filename: ".test-alt-tool-versions".into(),
+            ..Settings::default()
+        };
+        let files = discover_config_files(&dirs::CURRENT, &settings);
+        fs::remove_file(&home_config).unwrap();
+        let files = files.iter().map(|f| display_path(f)).collect_vec();
+        assert_debug_snapshot!(files, @r###"
+        [
+            "~/.test-alt-tool-versions",
+            "~/config/config.toml",
+        ]
+        "###);
+    }
+
+    #[test]
+    fn test_load_includes() {
+        let dir = env::temp_dir().join("rtx-test-includes");
+        let base = dir.join("base/.rtx.toml");
+        let project = dir.join("project/.rtx.toml");
+        file::create_dir_all(base.parent().unwrap()).unwrap();
+        file::create_dir_all(project.parent().unwrap()).unwrap();
+        let load = |path: &PathBuf| {
+            let settings = Settings::default();
+            let config_files = load_all_config_files(
+                &settings,
+                &[path.clone()],
+                &ToolMap::new(),
+                &BTreeMap::new(),
+                ConfigMap::new(),
+            )?;
+            Ok::<_, color_eyre::Report>(config_files)
+        };
+
+        fs::write(&base, "[env]\nFOO = \"base\"\nBAR = \"base\"").unwrap();
+        fs::write(
+            &project,
+            "include = [\"../base/.rtx.toml\", \"./../project/../base/.rtx.toml\"]\n[env]\nFOO = \"project\"",
+        )
+        .unwrap();
+        let config_files = load(&project).unwrap();
+        // both includes are the same file
+        assert_eq!(
+            config_files.keys().collect_vec(),
+            vec![&project, &base.canonicalize().unwrap()]
+        );
+        let (env, _, _) = load_env(&config_files, None);
+        assert_eq!(env.get("FOO").unwrap(), "project");
+        assert_eq!(env.get("BAR").unwrap(), "base");
+
+        fs::write(&base, "include = [\"../project/.rtx.toml\"]").unwrap();
+        let err = load(&project).unwrap_err();
+        assert!(err.to_string().contains("config include cycle"));
+
+        fs::remove_file(&base).unwrap();
+        let err = load(&project).unwrap_err();
+        assert!(err.to_string().contains("included config file not found"));
+        file::remove_all(&dir).unwrap();
+    }
 }
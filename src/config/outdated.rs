@@ -0,0 +1,101 @@
+use serde_derive::Serialize;
+
+use crate::config::Config;
+use crate::plugins::PluginName;
+use crate::toolset::{ToolVersion, ToolVersionRequest, Toolset};
+
+/// a version in the toolset that's behind the newest version its request allows, see
+/// `Config::outdated()`
+#[derive(Debug, Clone, Serialize)]
+pub struct Outdated {
+    pub plugin: PluginName,
+    pub requested: String,
+    /// the resolved version, `None` if it isn't installed
+    pub current: Option<String>,
+    pub latest: String,
+    /// the request is "latest" or an alias (e.g.: lts), so the version it points to moves on its
+    /// own instead of being bumped in the config
+    pub floating: bool,
+    /// the resolved version, for `rtx upgrade` to replace
+    #[serde(skip)]
+    pub tv: ToolVersion,
+}
+
+impl Config {
+    /// the versions in `ts` that aren't installed or are behind the newest remote version matching
+    /// their request. Symlinked versions and versions that aren't from the remote list (system,
+    /// path, ref) are left out, and so are plugins whose remote versions can't be listed.
+    pub fn outdated(&self, ts: &Toolset) -> Vec<Outdated> {
+        ts.list_current_versions(self)
+            .into_iter()
+            .filter(|(t, tv)| t.symlink_path(tv).is_none())
+            .filter(|(_, tv)| {
+                !matches!(
+                    tv.request,
+                    ToolVersionRequest::System(_)
+                        | ToolVersionRequest::Path(..)
+                        | ToolVersionRequest::Ref(..)
+                )
+            })
+            .filter_map(|(t, tv)| {
+                let latest = match tv.latest_version(self, &t) {
+                    Ok(latest) => latest,
+                    Err(err) => {
+                        debug!("skipping {}, no remote versions: {:#}", t.name, err);
+                        return None;
+                    }
+                };
+                let installed = t.is_version_installed(&tv);
+                if installed && tv.version == latest {
+                    return None;
+                }
+                let requested = tv.request.version();
+                Some(Outdated {
+                    floating: self.is_floating(&t.name, &requested),
+                    plugin: t.name.clone(),
+                    current: installed.then(|| tv.version.clone()),
+                    requested,
+                    latest,
+                    tv,
+                })
+            })
+            .collect()
+    }
+
+    fn is_floating(&self, plugin_name: &PluginName, requested: &str) -> bool {
+        requested == "latest"
+            || self
+                .resolve_alias(plugin_name, requested)
+                .map_or(false, |v| v != requested)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::cli::args::tool::ToolArg;
+    use crate::toolset::ToolsetBuilder;
+
+    use super::*;
+
+    #[test]
+    fn test_outdated() {
+        let mut config = Config::load().unwrap();
+        let ts = ToolsetBuilder::new()
+            .with_args(&[ToolArg::parse("dummy@1")])
+            .build(&mut config)
+            .unwrap();
+        let outdated = config.outdated(&ts);
+        let dummy = outdated.iter().find(|o| o.plugin == "dummy").unwrap();
+        assert_eq!(dummy.requested, "1");
+        assert_eq!(dummy.latest, "1.1.0");
+        assert!(!dummy.floating);
+
+        let tiny = "tiny".to_string();
+        assert!(config.is_floating(&tiny, "latest"));
+        assert!(config.is_floating(&tiny, "lts"));
+        assert!(config.is_floating(&tiny, "my/alias"));
+        assert!(!config.is_floating(&tiny, "3.0"));
+    }
+}
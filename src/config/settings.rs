@@ -1,12 +1,24 @@
 use std::collections::{BTreeMap, BTreeSet};
-use std::fmt::{Debug, Display, Formatter};
-use std::path::PathBuf;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
 use std::time::Duration;
 
+use clap::ValueEnum;
+use color_eyre::eyre::{eyre, Result};
+use color_eyre::Section;
+use indexmap::IndexMap;
 use log::LevelFilter;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_derive::{Deserialize, Serialize};
 
 use crate::env;
 use crate::env::*;
+use crate::file::display_path;
+use crate::tool::is_prerelease;
+use crate::toolset::ToolSourceKind;
 
 #[derive(Debug, Clone)]
 pub struct Settings {
@@ -25,7 +37,114 @@ pub struct Settings {
     pub disable_default_shorthands: bool,
     pub disable_tools: BTreeSet<String>,
     pub log_level: LevelFilter,
+    pub disable_version_change_warnings: bool,
+    pub offline: bool,
     pub raw: bool,
+    pub refresh_floating_aliases: bool,
+    pub prefer_installed_versions: bool,
+    /// the kinds of sources tool versions come from, the one that wins first. See
+    /// `ToolsetBuilder::build()`
+    pub source_precedence: Vec<String>,
+    pub strict: bool,
+    pub require_checksums: bool,
+    pub default_plugins: BTreeSet<String>,
+    pub bootstrap_default_plugins: bool,
+    pub env_overrides_shell: bool,
+    pub default_config_filename: String,
+    pub default_tool_versions_filename: String,
+    pub plugin_aliases: BTreeMap<String, String>,
+    pub mirror_prefix: Option<String>,
+    /// where versions are installed, e.g.: "/sdks/{plugin}/{version}", see
+    /// `check_install_dir_template()`
+    pub install_dir_template: Option<String>,
+    pub version_policy: BTreeMap<String, VersionPolicy>,
+    pub version_filter: BTreeMap<String, VersionFilter>,
+    /// the config file that last set each setting, see `provenance()`
+    pub sources: BTreeMap<&'static str, PathBuf>,
+}
+
+/// settings that default to an env var named `RTX_<KEY>`
+const ENV_KEYS: &[&str] = &[
+    "always_keep_download",
+    "always_keep_install",
+    "asdf_compat",
+    "bootstrap_default_plugins",
+    "default_config_filename",
+    "default_plugins",
+    "default_tool_versions_filename",
+    "disable_default_shorthands",
+    "disable_tools",
+    "disable_version_change_warnings",
+    "env_overrides_shell",
+    "experimental",
+    "install_dir_template",
+    "jobs",
+    "legacy_version_file",
+    "legacy_version_file_disable_tools",
+    "log_level",
+    "missing_runtime_behavior",
+    "mirror_prefix",
+    "offline",
+    "prefer_installed_versions",
+    "raw",
+    "refresh_floating_aliases",
+    "require_checksums",
+    "shorthands_file",
+    "source_precedence",
+    "strict",
+    "trusted_config_paths",
+    "verbose",
+];
+
+/// settings where the env var overrides the config files instead of the other way around
+const ENV_OVERRIDES: &[&str] = &[
+    "default_config_filename",
+    "default_tool_versions_filename",
+    "missing_runtime_behavior",
+];
+
+/// where the value of a setting came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SettingSource {
+    Default,
+    Env(String),
+    ConfigFile(PathBuf),
+}
+
+impl Display for SettingSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingSource::Default => write!(f, "default"),
+            SettingSource::Env(var) => write!(f, "${var}"),
+            SettingSource::ConfigFile(path) => write!(f, "{}", display_path(path)),
+        }
+    }
+}
+
+/// the effective value of a setting with its type, see `Settings::all()`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum SettingValue {
+    Bool(bool),
+    Integer(u64),
+    String(String),
+    List(Vec<String>),
+    Map(BTreeMap<String, String>),
+    /// an optional setting without a value or default
+    Unset,
+}
+
+impl Display for SettingValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingValue::Bool(b) => write!(f, "{b}"),
+            SettingValue::Integer(i) => write!(f, "{i}"),
+            SettingValue::String(s) => write!(f, "{s}"),
+            SettingValue::List(l) => write!(f, "{l:?}"),
+            SettingValue::Map(m) => write!(f, "{m:?}"),
+            SettingValue::Unset => Ok(()),
+        }
+    }
 }
 
 impl Default for Settings {
@@ -46,72 +165,256 @@ impl Default for Settings {
             disable_default_shorthands: *RTX_DISABLE_DEFAULT_SHORTHANDS,
             disable_tools: RTX_DISABLE_TOOLS.clone(),
             log_level: *RTX_LOG_LEVEL,
+            disable_version_change_warnings: *RTX_DISABLE_VERSION_CHANGE_WARNINGS,
+            offline: *RTX_OFFLINE,
             raw: *RTX_RAW,
+            refresh_floating_aliases: *RTX_REFRESH_FLOATING_ALIASES,
+            prefer_installed_versions: *RTX_PREFER_INSTALLED_VERSIONS != Some(false),
+            source_precedence: RTX_SOURCE_PRECEDENCE
+                .clone()
+                .unwrap_or_else(|| ["arg", "stdin", "env", "config"].map(String::from).to_vec()),
+            strict: *RTX_STRICT,
+            require_checksums: *RTX_REQUIRE_CHECKSUMS,
+            default_plugins: RTX_DEFAULT_PLUGINS.clone(),
+            bootstrap_default_plugins: *RTX_BOOTSTRAP_DEFAULT_PLUGINS,
+            env_overrides_shell: *RTX_ENV_OVERRIDES_SHELL != Some(false),
+            default_config_filename: RTX_DEFAULT_CONFIG_FILENAME
+                .clone()
+                .unwrap_or_else(|| ".rtx.toml".into()),
+            default_tool_versions_filename: RTX_DEFAULT_TOOL_VERSIONS_FILENAME
+                .clone()
+                .unwrap_or_else(|| ".tool-versions".into()),
+            plugin_aliases: BTreeMap::new(),
+            mirror_prefix: RTX_MIRROR_PREFIX.clone(),
+            install_dir_template: RTX_INSTALL_DIR_TEMPLATE.clone(),
+            version_policy: BTreeMap::new(),
+            version_filter: BTreeMap::new(),
+            sources: BTreeMap::new(),
         }
     }
 }
 
+/// set from the loaded settings so code that doesn't have them (http, git, caches) can check it
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// fails if rtx is offline, `what` describes the network access, e.g.: "download https://..."
+pub fn ensure_online(what: &str) -> Result<()> {
+    check_online(is_offline(), what)
+}
+
+fn check_online(offline: bool, what: &str) -> Result<()> {
+    if offline {
+        return Err(eyre!("rtx is offline, cannot {what}")
+            .suggestion("unset RTX_OFFLINE or the offline setting to allow network access"));
+    }
+    Ok(())
+}
+
+pub fn is_offline() -> bool {
+    *RTX_OFFLINE || OFFLINE.load(Ordering::Relaxed)
+}
+
+/// the `default_config_filename` and `default_tool_versions_filename` settings, set from the
+/// global settings for code that finds or parses config files without them
+static DEFAULT_FILENAMES: Lazy<RwLock<(String, String)>> = Lazy::new(|| {
+    let settings = Settings::default();
+    RwLock::new((
+        settings.default_config_filename,
+        settings.default_tool_versions_filename,
+    ))
+});
+
+/// the name of the local config file, ".rtx.toml" by default
+pub fn default_config_filename() -> String {
+    DEFAULT_FILENAMES.read().unwrap().0.clone()
+}
+
+/// the name of the asdf-style config file, ".tool-versions" by default
+pub fn default_tool_versions_filename() -> String {
+    DEFAULT_FILENAMES.read().unwrap().1.clone()
+}
+
+/// `default_tool_versions_filename()` with a ".toml" extension
+pub fn default_tool_versions_toml_filename() -> String {
+    format!("{}.toml", default_tool_versions_filename())
+}
+
 impl Settings {
-    pub fn to_index_map(&self) -> BTreeMap<String, String> {
-        let mut map = BTreeMap::new();
-        map.insert("experimental".to_string(), self.experimental.to_string());
-        map.insert(
-            "missing_runtime_behavior".to_string(),
-            self.missing_runtime_behavior.to_string(),
-        );
-        map.insert(
-            "always_keep_download".to_string(),
-            self.always_keep_download.to_string(),
-        );
-        map.insert(
-            "always_keep_install".to_string(),
-            self.always_keep_install.to_string(),
-        );
-        map.insert(
-            "legacy_version_file".to_string(),
-            self.legacy_version_file.to_string(),
+    pub fn set_offline(&self) {
+        OFFLINE.store(self.offline, Ordering::Relaxed);
+    }
+
+    pub fn set_default_filenames(&self) {
+        *DEFAULT_FILENAMES.write().unwrap() = (
+            self.default_config_filename.clone(),
+            self.default_tool_versions_filename.clone(),
         );
-        map.insert(
-            "legacy_version_file_disable_tools".to_string(),
-            format!(
-                "{:?}",
-                self.legacy_version_file_disable_tools
-                    .iter()
-                    .collect::<Vec<_>>()
+    }
+
+    /// where the value of `key` came from: the config file that last set it, the env var it
+    /// defaults to, or the default. Settings that extend a list are attributed to the last
+    /// config file that added to it.
+    pub fn provenance(&self, key: &str) -> SettingSource {
+        let env_var = format!("RTX_{}", key.to_uppercase());
+        let from_env = ENV_KEYS.contains(&key) && std::env::var_os(&env_var).is_some();
+        match self.sources.get(key) {
+            Some(path) if !(from_env && ENV_OVERRIDES.contains(&key)) => {
+                SettingSource::ConfigFile(path.clone())
+            }
+            _ if from_env => SettingSource::Env(env_var),
+            _ => SettingSource::Default,
+        }
+    }
+
+    /// checks the settings that can come from env vars, the ones from config files are checked
+    /// when they're parsed
+    pub fn validate(&self) -> Result<()> {
+        if let Some(template) = &self.install_dir_template {
+            if let Some(err) = check_install_dir_template(template) {
+                return Err(eyre!("invalid install_dir_template {template}: {err}"));
+            }
+        }
+        self.source_precedence()?;
+        Ok(())
+    }
+
+    /// the `source_precedence` setting, which has to list every kind of source exactly once
+    pub fn source_precedence(&self) -> Result<Vec<ToolSourceKind>> {
+        let mut kinds = vec![];
+        for name in &self.source_precedence {
+            let kind = ToolSourceKind::from_str(name, true)
+                .map_err(|_| eyre!("invalid source_precedence: unknown source {name}"))?;
+            if kinds.contains(&kind) {
+                return Err(eyre!(
+                    "invalid source_precedence: {name} is listed more than once"
+                ));
+            }
+            kinds.push(kind);
+        }
+        let missing = ToolSourceKind::value_variants()
+            .iter()
+            .filter(|k| !kinds.contains(k))
+            .map(|k| k.to_possible_value().unwrap().get_name().to_string())
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(eyre!(
+                "invalid source_precedence: missing {}",
+                missing.join(", ")
+            ));
+        }
+        Ok(kinds)
+    }
+
+    /// every setting by name with its effective value, including the ones left at their default
+    /// and optional ones that aren't set
+    pub fn all(&self) -> IndexMap<String, SettingValue> {
+        use SettingValue::{Bool, Integer, List, Map, Unset};
+        let string = |s: &str| SettingValue::String(s.to_string());
+        let optional = |s: Option<&str>| s.map_or(Unset, string);
+        let strings = |set: &BTreeSet<String>| List(set.iter().cloned().collect());
+        let paths = |set: &BTreeSet<PathBuf>| {
+            List(
+                set.iter()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .collect(),
+            )
+        };
+        let display_map = |map: BTreeMap<&String, String>| {
+            Map(map.into_iter().map(|(k, v)| (k.clone(), v)).collect())
+        };
+        let mut map = IndexMap::from([
+            ("always_keep_download", Bool(self.always_keep_download)),
+            ("always_keep_install", Bool(self.always_keep_install)),
+            ("asdf_compat", Bool(self.asdf_compat)),
+            (
+                "bootstrap_default_plugins",
+                Bool(self.bootstrap_default_plugins),
             ),
-        );
-        map.insert(
-            "plugin_autoupdate_last_check_duration".to_string(),
-            (self.plugin_autoupdate_last_check_duration.as_secs() / 60).to_string(),
-        );
-        map.insert(
-            "trusted_config_paths".to_string(),
-            format!("{:?}", self.trusted_config_paths.iter().collect::<Vec<_>>()),
-        );
-        map.insert("verbose".into(), self.verbose.to_string());
-        map.insert("asdf_compat".into(), self.asdf_compat.to_string());
-        map.insert("jobs".into(), self.jobs.to_string());
-        if let Some(shorthands_file) = &self.shorthands_file {
-            map.insert(
-                "shorthands_file".into(),
-                shorthands_file.to_string_lossy().to_string(),
-            );
-        }
-        map.insert(
-            "disable_default_shorthands".into(),
-            self.disable_default_shorthands.to_string(),
-        );
-        map.insert(
-            "disable_tools".into(),
-            format!("{:?}", self.disable_tools.iter().collect::<Vec<_>>()),
-        );
-        map.insert("log_level".into(), self.log_level.to_string());
-        map.insert("raw".into(), self.raw.to_string());
-        map
+            (
+                "default_config_filename",
+                string(&self.default_config_filename),
+            ),
+            ("default_plugins", strings(&self.default_plugins)),
+            (
+                "default_tool_versions_filename",
+                string(&self.default_tool_versions_filename),
+            ),
+            (
+                "disable_default_shorthands",
+                Bool(self.disable_default_shorthands),
+            ),
+            ("disable_tools", strings(&self.disable_tools)),
+            (
+                "disable_version_change_warnings",
+                Bool(self.disable_version_change_warnings),
+            ),
+            ("env_overrides_shell", Bool(self.env_overrides_shell)),
+            ("experimental", Bool(self.experimental)),
+            (
+                "install_dir_template",
+                optional(self.install_dir_template.as_deref()),
+            ),
+            ("jobs", Integer(self.jobs as u64)),
+            ("legacy_version_file", Bool(self.legacy_version_file)),
+            (
+                "legacy_version_file_disable_tools",
+                strings(&self.legacy_version_file_disable_tools),
+            ),
+            ("log_level", string(&self.log_level.to_string())),
+            ("mirror_prefix", optional(self.mirror_prefix.as_deref())),
+            (
+                "missing_runtime_behavior",
+                string(&self.missing_runtime_behavior.to_string()),
+            ),
+            ("offline", Bool(self.offline)),
+            ("plugin_aliases", Map(self.plugin_aliases.clone())),
+            (
+                "plugin_autoupdate_last_check_duration",
+                Integer(self.plugin_autoupdate_last_check_duration.as_secs() / 60),
+            ),
+            (
+                "prefer_installed_versions",
+                Bool(self.prefer_installed_versions),
+            ),
+            ("raw", Bool(self.raw)),
+            (
+                "refresh_floating_aliases",
+                Bool(self.refresh_floating_aliases),
+            ),
+            ("require_checksums", Bool(self.require_checksums)),
+            (
+                "shorthands_file",
+                optional(self.shorthands_file.as_ref().and_then(|p| p.to_str())),
+            ),
+            ("source_precedence", List(self.source_precedence.clone())),
+            ("strict", Bool(self.strict)),
+            ("trusted_config_paths", paths(&self.trusted_config_paths)),
+            ("verbose", Bool(self.verbose)),
+            (
+                "version_filter",
+                display_map(
+                    self.version_filter
+                        .iter()
+                        .map(|(k, v)| (k, v.to_string()))
+                        .collect(),
+                ),
+            ),
+            (
+                "version_policy",
+                display_map(
+                    self.version_policy
+                        .iter()
+                        .map(|(k, v)| (k, v.to_string()))
+                        .collect(),
+                ),
+            ),
+        ]);
+        map.sort_keys();
+        map.into_iter().map(|(k, v)| (k.to_string(), v)).collect()
     }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct SettingsBuilder {
     pub experimental: Option<bool>,
     pub missing_runtime_behavior: Option<MissingRuntimeBehavior>,
@@ -128,7 +431,27 @@ pub struct SettingsBuilder {
     pub disable_default_shorthands: Option<bool>,
     pub disable_tools: BTreeSet<String>,
     pub log_level: Option<LevelFilter>,
+    pub disable_version_change_warnings: Option<bool>,
+    pub offline: Option<bool>,
     pub raw: Option<bool>,
+    pub refresh_floating_aliases: Option<bool>,
+    pub prefer_installed_versions: Option<bool>,
+    pub source_precedence: Option<Vec<String>>,
+    pub strict: Option<bool>,
+    pub require_checksums: Option<bool>,
+    pub default_plugins: BTreeSet<String>,
+    pub bootstrap_default_plugins: Option<bool>,
+    pub env_overrides_shell: Option<bool>,
+    pub default_config_filename: Option<String>,
+    pub default_tool_versions_filename: Option<String>,
+    pub plugin_aliases: BTreeMap<String, String>,
+    pub mirror_prefix: Option<String>,
+    pub install_dir_template: Option<String>,
+    pub version_policy: BTreeMap<String, VersionPolicy>,
+    pub version_filter: BTreeMap<String, VersionFilter>,
+    /// the config file that last set each setting, see `merge_from()`
+    #[serde(skip)]
+    pub sources: BTreeMap<&'static str, PathBuf>,
 }
 
 impl SettingsBuilder {
@@ -139,51 +462,135 @@ impl SettingsBuilder {
     // }
 
     pub fn merge(&mut self, other: Self) -> &mut Self {
-        if other.experimental.is_some() {
-            self.experimental = other.experimental;
+        self.merge_keys(other);
+        self
+    }
+
+    /// merges `other` like `merge()`, recording `path` as the source of the settings it sets
+    pub fn merge_from(&mut self, other: Self, path: &Path) -> &mut Self {
+        for key in self.merge_keys(other) {
+            self.sources.insert(key, path.to_path_buf());
         }
-        if other.missing_runtime_behavior.is_some() {
-            self.missing_runtime_behavior = other.missing_runtime_behavior;
+        self
+    }
+
+    /// merges `other`, returning the keys of the settings it sets
+    fn merge_keys(&mut self, other: Self) -> Vec<&'static str> {
+        let mut keys = vec![];
+        macro_rules! replace {
+            ($($key:ident),+ $(,)?) => {$(
+                if other.$key.is_some() {
+                    self.$key = other.$key;
+                    keys.push(stringify!($key));
+                }
+            )+};
         }
-        if other.always_keep_download.is_some() {
-            self.always_keep_download = other.always_keep_download;
+        macro_rules! extend {
+            ($($key:ident),+ $(,)?) => {$(
+                if !other.$key.is_empty() {
+                    self.$key.extend(other.$key);
+                    keys.push(stringify!($key));
+                }
+            )+};
         }
-        if other.always_keep_install.is_some() {
-            self.always_keep_install = other.always_keep_install;
+        replace!(
+            experimental,
+            missing_runtime_behavior,
+            always_keep_download,
+            always_keep_install,
+            legacy_version_file,
+            plugin_autoupdate_last_check_duration,
+            verbose,
+            asdf_compat,
+            jobs,
+            shorthands_file,
+            disable_default_shorthands,
+            log_level,
+            disable_version_change_warnings,
+            offline,
+            raw,
+            refresh_floating_aliases,
+            prefer_installed_versions,
+            source_precedence,
+            strict,
+            require_checksums,
+            bootstrap_default_plugins,
+            env_overrides_shell,
+            default_config_filename,
+            default_tool_versions_filename,
+            mirror_prefix,
+            install_dir_template,
+        );
+        extend!(
+            legacy_version_file_disable_tools,
+            trusted_config_paths,
+            disable_tools,
+            default_plugins,
+            plugin_aliases,
+            version_policy,
+            version_filter,
+        );
+        self.sources.extend(other.sources);
+        keys
+    }
+
+    /// clears settings that only make sense in the global config (they're read before project
+    /// config files are loaded or would let a project loosen security), returning the keys that
+    /// were set
+    pub fn remove_global_only(&mut self) -> Vec<&'static str> {
+        let mut removed = vec![];
+        if self.plugin_autoupdate_last_check_duration.take().is_some() {
+            removed.push("plugin_autoupdate_last_check_duration");
         }
-        if other.legacy_version_file.is_some() {
-            self.legacy_version_file = other.legacy_version_file;
+        if !std::mem::take(&mut self.trusted_config_paths).is_empty() {
+            removed.push("trusted_config_paths");
         }
-        self.legacy_version_file_disable_tools
-            .extend(other.legacy_version_file_disable_tools);
-        if other.plugin_autoupdate_last_check_duration.is_some() {
-            self.plugin_autoupdate_last_check_duration =
-                other.plugin_autoupdate_last_check_duration;
+        if self.jobs.take().is_some() {
+            removed.push("jobs");
         }
-        self.trusted_config_paths.extend(other.trusted_config_paths);
-        if other.verbose.is_some() {
-            self.verbose = other.verbose;
+        if self.shorthands_file.take().is_some() {
+            removed.push("shorthands_file");
         }
-        if other.asdf_compat.is_some() {
-            self.asdf_compat = other.asdf_compat;
+        if self.disable_default_shorthands.take().is_some() {
+            removed.push("disable_default_shorthands");
         }
-        if other.jobs.is_some() {
-            self.jobs = other.jobs;
+        if !std::mem::take(&mut self.disable_tools).is_empty() {
+            removed.push("disable_tools");
         }
-        if other.shorthands_file.is_some() {
-            self.shorthands_file = other.shorthands_file;
+        if !std::mem::take(&mut self.plugin_aliases).is_empty() {
+            removed.push("plugin_aliases");
         }
-        if other.disable_default_shorthands.is_some() {
-            self.disable_default_shorthands = other.disable_default_shorthands;
+        if self.default_config_filename.take().is_some() {
+            removed.push("default_config_filename");
         }
-        self.disable_tools.extend(other.disable_tools);
-        if other.log_level.is_some() {
-            self.log_level = other.log_level;
+        if self.default_tool_versions_filename.take().is_some() {
+            removed.push("default_tool_versions_filename");
         }
-        if other.raw.is_some() {
-            self.raw = other.raw;
+        if self.install_dir_template.take().is_some() {
+            removed.push("install_dir_template");
         }
-        self
+        if self.mirror_prefix.take().is_some() {
+            removed.push("mirror_prefix");
+        }
+        if self.require_checksums.take().is_some() {
+            removed.push("require_checksums");
+        }
+        if !std::mem::take(&mut self.default_plugins).is_empty() {
+            removed.push("default_plugins");
+        }
+        if self.bootstrap_default_plugins.take().is_some() {
+            removed.push("bootstrap_default_plugins");
+        }
+        if !std::mem::take(&mut self.version_policy).is_empty() {
+            removed.push("version_policy");
+        }
+        if !std::mem::take(&mut self.version_filter).is_empty() {
+            removed.push("version_filter");
+        }
+        if self.source_precedence.take().is_some() {
+            removed.push("source_precedence");
+        }
+        removed
     }
 
     pub fn build(&self) -> Settings {
@@ -230,7 +637,51 @@ impl SettingsBuilder {
             .unwrap_or(settings.disable_default_shorthands);
         settings.disable_tools.extend(self.disable_tools.clone());
         settings.log_level = self.log_level.unwrap_or(settings.log_level);
+        settings.disable_version_change_warnings = self
+            .disable_version_change_warnings
+            .unwrap_or(settings.disable_version_change_warnings);
+        settings.offline = self.offline.unwrap_or(settings.offline);
         settings.raw = self.raw.unwrap_or(settings.raw);
+        settings.refresh_floating_aliases = self
+            .refresh_floating_aliases
+            .unwrap_or(settings.refresh_floating_aliases);
+        settings.prefer_installed_versions = self
+            .prefer_installed_versions
+            .unwrap_or(settings.prefer_installed_versions);
+        if let Some(source_precedence) = &self.source_precedence {
+            settings.source_precedence = source_precedence.clone();
+        }
+        settings.strict = self.strict.unwrap_or(settings.strict);
+        settings.require_checksums = self.require_checksums.unwrap_or(settings.require_checksums);
+        settings
+            .default_plugins
+            .extend(self.default_plugins.clone());
+        settings.bootstrap_default_plugins = self
+            .bootstrap_default_plugins
+            .unwrap_or(settings.bootstrap_default_plugins);
+        settings.env_overrides_shell = self
+            .env_overrides_shell
+            .unwrap_or(settings.env_overrides_shell);
+        // the env vars override the config files
+        if RTX_DEFAULT_CONFIG_FILENAME.is_none() {
+            if let Some(filename) = &self.default_config_filename {
+                settings.default_config_filename = filename.clone();
+            }
+        }
+        if RTX_DEFAULT_TOOL_VERSIONS_FILENAME.is_none() {
+            if let Some(filename) = &self.default_tool_versions_filename {
+                settings.default_tool_versions_filename = filename.clone();
+            }
+        }
+        settings.plugin_aliases.extend(self.plugin_aliases.clone());
+        settings.mirror_prefix = self.mirror_prefix.clone().or(settings.mirror_prefix);
+        settings.install_dir_template = self
+            .install_dir_template
+            .clone()
+            .or(settings.install_dir_template);
+        settings.version_policy.extend(self.version_policy.clone());
+        settings.version_filter.extend(self.version_filter.clone());
+        settings.sources = self.sources.clone();
 
         if settings.raw {
             settings.verbose = true;
@@ -241,13 +692,156 @@ impl SettingsBuilder {
     }
 }
 
+/// what's wrong with an `install_dir_template`, if anything. `{version}` has to be the last
+/// part of the path so the versions of a plugin (and its runtime symlinks) share a directory.
+pub fn check_install_dir_template(template: &str) -> Option<String> {
+    let Some(dir) = template.strip_suffix("/{version}") else {
+        return Some("it must end with /{version}".into());
+    };
+    if !dir.contains("{plugin}") {
+        return Some("it must contain {plugin}".into());
+    }
+    if dir.contains("{version}") {
+        return Some("{version} can only be the last part of the path".into());
+    }
+    None
+}
+
 impl Display for Settings {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        self.to_index_map().fmt(f)
+        let all = self.all().into_iter().map(|(k, v)| (k, v.to_string()));
+        f.debug_map().entries(all).finish()
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+/// which resolved versions of a plugin may be installed or run, from
+/// `[settings.version_policy.<plugin>]`, plugins without a policy allow any version
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct VersionPolicy {
+    pub deny_prerelease: bool,
+    pub require_semver: bool,
+    /// version prefixes that are allowed, any version is if this is empty
+    pub allow: Vec<String>,
+    /// version prefixes that are denied even if they're allowed
+    pub deny: Vec<String>,
+}
+
+impl VersionPolicy {
+    /// why the policy doesn't allow `version`, if it doesn't
+    pub fn check(&self, version: &str) -> Option<String> {
+        // "20" covers 20 and 20.x but not 200
+        let matches =
+            |prefix: &String| version == prefix || version.starts_with(&format!("{prefix}."));
+        if self.deny_prerelease && is_prerelease(version) {
+            return Some("prerelease versions are denied".into());
+        }
+        if self.require_semver && semver::Version::parse(version).is_err() {
+            return Some("only semver versions are allowed".into());
+        }
+        if !self.allow.is_empty() && !self.allow.iter().any(matches) {
+            return Some(format!(
+                "only versions matching {:?} are allowed",
+                self.allow
+            ));
+        }
+        if let Some(prefix) = self.deny.iter().find(|p| matches(p)) {
+            return Some(format!("versions matching {prefix} are denied"));
+        }
+        None
+    }
+}
+
+/// e.g.: "deny_prerelease, allow 18 20, deny 19"
+impl Display for VersionPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut parts = vec![];
+        if self.deny_prerelease {
+            parts.push("deny_prerelease".to_string());
+        }
+        if self.require_semver {
+            parts.push("require_semver".to_string());
+        }
+        if !self.allow.is_empty() {
+            parts.push(format!("allow {}", self.allow.join(" ")));
+        }
+        if !self.deny.is_empty() {
+            parts.push(format!("deny {}", self.deny.join(" ")));
+        }
+        match parts.is_empty() {
+            true => write!(f, "any version"),
+            false => write!(f, "{}", parts.join(", ")),
+        }
+    }
+}
+
+/// regexes for the versions of a plugin that fuzzy versions like `18`, `latest`, or `^18` may
+/// resolve to, from `[settings.version_filter.<plugin>]`. They're compiled when parsing the config.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(try_from = "VersionFilterPatterns", into = "VersionFilterPatterns")]
+pub struct VersionFilter {
+    /// only versions matching this are candidates
+    pub include: Option<Regex>,
+    /// versions matching this are never candidates, e.g.: `musl`
+    pub exclude: Option<Regex>,
+}
+
+impl VersionFilter {
+    pub fn retain(&self, versions: Vec<String>) -> Vec<String> {
+        versions
+            .into_iter()
+            .filter(|v| self.include.as_ref().map_or(true, |re| re.is_match(v)))
+            .filter(|v| self.exclude.as_ref().map_or(true, |re| !re.is_match(v)))
+            .collect()
+    }
+}
+
+/// the serialized form of `VersionFilter` for the config cache
+#[derive(Serialize, Deserialize)]
+struct VersionFilterPatterns {
+    include: Option<String>,
+    exclude: Option<String>,
+}
+
+impl TryFrom<VersionFilterPatterns> for VersionFilter {
+    type Error = regex::Error;
+
+    fn try_from(patterns: VersionFilterPatterns) -> Result<Self, Self::Error> {
+        let regex = |re: Option<String>| re.map(|re| Regex::new(&re)).transpose();
+        Ok(Self {
+            include: regex(patterns.include)?,
+            exclude: regex(patterns.exclude)?,
+        })
+    }
+}
+
+/// e.g.: "include ^20, exclude musl"
+impl Display for VersionFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut parts = vec![];
+        if let Some(re) = &self.include {
+            parts.push(format!("include {re}"));
+        }
+        if let Some(re) = &self.exclude {
+            parts.push(format!("exclude {re}"));
+        }
+        match parts.is_empty() {
+            true => write!(f, "any version"),
+            false => write!(f, "{}", parts.join(", ")),
+        }
+    }
+}
+
+impl From<VersionFilter> for VersionFilterPatterns {
+    fn from(filter: VersionFilter) -> Self {
+        let pattern = |re: Option<Regex>| re.map(|re| re.as_str().to_string());
+        Self {
+            include: pattern(filter.include),
+            exclude: pattern(filter.exclude),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum MissingRuntimeBehavior {
     AutoInstall,
     Prompt,
@@ -283,6 +877,242 @@ mod tests {
         assert_eq!(s1.missing_runtime_behavior, Some(AutoInstall));
     }
 
+    #[test]
+    fn test_check_install_dir_template() {
+        assert_eq!(check_install_dir_template("/sdks/{plugin}/{version}"), None);
+        assert_eq!(
+            check_install_dir_template("/sdks/{plugin}").unwrap(),
+            "it must end with /{version}"
+        );
+        assert_eq!(
+            check_install_dir_template("/sdks/{version}").unwrap(),
+            "it must contain {plugin}"
+        );
+        assert_eq!(
+            check_install_dir_template("/sdks/{version}/{plugin}/{version}").unwrap(),
+            "{version} can only be the last part of the path"
+        );
+        let settings = Settings {
+            install_dir_template: Some("/sdks".into()),
+            ..Settings::default()
+        };
+        assert_eq!(
+            settings.validate().unwrap_err().to_string(),
+            "invalid install_dir_template /sdks: it must end with /{version}"
+        );
+    }
+
+    #[test]
+    fn test_validate_source_precedence() {
+        let err = |precedence: &[&str]| {
+            let settings = Settings {
+                source_precedence: precedence.iter().map(|s| s.to_string()).collect(),
+                ..Settings::default()
+            };
+            settings.validate().unwrap_err().to_string()
+        };
+        assert_eq!(
+            err(&["arg", "env", "config"]),
+            "invalid source_precedence: missing stdin"
+        );
+        assert_eq!(
+            err(&["arg", "env", "config", "stdin", "env"]),
+            "invalid source_precedence: env is listed more than once"
+        );
+        assert_eq!(
+            err(&["arg", "env", "config", "file"]),
+            "invalid source_precedence: unknown source file"
+        );
+    }
+
+    #[test]
+    fn test_check_online() {
+        assert!(check_online(false, "download").is_ok());
+        let err = check_online(true, "download https://example.com").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "rtx is offline, cannot download https://example.com"
+        );
+    }
+
+    #[test]
+    fn test_version_policy() {
+        let policy = VersionPolicy {
+            deny_prerelease: true,
+            allow: vec!["20".into(), "18".into()],
+            deny: vec!["18.0".into()],
+            ..VersionPolicy::default()
+        };
+        assert_eq!(policy.check("20.1.0"), None);
+        assert_eq!(policy.check("18.1.0"), None);
+        assert_eq!(
+            policy.check("21.0.0-rc.1").unwrap(),
+            "prerelease versions are denied"
+        );
+        assert_eq!(
+            policy.check("200.0.0").unwrap(),
+            r#"only versions matching ["20", "18"] are allowed"#
+        );
+        assert_eq!(
+            policy.check("18.0.1").unwrap(),
+            "versions matching 18.0 are denied"
+        );
+
+        let policy = VersionPolicy {
+            require_semver: true,
+            ..VersionPolicy::default()
+        };
+        assert_eq!(policy.check("1.2.3"), None);
+        assert_eq!(
+            policy.check("ref-main").unwrap(),
+            "only semver versions are allowed"
+        );
+        assert_eq!(VersionPolicy::default().check("ref-main"), None);
+    }
+
+    #[test]
+    fn test_version_filter() {
+        let filter = VersionFilter {
+            include: Some(Regex::new(r"^18\.").unwrap()),
+            exclude: Some(Regex::new("musl").unwrap()),
+        };
+        let versions = ["16.0.0", "18.0.0", "18.1.0-musl", "18.1.0"];
+        let versions = versions.iter().map(|v| v.to_string()).collect();
+        assert_eq!(filter.retain(versions), vec!["18.0.0", "18.1.0"]);
+    }
+
+    #[test]
+    fn test_remove_global_only() {
+        let mut s = SettingsBuilder {
+            jobs: Some(4),
+            verbose: Some(true),
+            ..SettingsBuilder::default()
+        };
+        assert_eq!(s.remove_global_only(), vec!["jobs"]);
+        assert_eq!(s.jobs, None);
+        assert_eq!(s.verbose, Some(true));
+
+        let mut s = SettingsBuilder {
+            require_checksums: Some(false),
+            ..SettingsBuilder::default()
+        };
+        assert_eq!(s.remove_global_only(), vec!["require_checksums"]);
+        assert_eq!(s.require_checksums, None);
+
+        let mut s = SettingsBuilder {
+            default_plugins: ["node".into()].into(),
+            bootstrap_default_plugins: Some(true),
+            ..SettingsBuilder::default()
+        };
+        assert_eq!(
+            s.remove_global_only(),
+            vec!["default_plugins", "bootstrap_default_plugins"]
+        );
+        assert!(s.default_plugins.is_empty());
+
+        let mut s = SettingsBuilder {
+            version_policy: [("node".into(), VersionPolicy::default())].into(),
+            ..SettingsBuilder::default()
+        };
+        assert_eq!(s.remove_global_only(), vec!["version_policy"]);
+        assert!(s.version_policy.is_empty());
+
+        let mut s = SettingsBuilder {
+            source_precedence: Some(vec!["config".into()]),
+            ..SettingsBuilder::default()
+        };
+        assert_eq!(s.remove_global_only(), vec!["source_precedence"]);
+
+        let mut s = SettingsBuilder {
+            version_filter: [("node".into(), VersionFilter::default())].into(),
+            ..SettingsBuilder::default()
+        };
+        assert_eq!(s.remove_global_only(), vec!["version_filter"]);
+    }
+
+    #[test]
+    fn test_provenance() {
+        let mut s = SettingsBuilder::default();
+        let s1 = SettingsBuilder {
+            jobs: Some(4),
+            verbose: Some(true),
+            ..SettingsBuilder::default()
+        };
+        let s2 = SettingsBuilder {
+            verbose: Some(false),
+            missing_runtime_behavior: Some(Warn),
+            ..SettingsBuilder::default()
+        };
+        s.merge_from(s1, Path::new("/a/config.toml"));
+        s.merge_from(s2, Path::new("/b/.rtx.toml"));
+        let settings = s.build();
+
+        let provenance = |key| settings.provenance(key).to_string();
+        assert_eq!(provenance("jobs"), "/a/config.toml");
+        assert_eq!(provenance("verbose"), "/b/.rtx.toml");
+        // set in the env by the tests, which overrides the config files
+        assert_eq!(
+            provenance("missing_runtime_behavior"),
+            "$RTX_MISSING_RUNTIME_BEHAVIOR"
+        );
+        assert_eq!(provenance("raw"), "default");
+    }
+
+    #[test]
+    fn test_all() {
+        let policy = VersionPolicy {
+            deny_prerelease: true,
+            allow: vec!["18".into(), "20".into()],
+            ..VersionPolicy::default()
+        };
+        let filter = VersionFilter {
+            exclude: Some(Regex::new("musl").unwrap()),
+            ..VersionFilter::default()
+        };
+        let settings = SettingsBuilder {
+            jobs: Some(4),
+            missing_runtime_behavior: Some(AutoInstall),
+            version_policy: [("node".into(), policy)].into(),
+            version_filter: [("node".into(), filter)].into(),
+            ..SettingsBuilder::default()
+        }
+        .build();
+        let all = settings.all();
+        assert_eq!(all["jobs"], SettingValue::Integer(4));
+        assert_eq!(
+            all["missing_runtime_behavior"],
+            SettingValue::String("autoinstall".into())
+        );
+        let map = |v: &str| SettingValue::Map([("node".into(), v.into())].into());
+        assert_eq!(all["version_policy"], map("deny_prerelease, allow 18 20"));
+        assert_eq!(all["version_filter"], map("exclude musl"));
+        assert_eq!(all["mirror_prefix"], SettingValue::Unset);
+        assert_eq!(
+            serde_json::to_string(&all["missing_runtime_behavior"]).unwrap(),
+            r#""autoinstall""#
+        );
+    }
+
+    #[test]
+    fn test_default_filenames() {
+        let s = SettingsBuilder {
+            default_config_filename: Some(".other.toml".into()),
+            default_tool_versions_filename: Some(".other-tool-versions".into()),
+            ..SettingsBuilder::default()
+        };
+        // set in the env by the tests, which overrides the config files
+        let settings = s.build();
+        assert_eq!(settings.default_config_filename, ".test.rtx.toml");
+        assert_eq!(
+            settings.default_tool_versions_filename,
+            ".test-tool-versions"
+        );
+        assert_eq!(
+            default_tool_versions_toml_filename(),
+            ".test-tool-versions.toml"
+        );
+    }
+
     #[test]
     fn test_missing_runtime_behavior_display() {
         assert_eq!(AutoInstall.to_string(), "autoinstall");
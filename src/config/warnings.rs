@@ -0,0 +1,51 @@
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Mutex};
+
+use itertools::Itertools;
+
+use crate::env;
+
+/// a non-fatal problem found while loading the config or resolving the toolset
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Warning {
+    /// what the warning came from, e.g.: a config file or a plugin
+    pub source: String,
+    pub message: String,
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.source, self.message)
+    }
+}
+
+/// warnings collected while rtx runs instead of being printed right away, the CLI renders them
+/// once the command has finished. Clones share the same list so the CLI can keep a handle after
+/// the config has been moved into the command.
+#[derive(Debug, Default, Clone)]
+pub struct Warnings(Arc<Mutex<Vec<Warning>>>);
+
+impl Warnings {
+    pub fn push<S: Into<String>, M: Into<String>>(&self, source: S, message: M) {
+        self.0.lock().unwrap().push(Warning {
+            source: source.into(),
+            message: message.into(),
+        });
+    }
+
+    /// removes the warnings collected so far so they aren't rendered again
+    pub fn take(&self) -> Vec<Warning> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+
+    /// prints the warnings collected so far unless RTX_QUIET is set, each one is only shown once
+    pub fn render(&self) {
+        let warnings = self.take();
+        if *env::RTX_QUIET {
+            return;
+        }
+        for warning in warnings.into_iter().unique() {
+            warn!("{warning}");
+        }
+    }
+}
@@ -16,7 +16,13 @@ pub static SHELL: Lazy<String> = Lazy::new(|| var("SHELL").unwrap_or_else(|_| "s
 // paths and directories
 pub static HOME: Lazy<PathBuf> =
     Lazy::new(|| dirs_next::home_dir().unwrap_or_else(|| PathBuf::from("/")));
-pub static PWD: Lazy<PathBuf> = Lazy::new(|| current_dir().unwrap_or_else(|_| PathBuf::new()));
+/// falls back to $PWD when the cwd can't be read, e.g.: it was deleted after the shell cd'd into it
+pub static PWD: Lazy<PathBuf> = Lazy::new(|| {
+    current_dir()
+        .ok()
+        .or_else(|| var_os("PWD").map(PathBuf::from))
+        .unwrap_or_default()
+});
 pub static XDG_CACHE_HOME: Lazy<PathBuf> =
     Lazy::new(|| dirs_next::cache_dir().unwrap_or_else(|| HOME.join(".cache")));
 pub static XDG_DATA_HOME: Lazy<PathBuf> =
@@ -31,13 +37,16 @@ pub static RTX_DATA_DIR: Lazy<PathBuf> =
     Lazy::new(|| var_path("RTX_DATA_DIR").unwrap_or_else(|| XDG_DATA_HOME.join("rtx")));
 pub static RTX_TMP_DIR: Lazy<PathBuf> = Lazy::new(|| temp_dir().join("rtx"));
 
-pub static RTX_DEFAULT_TOOL_VERSIONS_FILENAME: Lazy<String> = Lazy::new(|| {
-    var("RTX_DEFAULT_TOOL_VERSIONS_FILENAME").unwrap_or_else(|_| ".tool-versions".into())
-});
-pub static RTX_DEFAULT_CONFIG_FILENAME: Lazy<String> =
-    Lazy::new(|| var("RTX_DEFAULT_CONFIG_FILENAME").unwrap_or_else(|_| ".rtx.toml".into()));
+pub static RTX_DEFAULT_TOOL_VERSIONS_FILENAME: Lazy<Option<String>> =
+    Lazy::new(|| var("RTX_DEFAULT_TOOL_VERSIONS_FILENAME").ok());
+pub static RTX_DEFAULT_CONFIG_FILENAME: Lazy<Option<String>> =
+    Lazy::new(|| var("RTX_DEFAULT_CONFIG_FILENAME").ok());
 pub static RTX_ENV: Lazy<Option<String>> = Lazy::new(|| var("RTX_ENV").ok());
+pub static RTX_PROFILE: Lazy<Option<String>> = Lazy::new(|| var("RTX_PROFILE").ok());
 pub static RTX_CONFIG_FILE: Lazy<Option<PathBuf>> = Lazy::new(|| var_path("RTX_CONFIG_FILE"));
+pub static RTX_SYSTEM_CONFIG_FILE: Lazy<PathBuf> = Lazy::new(|| {
+    var_path("RTX_SYSTEM_CONFIG_FILE").unwrap_or_else(|| PathBuf::from("/etc/rtx/config.toml"))
+});
 pub static RTX_USE_TOML: Lazy<bool> = Lazy::new(|| var_is_true("RTX_USE_TOML"));
 pub static RTX_EXE: Lazy<PathBuf> = Lazy::new(|| current_exe().unwrap_or_else(|_| "rtx".into()));
 pub static RTX_LOG_LEVEL: Lazy<LevelFilter> = Lazy::new(log_level);
@@ -77,17 +86,45 @@ pub static DIRENV_DIFF: Lazy<Option<String>> = Lazy::new(|| var("DIRENV_DIFF").o
 pub static RTX_CONFIRM: Lazy<Confirm> = Lazy::new(|| var_confirm("RTX_CONFIRM"));
 pub static RTX_EXPERIMENTAL: Lazy<bool> = Lazy::new(|| var_is_true("RTX_EXPERIMENTAL"));
 pub static RTX_ASDF_COMPAT: Lazy<bool> = Lazy::new(|| var_is_true("RTX_ASDF_COMPAT"));
+pub static RTX_OFFLINE: Lazy<bool> = Lazy::new(|| var_is_true("RTX_OFFLINE"));
+pub static RTX_DISABLE_VERSION_CHANGE_WARNINGS: Lazy<bool> =
+    Lazy::new(|| var_is_true("RTX_DISABLE_VERSION_CHANGE_WARNINGS"));
+pub static RTX_CONFIG_CACHE: Lazy<bool> = Lazy::new(|| var_is_true("RTX_CONFIG_CACHE"));
+pub static RTX_NO_EXEC_ENV_CACHE: Lazy<bool> = Lazy::new(|| var_is_true("RTX_NO_EXEC_ENV_CACHE"));
+pub static RTX_REFRESH_FLOATING_ALIASES: Lazy<bool> =
+    Lazy::new(|| var_is_true("RTX_REFRESH_FLOATING_ALIASES"));
+pub static RTX_STRICT: Lazy<bool> = Lazy::new(|| var_is_true("RTX_STRICT"));
+pub static RTX_REQUIRE_CHECKSUMS: Lazy<bool> = Lazy::new(|| var_is_true("RTX_REQUIRE_CHECKSUMS"));
+pub static RTX_BOOTSTRAP_DEFAULT_PLUGINS: Lazy<bool> =
+    Lazy::new(|| var_is_true("RTX_BOOTSTRAP_DEFAULT_PLUGINS"));
 pub static RTX_SHORTHANDS_FILE: Lazy<Option<PathBuf>> =
     Lazy::new(|| var_path("RTX_SHORTHANDS_FILE"));
+pub static RTX_MIRROR_PREFIX: Lazy<Option<String>> = Lazy::new(|| var("RTX_MIRROR_PREFIX").ok());
+pub static RTX_INSTALL_DIR_TEMPLATE: Lazy<Option<String>> =
+    Lazy::new(|| var("RTX_INSTALL_DIR_TEMPLATE").ok());
 pub static RTX_DISABLE_DEFAULT_SHORTHANDS: Lazy<bool> =
     Lazy::new(|| var_is_true("RTX_DISABLE_DEFAULT_SHORTHANDS"));
 pub static RTX_LEGACY_VERSION_FILE: Lazy<Option<bool>> =
     Lazy::new(|| var_option_bool("RTX_LEGACY_VERSION_FILE"));
+pub static RTX_PREFER_INSTALLED_VERSIONS: Lazy<Option<bool>> =
+    Lazy::new(|| var_option_bool("RTX_PREFER_INSTALLED_VERSIONS"));
+pub static RTX_ENV_OVERRIDES_SHELL: Lazy<Option<bool>> =
+    Lazy::new(|| var_option_bool("RTX_ENV_OVERRIDES_SHELL"));
+pub static RTX_SOURCE_PRECEDENCE: Lazy<Option<Vec<String>>> = Lazy::new(|| {
+    var("RTX_SOURCE_PRECEDENCE")
+        .ok()
+        .map(|v| v.split(',').map(|s| s.trim().to_string()).collect())
+});
 pub static RTX_LEGACY_VERSION_FILE_DISABLE_TOOLS: Lazy<BTreeSet<String>> = Lazy::new(|| {
     var("RTX_LEGACY_VERSION_FILE_DISABLE_TOOLS")
         .map(|v| v.split(',').map(|s| s.to_string()).collect())
         .unwrap_or_default()
 });
+pub static RTX_DEFAULT_PLUGINS: Lazy<BTreeSet<String>> = Lazy::new(|| {
+    var("RTX_DEFAULT_PLUGINS")
+        .map(|v| v.split(',').map(|s| s.to_string()).collect())
+        .unwrap_or_default()
+});
 pub static RTX_DISABLE_TOOLS: Lazy<BTreeSet<String>> = Lazy::new(|| {
     var("RTX_DISABLE_TOOLS")
         .map(|v| v.split(',').map(|s| s.to_string()).collect())
@@ -198,6 +235,19 @@ pub enum Confirm {
     Prompt,
 }
 
+/// env var names are case-insensitive on windows so they're uppercased there to keep `Path` and
+/// `PATH` from being two different keys, elsewhere they're left as-is
+pub fn normalize_key(key: &str) -> String {
+    normalize_key_with(key, cfg!(windows))
+}
+
+fn normalize_key_with(key: &str, case_insensitive: bool) -> String {
+    match case_insensitive {
+        true => key.to_ascii_uppercase(),
+        false => key.to_string(),
+    }
+}
+
 fn get_env_diff() -> EnvDiff {
     let env = vars().collect::<HashMap<_, _>>();
     match env.get("__RTX_DIFF") {
@@ -415,4 +465,14 @@ mod tests {
         remove_var("RTX_TEST_CONFIRM");
         assert_eq!(var_confirm("RTX_TEST_CONFIRM"), Confirm::Prompt);
     }
+
+    #[test]
+    fn test_normalize_key() {
+        assert_eq!(normalize_key_with("Path", true), "PATH");
+        assert_eq!(
+            normalize_key_with("rtx_node_version", true),
+            "RTX_NODE_VERSION"
+        );
+        assert_eq!(normalize_key_with("Path", false), "Path");
+    }
 }
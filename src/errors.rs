@@ -19,6 +19,27 @@ pub enum Error {
     UntrustedConfig(),
 }
 
+/// a download that failed verification while installing a tool version
+#[derive(Error, Debug)]
+pub enum InstallError {
+    #[error("checksum mismatch for {path}\nexpected: {expected}\nactual:   {actual}")]
+    ChecksumMismatch {
+        path: String,
+        expected: String,
+        actual: String,
+    },
+    #[error("no checksum available for {0}, one is required by the require_checksums setting")]
+    ChecksumMissing(String),
+    #[error("invalid signature for {0}")]
+    SignatureInvalid(String),
+    #[error("could not verify the signature of {0}, gpg and the signing key are required by the require_checksums setting")]
+    SignatureUnverified(String),
+    #[error(
+        "{0} does not verify checksums, it can't be installed with the require_checksums setting"
+    )]
+    ChecksumUnsupported(String),
+}
+
 fn render_exit_status(exit_status: &Option<ExitStatus>) -> String {
     match exit_status.and_then(|s| s.code()) {
         Some(exit_status) => format!("exit code {exit_status}"),
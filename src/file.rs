@@ -1,7 +1,9 @@
-use std::fs::File;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
 use std::os::unix::fs::symlink;
 use std::os::unix::prelude::*;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use std::{fs, io};
 
@@ -167,6 +169,46 @@ pub fn make_executable(path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// writes to a temp file next to `path` and renames it over `path` so a crash or failed write
+/// never leaves it partially written, the permissions of the existing file are kept. If `path` is
+/// a symlink the file it points to is replaced instead of the link.
+pub fn write_atomic<C: AsRef<[u8]>>(path: &Path, contents: C) -> Result<()> {
+    write_atomic_with(path, |f| f.write_all(contents.as_ref()))
+}
+
+fn write_atomic_with<F: FnOnce(&mut File) -> io::Result<()>>(path: &Path, write: F) -> Result<()> {
+    // unique per write so threads writing the same file don't share a temp file
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let path = &path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let filename = path.file_name().unwrap_or_default().to_string_lossy();
+    let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let tmp = path.with_file_name(format!(".{filename}.rtx-tmp-{}-{n}", std::process::id()));
+    trace!("write {}", path.display());
+    let result = (|| -> Result<()> {
+        // created with the final mode so the contents are never readable by more users than
+        // the existing file allows
+        let permissions = path.metadata().map(|m| m.permissions()).ok();
+        let mode = permissions.as_ref().map_or(0o666, |p| p.mode());
+        let mut f = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .mode(mode)
+            .open(&tmp)?;
+        write(&mut f)?;
+        f.sync_all()?;
+        if let Some(permissions) = permissions {
+            // the umask may have dropped some of the bits
+            fs::set_permissions(&tmp, permissions)?;
+        }
+        fs::rename(&tmp, path)?;
+        Ok(())
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result
+}
+
 pub struct FindUp {
     current_dir: PathBuf,
     current_dir_filenames: Vec<String>,
@@ -281,6 +323,46 @@ mod tests {
         assert_eq!(display_path(&path), path.display().to_string());
     }
 
+    #[test]
+    fn test_write_atomic() {
+        let path = env::temp_dir().join("rtx-test-write-atomic");
+        fs::write(&path, "orig").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+
+        let err = write_atomic_with(&path, |f| {
+            f.write_all(b"part")?;
+            Err(io::Error::new(io::ErrorKind::Other, "interrupted"))
+        });
+        assert_eq!(err.unwrap_err().to_string(), "interrupted");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "orig");
+        let tmp_files = dir_files(&env::temp_dir()).unwrap();
+        assert!(!tmp_files
+            .iter()
+            .any(|f| f.starts_with(".rtx-test-write-atomic")));
+
+        write_atomic(&path, "new").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        let mode = path.metadata().unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_write_atomic_symlink() {
+        let dir = dirs::HOME.join("rtx-test-write-atomic-symlink");
+        let target = dir.join("config.toml");
+        let link = dir.join("link.toml");
+        create_dir_all(&dir).unwrap();
+        fs::write(&target, "orig").unwrap();
+        let _ = fs::remove_file(&link);
+        make_symlink(&target, &link).unwrap();
+
+        write_atomic(&link, "new").unwrap();
+        assert!(link.symlink_metadata().unwrap().file_type().is_symlink());
+        assert_eq!(fs::read_to_string(&target).unwrap(), "new");
+        remove_all(&dir).unwrap();
+    }
+
     #[test]
     fn test_replace_path() {
         assert_eq!(replace_path(Path::new("~/cwd")), dirs::HOME.join("cwd"));
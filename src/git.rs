@@ -1,10 +1,12 @@
+use std::fs;
 use std::fs::create_dir_all;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use color_eyre::eyre::{eyre, Result};
 
 use crate::cmd;
-use crate::file::touch_dir;
+use crate::config::ensure_online;
+use crate::file::{display_path, touch_dir};
 
 pub struct Git {
     pub dir: PathBuf,
@@ -20,6 +22,7 @@ impl Git {
     }
 
     pub fn update(&self, gitref: Option<String>) -> Result<(String, String)> {
+        ensure_online(&format!("update {}", self.dir.display()))?;
         let gitref = gitref.map_or_else(|| self.current_branch(), Ok)?;
         debug!("updating {} to {}", self.dir.display(), gitref);
         self.run_git_command(&[
@@ -46,6 +49,7 @@ impl Git {
     }
 
     pub fn clone(&self, url: &str) -> Result<()> {
+        ensure_online(&format!("clone {url}"))?;
         debug!("cloning {} to {}", url, self.dir.display());
         if let Some(parent) = self.dir.parent() {
             create_dir_all(parent)?;
@@ -72,6 +76,24 @@ impl Git {
         Ok(sha)
     }
 
+    /// the commit HEAD points to, read from the files in .git instead of running git so it's cheap
+    /// enough to check on every prompt. `None` if it can't be found that way.
+    pub fn head_sha(&self) -> Option<String> {
+        let git_dir = self.dir.join(".git");
+        let head = fs::read_to_string(git_dir.join("HEAD")).ok()?;
+        let Some(ref_name) = head.trim().strip_prefix("ref: ") else {
+            return Some(head.trim().to_string());
+        };
+        if let Ok(sha) = fs::read_to_string(git_dir.join(ref_name)) {
+            return Some(sha.trim().to_string());
+        }
+        let packed_refs = fs::read_to_string(git_dir.join("packed-refs")).ok()?;
+        let suffix = format!(" {ref_name}");
+        packed_refs
+            .lines()
+            .find_map(|line| line.strip_suffix(&suffix).map(|sha| sha.to_string()))
+    }
+
     pub fn current_sha_short(&self) -> Result<String> {
         let sha = cmd!("git", "-C", &self.dir, "rev-parse", "--short", "HEAD").read()?;
         debug!("current sha for {}: {}", self.dir.display(), &sha);
@@ -84,6 +106,44 @@ impl Git {
         Ok(aref)
     }
 
+    /// true if `dir` is anywhere in a git work tree, unlike `is_repo()` which checks for the root
+    pub fn is_inside_work_tree(&self) -> bool {
+        cmd!("git", "-C", &self.dir, "rev-parse", "--is-inside-work-tree")
+            .stderr_null()
+            .read()
+            .map_or(false, |out| out == "true")
+    }
+
+    /// the content of `file` at `gitref`, `None` if it didn't exist at that ref
+    pub fn show_file(&self, gitref: &str, file: &Path) -> Result<Option<String>> {
+        let file = file
+            .strip_prefix(&self.dir)
+            .unwrap_or(file)
+            .to_string_lossy();
+        let tree = cmd!(
+            "git",
+            "-C",
+            &self.dir,
+            "ls-tree",
+            "--name-only",
+            gitref,
+            "--",
+            &*file
+        )
+        .stderr_null()
+        .read()
+        .map_err(|_| eyre!("failed to read {gitref} in {}", display_path(&self.dir)))?;
+        if tree.is_empty() {
+            return Ok(None);
+        }
+        // not `read()` since that trims the trailing newline
+        let spec = format!("{gitref}:./{file}");
+        let out = cmd!("git", "-C", &self.dir, "show", spec)
+            .stdout_capture()
+            .run()?;
+        Ok(Some(String::from_utf8(out.stdout)?))
+    }
+
     pub fn get_remote_url(&self) -> Option<String> {
         let res = cmd!(
             "git",
@@ -170,3 +230,17 @@ fn get_git_version() -> Result<String> {
 //         assert_eq!(update_result, (prev_rev, latest));
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_head_sha() {
+        let git = Git::new(PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+        assert_str_eq!(git.head_sha().unwrap(), git.current_sha().unwrap());
+        assert!(Git::new(std::env::temp_dir()).head_sha().is_none());
+    }
+}
@@ -0,0 +1,66 @@
+use std::path::Path;
+
+use color_eyre::eyre::Result;
+
+use crate::cmd;
+use crate::errors::InstallError;
+use crate::file::{self, display_path};
+
+/// checks the detached signature `sig` of `file` with gpg, returns false if it couldn't be
+/// checked because gpg isn't installed or doesn't have the signing key
+pub fn verify_signature(sig: &Path, file: &Path) -> Result<bool> {
+    if file::which("gpg").is_none() {
+        debug!("gpg is not installed, not verifying {}", display_path(sig));
+        return Ok(false);
+    }
+    let output = cmd!("gpg", "--batch", "--status-fd", "1", "--verify", sig, file)
+        .stdout_capture()
+        .stderr_null()
+        .unchecked()
+        .run()?;
+    let status = String::from_utf8_lossy(&output.stdout);
+    let has = |keyword: &str| {
+        status.lines().any(|l| {
+            l.strip_prefix("[GNUPG:] ")
+                .map_or(false, |l| l.starts_with(keyword))
+        })
+    };
+    if has("BADSIG") {
+        Err(InstallError::SignatureInvalid(display_path(file)).into())
+    } else if has("GOODSIG") && output.status.success() {
+        Ok(true)
+    } else if has("NO_PUBKEY") || has("ERRSIG") {
+        debug!("no key to verify {}", display_path(sig));
+        Ok(false)
+    } else {
+        Err(InstallError::SignatureInvalid(display_path(file)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::env;
+
+    use super::*;
+
+    #[test]
+    fn test_verify_signature_invalid() {
+        if file::which("gpg").is_none() {
+            return;
+        }
+        let dir = env::temp_dir().join("rtx-test-gpg");
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("SHASUMS256.txt");
+        let sig = dir.join("SHASUMS256.txt.asc");
+        fs::write(&file, "abc  bun-linux-x64.zip\n").unwrap();
+        fs::write(&sig, "not a signature\n").unwrap();
+        let err = verify_signature(&sig, &file).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<InstallError>(),
+            Some(InstallError::SignatureInvalid(_))
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
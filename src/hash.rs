@@ -3,9 +3,13 @@ use std::fs::File;
 use std::hash::{Hash, Hasher};
 use std::path::Path;
 
-use color_eyre::eyre::{bail, Result};
+use color_eyre::eyre::Result;
 use sha2::{Digest, Sha256};
 
+use crate::config::Settings;
+use crate::errors::InstallError;
+use crate::file::display_path;
+
 pub fn hash_to_str<T: Hash>(t: &T) -> String {
     let mut s = DefaultHasher::new();
     t.hash(&mut s);
@@ -13,6 +17,25 @@ pub fn hash_to_str<T: Hash>(t: &T) -> String {
     format!("{bytes:x}")
 }
 
+/// unlike `hash_to_str` this is the same across rtx versions so it can be stored
+pub fn hash_sha256_to_str(s: &str) -> String {
+    format!("{:x}", Sha256::digest(s))
+}
+
+/// hashes the parts in order, separating them so `["ab", "c"]` and `["a", "bc"]` differ
+pub fn hash_parts_sha256<I, S>(parts: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part.as_ref().as_bytes());
+        hasher.update([0]);
+    }
+    format!("{:x}", hasher.finalize())
+}
+
 pub fn file_hash_sha256(path: &Path) -> Result<String> {
     let mut file = File::open(path)?;
     let mut hasher = Sha256::new();
@@ -23,17 +46,47 @@ pub fn file_hash_sha256(path: &Path) -> Result<String> {
 
 pub fn ensure_checksum_sha256(path: &Path, checksum: &str) -> Result<()> {
     let actual = file_hash_sha256(path)?;
-    if actual != checksum {
-        bail!(
-            "Checksum mismatch for file {:?}:\nExpected: {}\nActual: {}",
-            path,
-            checksum,
-            actual
-        );
+    let expected = checksum.trim();
+    if actual != expected {
+        return Err(InstallError::ChecksumMismatch {
+            path: display_path(path),
+            expected: expected.to_string(),
+            actual,
+        }
+        .into());
     }
     Ok(())
 }
 
+/// checks a download against its checksum, without one it's only installed if the
+/// require_checksums setting is off
+pub fn verify_checksum_sha256(
+    settings: &Settings,
+    path: &Path,
+    checksum: Option<&str>,
+) -> Result<()> {
+    match checksum {
+        Some(checksum) => ensure_checksum_sha256(path, checksum),
+        None if settings.require_checksums => {
+            Err(InstallError::ChecksumMissing(display_path(path)).into())
+        }
+        None => {
+            debug!("no checksum to verify {}", display_path(path));
+            Ok(())
+        }
+    }
+}
+
+/// the checksum for `filename` in a SHASUMS256.txt file, lines are `<checksum>  <filename>`
+/// with a `*` before the filename for ones hashed in binary mode
+pub fn find_checksum_in_shasums(shasums: &str, filename: &str) -> Option<String> {
+    shasums.lines().find_map(|line| {
+        let (checksum, name) = line.split_once(char::is_whitespace)?;
+        let name = name.trim_start().trim_start_matches('*');
+        (name == filename).then(|| checksum.to_string())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use insta::assert_snapshot;
@@ -51,4 +104,32 @@ mod tests {
         let hash = file_hash_sha256(path).unwrap();
         assert_snapshot!(hash);
     }
+
+    #[test]
+    fn test_verify_checksum_sha256() {
+        let path = Path::new(".test-tool-versions");
+        let hash = file_hash_sha256(path).unwrap();
+        let mut settings = Settings::default();
+        verify_checksum_sha256(&settings, path, Some(&format!("{hash}\n"))).unwrap();
+        verify_checksum_sha256(&settings, path, None).unwrap();
+
+        let err = verify_checksum_sha256(&settings, path, Some("abc")).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<InstallError>(),
+            Some(InstallError::ChecksumMismatch { expected, .. }) if expected == "abc"
+        ));
+
+        settings.require_checksums = true;
+        let err = verify_checksum_sha256(&settings, path, None).unwrap_err();
+        assert_snapshot!(err.to_string(), @"no checksum available for .test-tool-versions, one is required by the require_checksums setting");
+    }
+
+    #[test]
+    fn test_find_checksum_in_shasums() {
+        let shasums = "abc  bun-linux-x64.zip\ndef *bun-darwin-aarch64.zip\n";
+        let find = |f| find_checksum_in_shasums(shasums, f);
+        assert_eq!(find("bun-linux-x64.zip"), Some("abc".into()));
+        assert_eq!(find("bun-darwin-aarch64.zip"), Some("def".into()));
+        assert_eq!(find("bun-linux-x64"), None);
+    }
 }
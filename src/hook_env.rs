@@ -1,4 +1,6 @@
 use std::collections::{BTreeMap, BTreeSet};
+use std::env::{join_paths, split_paths};
+use std::fmt::{Display, Formatter};
 use std::io::prelude::*;
 use std::ops::Deref;
 use std::path::PathBuf;
@@ -12,17 +14,25 @@ use flate2::Compression;
 use itertools::Itertools;
 use serde_derive::{Deserialize, Serialize};
 
-use crate::env_diff::{EnvDiffOperation, EnvDiffPatches};
+use crate::config::Config;
+use crate::config::MissingRuntimeBehavior::{Prompt, Warn};
+use crate::direnv::DirenvDiff;
+use crate::env::__RTX_DIFF;
+use crate::env_diff::{EnvDiff, EnvDiffOperation, EnvDiffPatches};
 use crate::hash::hash_to_str;
 use crate::shell::Shell;
+use crate::toolset::{Toolset, ToolsetBuilder};
 use crate::{dirs, env};
 
 /// this function will early-exit the application if hook-env is being
 /// called and it does not need to be
-pub fn should_exit_early(watch_files: &[PathBuf]) -> bool {
+pub fn should_exit_early(watch_files: &[PathBuf], env_fingerprint: &str) -> bool {
     if env::ARGS.len() < 2 || env::ARGS[1] != "hook-env" {
         return false;
     }
+    if env::ARGS.iter().any(|a| a == "--dry-run") {
+        return false;
+    }
     let watch_files = get_watch_files(watch_files);
     match env::var("__RTX_WATCH") {
         Ok(raw) => {
@@ -34,6 +44,10 @@ pub fn should_exit_early(watch_files: &[PathBuf]) -> bool {
                     if have_rtx_env_vars_been_modified(&watches) {
                         return false;
                     }
+                    if watches.env_fingerprint != env_fingerprint {
+                        trace!("RTX_*_VERSION env vars modified");
+                        return false;
+                    }
                 }
                 Err(e) => {
                     debug!("error deserializing watches: {:?}", e);
@@ -92,6 +106,8 @@ fn have_rtx_env_vars_been_modified(watches: &HookEnvWatches) -> bool {
 pub struct HookEnvWatches {
     files: BTreeMap<PathBuf, SystemTime>,
     env_var_hash: String,
+    #[serde(default)]
+    env_fingerprint: String,
 }
 
 pub fn serialize_watches(watches: &HookEnvWatches) -> Result<String> {
@@ -109,7 +125,7 @@ pub fn deserialize_watches(raw: String) -> Result<HookEnvWatches> {
     Ok(rmp_serde::from_slice(&writer[..])?)
 }
 
-pub fn build_watches(watch_files: &[PathBuf]) -> Result<HookEnvWatches> {
+pub fn build_watches(watch_files: &[PathBuf], env_fingerprint: &str) -> Result<HookEnvWatches> {
     let mut watches = BTreeMap::new();
     for cf in get_watch_files(watch_files) {
         watches.insert(cf.clone(), cf.metadata()?.modified()?);
@@ -118,6 +134,7 @@ pub fn build_watches(watch_files: &[PathBuf]) -> Result<HookEnvWatches> {
     Ok(HookEnvWatches {
         files: watches,
         env_var_hash: get_rtx_env_vars_hashed(),
+        env_fingerprint: env_fingerprint.to_string(),
     })
 }
 
@@ -144,12 +161,192 @@ fn get_rtx_env_vars_hashed() -> String {
     hash_to_str(&env_vars)
 }
 
+/// the commands that restore the env from before rtx was activated, used by `rtx deactivate` so
+/// __RTX_DIFF and __RTX_WATCH are unset as well
 pub fn clear_old_env(shell: &dyn Shell) -> String {
+    let mut patches = clear_old_env_patches();
+    patches.push(EnvDiffOperation::Remove("__RTX_DIFF".into()));
+    patches.push(EnvDiffOperation::Remove("__RTX_WATCH".into()));
+    build_env_commands(shell, &patches)
+}
+
+fn clear_old_env_patches() -> EnvDiffPatches {
     let mut patches = env::__RTX_DIFF.reverse().to_patches();
     if let Some(path) = env::PRISTINE_ENV.deref().get("PATH") {
         patches.push(EnvDiffOperation::Change("PATH".into(), path.to_string()));
     }
-    build_env_commands(shell, &patches)
+    patches
+}
+
+/// the toolset hook-env sets the env up for, missing versions are installed but never prompted
+/// for since the output is eval'd by the shell
+pub fn load_toolset(config: &mut Config) -> Result<Toolset> {
+    if config.settings.missing_runtime_behavior == Prompt {
+        config.settings.missing_runtime_behavior = Warn;
+    }
+    ToolsetBuilder::new().with_install_missing().build(config)
+}
+
+/// the commands that undo the env from the last run and set the env for `ts`, along with
+/// __RTX_DIFF so the next run can undo it and __RTX_WATCH so it can tell if it needs to run
+pub fn build_env_script(config: &Config, ts: &Toolset, shell: &dyn Shell) -> Result<String> {
+    Ok(build_env_commands(shell, &build_env_patches(config, ts)?))
+}
+
+/// the operations behind `build_env_script()`, in the order the shell runs them
+pub fn build_env_patches(config: &Config, ts: &Toolset) -> Result<EnvDiffPatches> {
+    let mut output = clear_old_env_patches();
+    let env = ts.env(config)?;
+    let mut diff = EnvDiff::new(&env::PRISTINE_ENV, env);
+    for k in &config.env_remove {
+        // record inherited vars unset by config so they are restored on leaving the directory
+        if let Some(v) = env::PRISTINE_ENV.get(k) {
+            diff.old.insert(k.clone(), v.clone());
+        }
+    }
+    let mut patches = diff.to_patches();
+
+    let mut paths = config.path_dirs.clone();
+    paths.extend(ts.list_paths(config)); // load the active runtime paths
+    diff.path = paths.clone(); // update __RTX_DIFF with the new paths for the next run
+
+    patches.extend(build_path_operations(&paths, &__RTX_DIFF.path)?);
+    patches.push(EnvDiffOperation::Add(
+        "__RTX_DIFF".into(),
+        diff.serialize()?,
+    ));
+    patches.push(build_watch_operation(config)?);
+
+    output.extend(patches);
+    Ok(output)
+}
+
+/// a change to an env var from running the hook-env script, see `env_changes()`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvChange {
+    Add(String, String),
+    /// the key, the old value, and the new value
+    Change(String, String, String),
+    /// the key and the old value
+    Remove(String, String),
+}
+
+impl Display for EnvChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EnvChange::Add(k, v) => write!(f, "+ {k}={v}"),
+            // PATH is too long to compare by eye, so the entries that changed are shown instead
+            EnvChange::Change(k, old, new) if k == "PATH" => {
+                let old = split_paths(old).collect_vec();
+                let new = split_paths(new).collect_vec();
+                write!(f, "~ {k}")?;
+                for p in new.iter().filter(|p| !old.contains(p)) {
+                    write!(f, "\n    + {}", p.display())?;
+                }
+                for p in old.iter().filter(|p| !new.contains(p)) {
+                    write!(f, "\n    - {}", p.display())?;
+                }
+                Ok(())
+            }
+            EnvChange::Change(k, old, new) => write!(f, "~ {k}: {old} -> {new}"),
+            EnvChange::Remove(k, old) => write!(f, "- {k} (was: {old})"),
+        }
+    }
+}
+
+/// what running the commands for `patches` would do to `env`, used by `rtx hook-env --dry-run`
+/// to show what the hook does without emitting shell code. The vars rtx uses to track its own
+/// state are left out.
+pub fn env_changes(env: &BTreeMap<String, String>, patches: &EnvDiffPatches) -> Vec<EnvChange> {
+    let mut new_env = env.clone();
+    for patch in patches {
+        match patch {
+            EnvDiffOperation::Add(k, v) | EnvDiffOperation::Change(k, v) => {
+                new_env.insert(k.clone(), v.clone());
+            }
+            EnvDiffOperation::Remove(k) => {
+                new_env.remove(k);
+            }
+        }
+    }
+    diff_envs(env, &new_env)
+}
+
+/// the vars that were added, changed, or removed from `old` to `new`, except the ones rtx uses to
+/// track its own state
+pub fn diff_envs(old: &BTreeMap<String, String>, new: &BTreeMap<String, String>) -> Vec<EnvChange> {
+    let keys = old.keys().chain(new.keys()).unique().sorted();
+    keys.filter(|k| !matches!(k.as_str(), "__RTX_DIFF" | "__RTX_WATCH"))
+        .filter_map(|k| match (old.get(k), new.get(k)) {
+            (None, Some(new)) => Some(EnvChange::Add(k.clone(), new.clone())),
+            (Some(old), None) => Some(EnvChange::Remove(k.clone(), old.clone())),
+            (Some(old), Some(new)) if old != new => {
+                Some(EnvChange::Change(k.clone(), old.clone(), new.clone()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// modifies the PATH and optionally DIRENV_DIFF env var if it exists
+fn build_path_operations(
+    installs: &Vec<PathBuf>,
+    to_remove: &Vec<PathBuf>,
+) -> Result<Vec<EnvDiffOperation>> {
+    let new_path = join_paths([installs.clone(), env::PATH.clone()].concat())?
+        .to_string_lossy()
+        .to_string();
+    let mut ops = vec![EnvDiffOperation::Add("PATH".into(), new_path)];
+
+    if let Some(input) = env::DIRENV_DIFF.deref() {
+        match update_direnv_diff(input, installs, to_remove) {
+            Ok(Some(op)) => {
+                ops.push(op);
+            }
+            Err(err) => warn!("failed to update DIRENV_DIFF: {:#}", err),
+            _ => {}
+        }
+    }
+
+    Ok(ops)
+}
+
+/// inserts install path to DIRENV_DIFF both for old and new
+/// this makes direnv think that these paths were added before it ran
+/// that way direnv will not remove the path when it runs the next time
+fn update_direnv_diff(
+    input: &str,
+    installs: &Vec<PathBuf>,
+    to_remove: &Vec<PathBuf>,
+) -> Result<Option<EnvDiffOperation>> {
+    let mut diff = DirenvDiff::parse(input)?;
+    if diff.new_path().is_empty() {
+        return Ok(None);
+    }
+    for path in to_remove {
+        diff.remove_path_from_old_and_new(path)?;
+    }
+    for install in installs {
+        diff.add_path_to_old_and_new(install)?;
+    }
+
+    Ok(Some(EnvDiffOperation::Change(
+        "DIRENV_DIFF".into(),
+        diff.dump()?,
+    )))
+}
+
+fn build_watch_operation(config: &Config) -> Result<EnvDiffOperation> {
+    let watch_files: Vec<_> = config
+        .config_files
+        .values()
+        .flat_map(|p| p.watch_files())
+        .collect();
+    let watches = build_watches(&watch_files, config.env_fingerprint())?;
+    Ok(EnvDiffOperation::Add(
+        "__RTX_WATCH".into(),
+        serialize_watches(&watches)?,
+    ))
 }
 
 pub fn build_env_commands(shell: &dyn Shell, patches: &EnvDiffPatches) -> String {
@@ -185,6 +382,7 @@ mod tests {
         let watches = HookEnvWatches {
             files: BTreeMap::new(),
             env_var_hash: "".into(),
+            env_fingerprint: "".into(),
         };
         assert!(!have_config_files_been_modified(&watches, files));
 
@@ -192,6 +390,7 @@ mod tests {
         let watches = HookEnvWatches {
             files: BTreeMap::from([(fp.clone(), UNIX_EPOCH)]),
             env_var_hash: "".into(),
+            env_fingerprint: "".into(),
         };
         let files = BTreeSet::from([fp.clone()]);
         assert!(have_config_files_been_modified(&watches, files));
@@ -200,16 +399,41 @@ mod tests {
         let watches = HookEnvWatches {
             files: BTreeMap::from([(fp.clone(), modtime)]),
             env_var_hash: "".into(),
+            env_fingerprint: "".into(),
         };
         let files = BTreeSet::from([fp]);
         assert!(!have_config_files_been_modified(&watches, files));
     }
 
+    #[test]
+    fn test_env_changes() {
+        let env = BTreeMap::from([
+            ("FOO".to_string(), "1".to_string()),
+            ("BAR".to_string(), "1".to_string()),
+            ("KEEP".to_string(), "1".to_string()),
+            ("PATH".to_string(), "/a:/b".to_string()),
+        ]);
+        let patches = vec![
+            EnvDiffOperation::Change("FOO".into(), "2".into()),
+            EnvDiffOperation::Remove("BAR".into()),
+            EnvDiffOperation::Add("BAZ".into(), "3".into()),
+            EnvDiffOperation::Change("KEEP".into(), "1".into()),
+            EnvDiffOperation::Add("PATH".into(), "/c:/a".into()),
+            EnvDiffOperation::Add("__RTX_DIFF".into(), "abc".into()),
+        ];
+        let changes = env_changes(&env, &patches).into_iter().join("\n");
+        assert_str_eq!(
+            changes,
+            "- BAR (was: 1)\n+ BAZ=3\n~ FOO: 1 -> 2\n~ PATH\n    + /c\n    - /b"
+        );
+    }
+
     #[test]
     fn test_serialize_watches_empty() {
         let watches = HookEnvWatches {
             files: BTreeMap::new(),
             env_var_hash: "".into(),
+            env_fingerprint: "".into(),
         };
         let serialized = serialize_watches(&watches).unwrap();
         let deserialized = deserialize_watches(serialized).unwrap();
@@ -221,6 +445,7 @@ mod tests {
         let serialized = serialize_watches(&HookEnvWatches {
             files: BTreeMap::from([("foo".into(), UNIX_EPOCH)]),
             env_var_hash: "testing-123".into(),
+            env_fingerprint: "".into(),
         })
         .unwrap();
         let deserialized = deserialize_watches(serialized).unwrap();
@@ -1,8 +1,13 @@
 use color_eyre::eyre::{eyre, Result};
 use reqwest::blocking::{RequestBuilder, Response};
-use reqwest::IntoUrl;
-use std::fs::File;
-use std::path::Path;
+use reqwest::header::{CONTENT_RANGE, RANGE};
+use reqwest::{IntoUrl, StatusCode};
+use std::fs::{self, File, OpenOptions};
+use std::path::{Path, PathBuf};
+
+use crate::config::ensure_online;
+use crate::hash::hash_to_str;
+use crate::{dirs, file};
 
 pub struct Client {
     reqwest: reqwest::blocking::Client,
@@ -21,6 +26,8 @@ impl Client {
     }
 
     pub fn get_text<U: IntoUrl>(&self, url: U) -> Result<String> {
+        let url = url.into_url()?;
+        ensure_online(&format!("fetch {url}"))?;
         let resp = self.get(url).send()?;
         self.ensure_success(&resp)?;
         let text = resp.text()?;
@@ -29,6 +36,7 @@ impl Client {
 
     pub fn download_file<U: IntoUrl>(&self, url: U, path: &Path) -> Result<()> {
         let url = url.into_url()?;
+        ensure_online(&format!("download {url}"))?;
         debug!("Downloading {} to {}", &url, path.display());
         let mut resp = self.get(url).send()?;
         self.ensure_success(&resp)?;
@@ -37,6 +45,56 @@ impl Client {
         Ok(())
     }
 
+    /// like `download_file` but for large tool downloads: the file is downloaded to the cache first
+    /// and left there if it fails partway, so the next install of the same url and version
+    /// continues it with a range request instead of starting over. The caller still has to verify
+    /// the checksum since the partial file could be from a release that was replaced.
+    pub fn download_file_resumable<U: IntoUrl>(
+        &self,
+        url: U,
+        path: &Path,
+        version: &str,
+    ) -> Result<()> {
+        let url = url.into_url()?;
+        ensure_online(&format!("download {url}"))?;
+        let partial = partial_download_path(url.as_str(), version);
+        file::create_dir_all(partial.parent().unwrap())?;
+        let offset = fs::metadata(&partial).map(|m| m.len()).unwrap_or_default();
+        let mut req = self.get(url.clone());
+        if offset > 0 {
+            debug!("Resuming download of {} at {} bytes", &url, offset);
+            req = req.header(RANGE, format!("bytes={offset}-"));
+        }
+        let mut resp = req.send()?;
+        let resumed = resp.status() == StatusCode::PARTIAL_CONTENT
+            && resp
+                .headers()
+                .get(CONTENT_RANGE)
+                .and_then(|v| v.to_str().ok())
+                .map_or(false, |v| v.starts_with(&format!("bytes {offset}-")));
+        if offset > 0 && !resumed && resp.status() != StatusCode::OK {
+            // e.g.: the partial file is bigger than the file on the server
+            debug!("Restarting download of {}: {}", &url, resp.status());
+            fs::remove_file(&partial)?;
+            resp = self.get(url).send()?;
+        }
+        self.ensure_success(&resp)?;
+        debug!("Downloading {} to {}", resp.url(), path.display());
+        let mut file = match resumed {
+            true => OpenOptions::new().append(true).open(&partial)?,
+            // the server sent the whole file
+            false => File::create(&partial)?,
+        };
+        resp.copy_to(&mut file)?;
+        drop(file);
+        if fs::rename(&partial, path).is_err() {
+            // the cache may be on a different filesystem than the download dir
+            fs::copy(&partial, path)?;
+            fs::remove_file(&partial)?;
+        }
+        Ok(())
+    }
+
     pub fn ensure_success(&self, resp: &Response) -> Result<()> {
         if resp.status().is_success() {
             Ok(())
@@ -45,3 +103,83 @@ impl Client {
         }
     }
 }
+
+/// where an unfinished download of `url` is kept between attempts
+fn partial_download_path(url: &str, version: &str) -> PathBuf {
+    let key = hash_to_str(&(url, version));
+    dirs::CACHE.join("downloads").join(format!("{key}.part"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::env;
+
+    use super::*;
+
+    /// serves `body` once, only the part after the offset if the request has a range
+    fn serve_once(body: &'static str) -> (String, thread::JoinHandle<Option<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/tool.tar.gz", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut range = None;
+            for line in BufReader::new(&stream).lines() {
+                let line = line.unwrap();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some(r) = line.to_lowercase().strip_prefix("range: bytes=") {
+                    range = Some(r.trim_end_matches('-').to_string());
+                }
+            }
+            let response = match &range {
+                Some(offset) => {
+                    let offset: usize = offset.parse().unwrap();
+                    let part = &body[offset..];
+                    format!(
+                        "HTTP/1.1 206 Partial Content\r\nContent-Range: bytes {offset}-{}/{}\r\nContent-Length: {}\r\n\r\n{part}",
+                        body.len() - 1,
+                        body.len(),
+                        part.len()
+                    )
+                }
+                None => format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{body}",
+                    body.len()
+                ),
+            };
+            stream.write_all(response.as_bytes()).unwrap();
+            range
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn test_download_file_resumable() {
+        let (url, server) = serve_once("hello world");
+        let path = env::temp_dir().join("rtx-test-download-resumable");
+        let partial = partial_download_path(&url, "1.0.0");
+        file::create_dir_all(partial.parent().unwrap()).unwrap();
+        fs::write(&partial, "hello").unwrap();
+
+        let client = Client::new().unwrap();
+        client
+            .download_file_resumable(&url, &path, "1.0.0")
+            .unwrap();
+        assert_eq!(server.join().unwrap(), Some("5".to_string()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "hello world");
+        assert!(!partial.exists());
+
+        let (url, server) = serve_once("hello world");
+        client.download_file_resumable(url, &path, "1.0.0").unwrap();
+        assert_eq!(server.join().unwrap(), None);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "hello world");
+        fs::remove_file(&path).unwrap();
+    }
+}
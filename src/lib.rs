@@ -26,10 +26,12 @@ mod fake_asdf;
 mod file;
 mod git;
 pub mod github;
+mod gpg;
 mod hash;
 mod hook_env;
 mod http;
 mod lock_file;
+pub mod logger;
 mod plugins;
 mod runtime_symlinks;
 mod shell;
@@ -5,11 +5,35 @@ use std::fs::{create_dir_all, File, OpenOptions};
 use std::path::PathBuf;
 
 use color_eyre::eyre::Result;
+use log::{Log, Metadata, Record};
 use simplelog::*;
 
+/// receives rtx's log messages instead of them being written to stderr, e.g.: so an app
+/// embedding rtx can display errors itself
+pub trait LogCollector: Send + Sync {
+    fn collect(&self, level: Level, message: String);
+}
+
 pub fn init(log_level: LevelFilter, log_file_level: LevelFilter) {
-    let mut loggers: Vec<Box<dyn SharedLogger>> = vec![];
-    loggers.push(init_term_logger(log_level));
+    init_loggers(init_term_logger(log_level), log_file_level);
+}
+
+/// like `init` but sends log messages to `collector` instead of stderr
+#[allow(dead_code)] // only used by library consumers
+pub fn init_with_collector(
+    log_level: LevelFilter,
+    log_file_level: LevelFilter,
+    collector: Box<dyn LogCollector>,
+) {
+    let logger = CollectorLogger {
+        level: log_level,
+        collector,
+    };
+    init_loggers(Box::new(logger), log_file_level);
+}
+
+fn init_loggers(logger: Box<dyn SharedLogger>, log_file_level: LevelFilter) {
+    let mut loggers: Vec<Box<dyn SharedLogger>> = vec![logger];
 
     if let Ok(log) = env::var("RTX_LOG_FILE") {
         let log_file = PathBuf::from(log);
@@ -22,6 +46,40 @@ pub fn init(log_level: LevelFilter, log_file_level: LevelFilter) {
     });
 }
 
+struct CollectorLogger {
+    level: LevelFilter,
+    collector: Box<dyn LogCollector>,
+}
+
+impl Log for CollectorLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            self.collector
+                .collect(record.level(), record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+impl SharedLogger for CollectorLogger {
+    fn level(&self) -> LevelFilter {
+        self.level
+    }
+
+    fn config(&self) -> Option<&Config> {
+        None
+    }
+
+    fn as_log(self: Box<Self>) -> Box<dyn Log> {
+        Box::new(*self)
+    }
+}
+
 fn init_log_file(log_file: PathBuf) -> Result<File> {
     if let Some(log_dir) = log_file.parent() {
         create_dir_all(log_dir)?;
@@ -70,10 +128,40 @@ fn init_write_logger(level: LevelFilter, log_path: PathBuf) -> Option<Box<dyn Sh
 
 #[cfg(test)]
 mod tests {
+    use std::sync::{Arc, Mutex};
+
     use super::*;
 
     #[test]
     fn test_init() {
         init(LevelFilter::Debug, LevelFilter::Debug);
     }
+
+    #[test]
+    fn test_collector_logger() {
+        #[derive(Default, Clone)]
+        struct Collector(Arc<Mutex<Vec<(Level, String)>>>);
+        impl LogCollector for Collector {
+            fn collect(&self, level: Level, message: String) {
+                self.0.lock().unwrap().push((level, message));
+            }
+        }
+        let collector = Collector::default();
+        let logger = CollectorLogger {
+            level: LevelFilter::Warn,
+            collector: Box::new(collector.clone()),
+        };
+        for (level, msg) in [(Level::Warn, "careful"), (Level::Debug, "hidden")] {
+            logger.log(
+                &Record::builder()
+                    .level(level)
+                    .args(format_args!("{msg}"))
+                    .build(),
+            );
+        }
+        assert_eq!(
+            *collector.0.lock().unwrap(),
+            vec![(Level::Warn, "careful".to_string())]
+        );
+    }
 }
@@ -35,6 +35,7 @@ mod fake_asdf;
 mod file;
 mod git;
 pub mod github;
+mod gpg;
 mod hash;
 mod hook_env;
 mod http;
@@ -80,13 +81,28 @@ fn run(args: &Vec<String>) -> Result<()> {
         warn!("Error migrating: {}", err);
     }
 
-    let config = Config::load()?;
+    let config = load_config(args)?;
+    let warnings = config.warnings.clone();
     let config = shims::handle_shim(config, args, out)?;
     if config.should_exit_early {
+        warnings.render();
         return Ok(());
     }
     let cli = Cli::new_with_external_commands(&config);
-    cli.run(config, args, out)
+    let result = cli.run(config, args, out);
+    warnings.render();
+    result
+}
+
+/// shims and `rtx exec` only need the plugins of the tools they run, so they don't load the others
+/// unless they turn out to be needed
+fn load_config(args: &[String]) -> Result<Config> {
+    let (_, bin_name) = args[0].rsplit_once('/').unwrap_or(("", &args[0]));
+    let is_exec = matches!(args.get(1).map(String::as_str), Some("exec" | "x"));
+    match bin_name != "rtx" || is_exec {
+        true => Config::load_lazy(),
+        false => Config::load(),
+    }
 }
 
 fn handle_ctrlc() {
@@ -8,12 +8,14 @@ use versions::Versioning;
 use crate::cli::version::{ARCH, OS};
 use crate::cmd::CmdLineRunner;
 use crate::config::{Config, Settings};
+use crate::errors::InstallError;
+use crate::file::display_path;
 use crate::github::GithubRelease;
 use crate::plugins::core::CorePlugin;
 use crate::plugins::{Plugin, PluginName};
 use crate::toolset::{ToolVersion, ToolVersionRequest};
 use crate::ui::progress_report::ProgressReport;
-use crate::{env, file, http};
+use crate::{env, file, gpg, hash, http};
 
 #[derive(Debug)]
 pub struct BunPlugin {
@@ -57,23 +59,62 @@ impl BunPlugin {
             .execute()
     }
 
-    fn download(&self, tv: &ToolVersion, pr: &ProgressReport) -> Result<PathBuf> {
+    fn download(
+        &self,
+        settings: &Settings,
+        tv: &ToolVersion,
+        pr: &ProgressReport,
+    ) -> Result<PathBuf> {
         let http = http::Client::new()?;
-        let url = format!(
-            "https://github.com/oven-sh/bun/releases/download/bun-v{}/bun-{}-{}.zip",
-            tv.version,
-            os(),
-            arch()
+        let release_url = format!(
+            "https://github.com/oven-sh/bun/releases/download/bun-v{}",
+            tv.version
         );
-        let filename = url.split('/').last().unwrap();
-        let tarball_path = tv.download_path().join(filename);
+        let filename = format!("bun-{}-{}.zip", os(), arch());
+        let url = format!("{release_url}/{filename}");
+        let tarball_path = tv.download_path().join(&filename);
 
         pr.set_message(format!("downloading {}", &url));
-        http.download_file(&url, &tarball_path)?;
+        http.download_file_resumable(&url, &tarball_path, &tv.version)?;
+
+        pr.set_message("verifying checksum");
+        let checksum = self.fetch_checksum(settings, &http, tv, &release_url, &filename)?;
+        hash::verify_checksum_sha256(settings, &tarball_path, checksum.as_deref())?;
 
         Ok(tarball_path)
     }
 
+    /// the checksum from the release's SHASUMS256.txt, which is checked against its signature
+    /// if gpg has bun's key. With require_checksums an unverified signature is an error.
+    fn fetch_checksum(
+        &self,
+        settings: &Settings,
+        http: &http::Client,
+        tv: &ToolVersion,
+        release_url: &str,
+        filename: &str,
+    ) -> Result<Option<String>> {
+        let shasums_path = tv.download_path().join("SHASUMS256.txt");
+        let shasums_url = format!("{release_url}/SHASUMS256.txt");
+        if let Err(err) = http.download_file(&shasums_url, &shasums_path) {
+            debug!("failed to download {shasums_url}: {err:#}");
+            return Ok(None);
+        }
+        let sig_path = tv.download_path().join("SHASUMS256.txt.asc");
+        let verified = match http.download_file(format!("{shasums_url}.asc"), &sig_path) {
+            Ok(()) => gpg::verify_signature(&sig_path, &shasums_path)?,
+            Err(err) => {
+                debug!("failed to download {shasums_url}.asc: {err:#}");
+                false
+            }
+        };
+        if !verified && settings.require_checksums {
+            return Err(InstallError::SignatureUnverified(display_path(&shasums_path)).into());
+        }
+        let shasums = fs::read_to_string(&shasums_path)?;
+        Ok(hash::find_checksum_in_shasums(&shasums, filename))
+    }
+
     fn install(&self, tv: &ToolVersion, pr: &ProgressReport, tarball_path: &Path) -> Result<()> {
         pr.set_message(format!("installing {}", tarball_path.display()));
         fs::remove_dir_all(tv.install_path())?;
@@ -110,6 +151,10 @@ impl Plugin for BunPlugin {
         Ok(vec![".bun-version".into()])
     }
 
+    fn verifies_checksums(&self) -> bool {
+        true
+    }
+
     fn install_version(
         &self,
         config: &Config,
@@ -118,7 +163,7 @@ impl Plugin for BunPlugin {
     ) -> Result<()> {
         assert!(matches!(&tv.request, ToolVersionRequest::Version { .. }));
 
-        let tarball_path = self.download(tv, pr)?;
+        let tarball_path = self.download(&config.settings, tv, pr)?;
         self.install(tv, pr, &tarball_path)?;
         self.verify(config, tv, pr)?;
 
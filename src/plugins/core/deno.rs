@@ -72,9 +72,7 @@ impl DenoPlugin {
         let tarball_path = tv.download_path().join(filename);
 
         pr.set_message(format!("downloading {}", &url));
-        http.download_file(&url, &tarball_path)?;
-
-        // TODO: hash::ensure_checksum_sha256(&tarball_path, &m.sha256)?;
+        http.download_file_resumable(&url, &tarball_path, &tv.version)?;
 
         Ok(tarball_path)
     }
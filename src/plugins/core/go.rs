@@ -95,27 +95,37 @@ impl GoPlugin {
             .execute()
     }
 
-    fn download(&self, tv: &ToolVersion, pr: &ProgressReport) -> Result<PathBuf> {
+    fn download(&self, config: &Config, tv: &ToolVersion, pr: &ProgressReport) -> Result<PathBuf> {
         let http = http::Client::new()?;
         let filename = format!("go{}.{}-{}.tar.gz", tv.version, platform(), arch());
-        let tarball_url = format!("{}/{}", &*env::RTX_GO_DOWNLOAD_MIRROR, &filename);
+        let mirror = config
+            .mirror_url(&self.core.name)
+            .unwrap_or_else(|| env::RTX_GO_DOWNLOAD_MIRROR.clone());
+        let tarball_url = format!("{}/{}", mirror.trim_end_matches('/'), &filename);
         let tarball_path = tv.download_path().join(filename);
 
         pr.set_message(format!("downloading {}", &tarball_url));
-        http.download_file(&tarball_url, &tarball_path)?;
+        http.download_file_resumable(&tarball_url, &tarball_path, &tv.version)?;
 
-        self.verify_tarball_checksum(&tarball_url, &tarball_path)?;
+        self.verify_tarball_checksum(&config.settings, &tarball_url, &tarball_path)?;
 
         Ok(tarball_path)
     }
 
-    fn verify_tarball_checksum(&self, tarball_url: &str, tarball_path: &Path) -> Result<()> {
-        if !*env::RTX_GO_SKIP_CHECKSUM {
-            let checksum_url = format!("{}.sha256", tarball_url);
-            let checksum = http::Client::new()?.get_text(checksum_url)?;
-            hash::ensure_checksum_sha256(tarball_path, &checksum)?;
-        }
-        Ok(())
+    fn verify_tarball_checksum(
+        &self,
+        settings: &Settings,
+        tarball_url: &str,
+        tarball_path: &Path,
+    ) -> Result<()> {
+        let checksum = match *env::RTX_GO_SKIP_CHECKSUM {
+            true => None,
+            false => {
+                let checksum_url = format!("{}.sha256", tarball_url);
+                Some(http::Client::new()?.get_text(checksum_url)?)
+            }
+        };
+        hash::verify_checksum_sha256(settings, tarball_path, checksum.as_deref())
     }
 
     fn install(&self, tv: &ToolVersion, pr: &ProgressReport, tarball_path: &Path) -> Result<()> {
@@ -146,7 +156,20 @@ impl Plugin for GoPlugin {
             .cloned()
     }
     fn legacy_filenames(&self, _settings: &Settings) -> Result<Vec<String>> {
-        Ok(vec![".go-version".into()])
+        Ok(vec![".go-version".into(), "go.mod".into()])
+    }
+
+    fn parse_legacy_file(&self, path: &Path, _settings: &Settings) -> Result<String> {
+        let body = fs::read_to_string(path)?;
+        let v = match path.file_name() {
+            Some(name) if name == "go.mod" => parse_go_mod(&body),
+            _ => body.trim().to_string(),
+        };
+        Ok(v)
+    }
+
+    fn verifies_checksums(&self) -> bool {
+        true
     }
 
     fn install_version(
@@ -155,7 +178,7 @@ impl Plugin for GoPlugin {
         tv: &ToolVersion,
         pr: &ProgressReport,
     ) -> Result<()> {
-        let tarball_path = self.download(tv, pr)?;
+        let tarball_path = self.download(config, tv, pr)?;
         self.install(tv, pr, &tarball_path)?;
         self.verify(config, tv, pr)?;
 
@@ -219,3 +242,49 @@ fn arch() -> &'static str {
         &ARCH
     }
 }
+
+/// uses `toolchain go1.21.3` if set, otherwise `go 1.21`. A `go` directive without a patch
+/// version is resolved to the latest patch release since "1.20" is also a release of its own.
+fn parse_go_mod(body: &str) -> String {
+    let directive = |name: &str| {
+        body.lines()
+            .map(|line| line.split("//").next().unwrap_or_default().trim())
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix(' '))
+            .map(|v| v.trim().to_string())
+    };
+    if let Some(toolchain) = directive("toolchain") {
+        if let Some(v) = toolchain.strip_prefix("go") {
+            return v.to_string();
+        }
+    }
+    match directive("go") {
+        Some(v) if v.split('.').count() < 3 => format!("prefix:{v}"),
+        Some(v) => v,
+        None => "".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_go_mod() {
+        assert_eq!(
+            parse_go_mod(indoc! {r#"
+            module example.com/foo
+
+            go 1.21
+        "#}),
+            "prefix:1.21"
+        );
+        assert_eq!(parse_go_mod("module foo\ngo 1.21.3 // comment\n"), "1.21.3");
+        assert_eq!(
+            parse_go_mod("module foo\ngo 1.21\ntoolchain go1.21.4\n"),
+            "1.21.4"
+        );
+        assert_eq!(parse_go_mod("module foo\n"), "");
+    }
+}
@@ -1,9 +1,10 @@
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use color_eyre::eyre::{eyre, Result};
+use indexmap::IndexMap;
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 use serde_derive::{Deserialize, Serialize};
@@ -115,7 +116,7 @@ impl JavaPlugin {
         let tarball_path = tv.download_path().join(filename);
 
         pr.set_message(format!("downloading {}", &m.url));
-        http.download_file(&m.url, &tarball_path)?;
+        http.download_file_resumable(&m.url, &tarball_path, &tv.version)?;
 
         hash::ensure_checksum_sha256(&tarball_path, &m.sha256)?;
 
@@ -204,11 +205,15 @@ impl Plugin for JavaPlugin {
             .cloned()
     }
 
-    fn get_aliases(&self, _settings: &Settings) -> Result<BTreeMap<String, String>> {
-        let aliases = BTreeMap::from([("lts".into(), "17".into())]);
+    fn list_aliases(&self, _settings: &Settings) -> Result<IndexMap<String, String>> {
+        let aliases = IndexMap::from([("lts".into(), "17".into())]);
         Ok(aliases)
     }
 
+    fn verifies_checksums(&self) -> bool {
+        true
+    }
+
     fn install_version(
         &self,
         config: &Config,
@@ -21,7 +21,7 @@ use crate::plugins::{Plugin, PluginName};
 use crate::timeout::run_with_timeout;
 use crate::tool::Tool;
 use crate::toolset::ToolVersion;
-use crate::{dirs, env};
+use crate::{dirs, env, file};
 
 mod bun;
 mod deno;
@@ -59,6 +59,14 @@ fn build_core_plugins(tools: Vec<Box<dyn Plugin>>) -> ToolMap {
     }))
 }
 
+/// node-build, python-build, ruby-build, and ruby-install check downloads against the checksums
+/// they ship with but skip it without saying so when there's nothing to compute them with
+pub fn build_tool_verifies_checksums() -> bool {
+    ["openssl", "shasum", "sha256sum"]
+        .iter()
+        .any(|bin| file::which(bin).is_some())
+}
+
 #[derive(Debug)]
 pub struct CorePlugin {
     pub name: PluginName,
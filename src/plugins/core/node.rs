@@ -1,10 +1,10 @@
-use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::exit;
 
 use clap::Command;
 use color_eyre::eyre::Result;
+use indexmap::IndexMap;
 
 use crate::cmd::CmdLineRunner;
 use crate::config::{Config, Settings};
@@ -13,7 +13,7 @@ use crate::env::{RTX_NODE_CONCURRENCY, RTX_NODE_FORCE_COMPILE};
 use crate::file::create_dir_all;
 use crate::git::Git;
 use crate::lock_file::LockFile;
-use crate::plugins::core::CorePlugin;
+use crate::plugins::core::{self, CorePlugin};
 use crate::plugins::{Plugin, PluginName};
 use crate::toolset::{ToolVersion, ToolVersionRequest};
 use crate::ui::progress_report::ProgressReport;
@@ -177,7 +177,7 @@ impl Plugin for NodePlugin {
             .cloned()
     }
 
-    fn get_aliases(&self, _settings: &Settings) -> Result<BTreeMap<String, String>> {
+    fn list_aliases(&self, _settings: &Settings) -> Result<IndexMap<String, String>> {
         let aliases = [
             ("lts/argon", "4"),
             ("lts/boron", "6"),
@@ -247,6 +247,10 @@ impl Plugin for NodePlugin {
         exit(0);
     }
 
+    fn verifies_checksums(&self) -> bool {
+        core::build_tool_verifies_checksums()
+    }
+
     fn install_version(
         &self,
         config: &Config,
@@ -257,6 +261,8 @@ impl Plugin for NodePlugin {
         pr.set_message("running node-build");
         let mut cmd = CmdLineRunner::new(&config.settings, self.node_build_bin())
             .with_pr(pr)
+            .envs(config.mirror_env(&self.core.name))
+            .envs(config.plugin_install_env(&self.core.name))
             .arg(tv.version.as_str());
         if matches!(&tv.request, ToolVersionRequest::Ref { .. }) || *RTX_NODE_FORCE_COMPILE {
             let make_opts = String::from(" -j") + &RTX_NODE_CONCURRENCY.to_string();
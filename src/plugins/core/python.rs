@@ -1,16 +1,16 @@
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
-use color_eyre::eyre::{eyre, Result};
+use color_eyre::eyre::Result;
 
 use crate::cmd::CmdLineRunner;
 use crate::config::{Config, Settings};
 
 use crate::file::create_dir_all;
 use crate::git::Git;
-use crate::plugins::core::CorePlugin;
-use crate::plugins::{Plugin, PluginName};
-use crate::toolset::{ToolVersion, ToolVersionRequest};
+use crate::plugins::core::{self, CorePlugin};
+use crate::plugins::{Plugin, PluginCapability, PluginName};
+use crate::toolset::ToolVersion;
 use crate::ui::progress_report::ProgressReport;
 use crate::{cmd, env, file, http};
 
@@ -150,6 +150,10 @@ impl Plugin for PythonPlugin {
         &self.core.name
     }
 
+    fn supports(&self, capability: PluginCapability) -> bool {
+        !matches!(capability, PluginCapability::Ref)
+    }
+
     fn list_remote_versions(&self, _settings: &Settings) -> Result<Vec<String>> {
         self.core
             .remote_version_cache
@@ -161,6 +165,10 @@ impl Plugin for PythonPlugin {
         Ok(vec![".python-version".to_string()])
     }
 
+    fn verifies_checksums(&self) -> bool {
+        core::build_tool_verifies_checksums()
+    }
+
     fn install_version(
         &self,
         config: &Config,
@@ -168,12 +176,11 @@ impl Plugin for PythonPlugin {
         pr: &ProgressReport,
     ) -> Result<()> {
         self.install_python_build()?;
-        if matches!(tv.request, ToolVersionRequest::Ref(..)) {
-            return Err(eyre!("Ref versions not supported for python"));
-        }
         pr.set_message("running python-build");
         let mut cmd = CmdLineRunner::new(&config.settings, self.python_build_bin())
             .with_pr(pr)
+            .envs(config.mirror_env(&self.core.name))
+            .envs(config.plugin_install_env(&self.core.name))
             .arg(tv.version.as_str())
             .arg(tv.install_path());
         if config.settings.verbose {
@@ -12,7 +12,7 @@ use crate::env::GITHUB_API_TOKEN;
 use crate::git::Git;
 use crate::github::GithubRelease;
 use crate::lock_file::LockFile;
-use crate::plugins::core::CorePlugin;
+use crate::plugins::core::{self, CorePlugin};
 use crate::plugins::{Plugin, PluginName};
 use crate::toolset::{ToolVersion, ToolVersionRequest};
 use crate::ui::progress_report::ProgressReport;
@@ -336,18 +336,15 @@ impl Plugin for RubyPlugin {
     fn parse_legacy_file(&self, path: &Path, _settings: &Settings) -> Result<String> {
         let v = match path.file_name() {
             Some(name) if name == "Gemfile" => parse_gemfile(&fs::read_to_string(path)?),
-            _ => {
-                // .ruby-version
-                let body = fs::read_to_string(path)?;
-                body.trim()
-                    .trim_start_matches("ruby-")
-                    .trim_start_matches('v')
-                    .to_string()
-            }
+            _ => parse_ruby_version(&fs::read_to_string(path)?),
         };
         Ok(v)
     }
 
+    fn verifies_checksums(&self) -> bool {
+        core::build_tool_verifies_checksums()
+    }
+
     fn install_version(
         &self,
         config: &Config,
@@ -358,7 +355,10 @@ impl Plugin for RubyPlugin {
         assert!(matches!(&tv.request, ToolVersionRequest::Version { .. }));
 
         pr.set_message("running ruby-build");
-        self.install_cmd(&config.settings, tv, pr)?.execute()?;
+        self.install_cmd(&config.settings, tv, pr)?
+            .envs(config.mirror_env(&self.core.name))
+            .envs(config.plugin_install_env(&self.core.name))
+            .execute()?;
 
         self.test_ruby(config, tv, pr)?;
         self.install_rubygems_hook(tv)?;
@@ -384,6 +384,14 @@ impl Plugin for RubyPlugin {
     }
 }
 
+/// .ruby-version, e.g.: "ruby-3.2.2"
+fn parse_ruby_version(body: &str) -> String {
+    body.trim()
+        .trim_start_matches("ruby-")
+        .trim_start_matches('v')
+        .to_string()
+}
+
 fn parse_gemfile(body: &str) -> String {
     let v = body
         .lines()
@@ -400,6 +408,14 @@ fn parse_gemfile(body: &str) -> String {
     let v = regex!(r#" *ruby *['"]([^'"]*).*"#)
         .replace_all(&v, "$1")
         .to_string();
+    // "~> 3.2.1" means ">= 3.2.1, < 3.3" so the latest 3.2 is a good match
+    let v = match v.trim().strip_prefix("~>") {
+        Some(v) => match v.trim().rsplit_once('.') {
+            Some((v, _)) => v.to_string(),
+            None => v.trim().to_string(),
+        },
+        None => v,
+    };
     let v = regex!(r#"^[^0-9]"#).replace_all(&v, "").to_string();
     let v = regex!(r#"(.*)__ENGINE__(.*)"#)
         .replace_all(&v, "$2-$1")
@@ -450,5 +466,14 @@ mod tests {
         "#}),
             ""
         );
+        assert_eq!(parse_gemfile("ruby '~> 3.2.1'\n"), "3.2");
+        assert_eq!(parse_gemfile("ruby \"~> 3.2\"\n"), "3");
+    }
+
+    #[test]
+    fn test_parse_ruby_version() {
+        assert_eq!(parse_ruby_version("ruby-3.2.2\n"), "3.2.2");
+        assert_eq!(parse_ruby_version("3.1\n"), "3.1");
+        assert_eq!(parse_ruby_version("jruby-9.4.3.0\n"), "jruby-9.4.3.0");
     }
 }
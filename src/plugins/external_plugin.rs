@@ -1,20 +1,22 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Formatter};
 use std::fs;
 use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
 use std::process::exit;
+use std::sync::RwLock;
 
 use std::time::Duration;
 
 use clap::Command;
 use color_eyre::eyre::{eyre, Result, WrapErr};
 use console::style;
+use indexmap::IndexMap;
 use itertools::Itertools;
-use once_cell::sync::Lazy;
+use once_cell::sync::{Lazy, OnceCell};
 
 use crate::cache::CacheManager;
-use crate::config::{Config, Settings};
+use crate::config::{ensure_online, Config, Settings};
 use crate::env::{PREFER_STALE, RTX_FETCH_REMOTE_VERSIONS_TIMEOUT};
 use crate::env_diff::{EnvDiff, EnvDiffOperation};
 use crate::errors::Error::PluginNotInstalled;
@@ -24,8 +26,11 @@ use crate::hash::hash_to_str;
 use crate::plugins::external_plugin_cache::ExternalPluginCache;
 use crate::plugins::rtx_plugin_toml::RtxPluginToml;
 use crate::plugins::Script::{Download, ExecEnv, Install, ParseLegacyFile};
-use crate::plugins::{Plugin, PluginName, PluginType, Script, ScriptManager};
+use crate::plugins::{
+    HealthReport, Plugin, PluginCapability, PluginName, PluginType, Script, ScriptManager,
+};
 use crate::timeout::run_with_timeout;
+use crate::tool::{custom_installs_path, installs_path};
 use crate::toolset::{ToolVersion, ToolVersionRequest};
 use crate::ui::progress_report::ProgressReport;
 use crate::{dirs, env, file};
@@ -38,18 +43,22 @@ pub struct ExternalPlugin {
     pub toml: RtxPluginToml,
     cache_path: PathBuf,
     downloads_path: PathBuf,
-    installs_path: PathBuf,
     script_man: ScriptManager,
     cache: ExternalPluginCache,
-    remote_version_cache: CacheManager<Vec<String>>,
-    latest_stable_cache: CacheManager<Option<String>>,
-    alias_cache: CacheManager<Vec<(String, String)>>,
-    legacy_filename_cache: CacheManager<Vec<String>>,
+    remote_version_cache: RwLock<CacheManager<Vec<String>>>,
+    latest_stable_cache: RwLock<CacheManager<Option<String>>>,
+    alias_cache: RwLock<CacheManager<Vec<(String, String)>>>,
+    legacy_filename_cache: RwLock<CacheManager<Vec<String>>>,
+    capabilities: RwLock<OnceCell<HashSet<PluginCapability>>>,
 }
 
 impl ExternalPlugin {
     pub fn new(name: &PluginName) -> Self {
-        let plugin_path = dirs::PLUGINS.join(name);
+        Self::with_path(name, dirs::PLUGINS.join(name))
+    }
+
+    /// a plugin installed at `plugin_path` instead of in the plugins directory
+    pub fn with_path(name: &PluginName, plugin_path: PathBuf) -> Self {
         let cache_path = dirs::CACHE.join(name);
         let toml_path = plugin_path.join("rtx.plugin.toml");
         let toml = RtxPluginToml::from_file(&toml_path).unwrap();
@@ -62,22 +71,30 @@ impl ExternalPlugin {
             name: name.into(),
             script_man: build_script_man(name, &plugin_path),
             downloads_path: dirs::DOWNLOADS.join(name),
-            installs_path: dirs::INSTALLS.join(name),
             cache: ExternalPluginCache::default(),
-            remote_version_cache: CacheManager::new(cache_path.join("remote_versions.msgpack.z"))
-                .with_fresh_duration(fresh_duration)
-                .with_fresh_file(plugin_path.clone())
-                .with_fresh_file(plugin_path.join("bin/list-all")),
-            latest_stable_cache: CacheManager::new(cache_path.join("latest_stable.msgpack.z"))
-                .with_fresh_duration(fresh_duration)
-                .with_fresh_file(plugin_path.clone())
-                .with_fresh_file(plugin_path.join("bin/latest-stable")),
-            alias_cache: CacheManager::new(cache_path.join("aliases.msgpack.z"))
-                .with_fresh_file(plugin_path.clone())
-                .with_fresh_file(plugin_path.join("bin/list-aliases")),
-            legacy_filename_cache: CacheManager::new(cache_path.join("legacy_filenames.msgpack.z"))
-                .with_fresh_file(plugin_path.clone())
-                .with_fresh_file(plugin_path.join("bin/list-legacy-filenames")),
+            remote_version_cache: RwLock::new(
+                CacheManager::new(cache_path.join("remote_versions.msgpack.z"))
+                    .with_fresh_duration(fresh_duration)
+                    .with_fresh_file(plugin_path.clone())
+                    .with_fresh_file(plugin_path.join("bin/list-all")),
+            ),
+            latest_stable_cache: RwLock::new(
+                CacheManager::new(cache_path.join("latest_stable.msgpack.z"))
+                    .with_fresh_duration(fresh_duration)
+                    .with_fresh_file(plugin_path.clone())
+                    .with_fresh_file(plugin_path.join("bin/latest-stable")),
+            ),
+            alias_cache: RwLock::new(
+                CacheManager::new(cache_path.join("aliases.msgpack.z"))
+                    .with_fresh_file(plugin_path.clone())
+                    .with_fresh_file(plugin_path.join("bin/list-aliases")),
+            ),
+            legacy_filename_cache: RwLock::new(
+                CacheManager::new(cache_path.join("legacy_filenames.msgpack.z"))
+                    .with_fresh_file(plugin_path.clone())
+                    .with_fresh_file(plugin_path.join("bin/list-legacy-filenames")),
+            ),
+            capabilities: RwLock::new(OnceCell::new()),
             plugin_path,
             cache_path,
             repo_url: None,
@@ -85,7 +102,19 @@ impl ExternalPlugin {
         }
     }
 
+    /// the caches are behind locks so the values this process already loaded can be reset too
+    fn clear_caches(&self) -> Result<()> {
+        self.remote_version_cache.write().unwrap().clear()?;
+        self.latest_stable_cache.write().unwrap().clear()?;
+        self.alias_cache.write().unwrap().clear()?;
+        self.legacy_filename_cache.write().unwrap().clear()?;
+        self.capabilities.write().unwrap().take();
+        self.cache.clear();
+        Ok(())
+    }
+
     fn fetch_remote_versions(&self, settings: &Settings) -> Result<Vec<String>> {
+        ensure_online(&format!("list remote versions for {}", self.name))?;
         let cmd = self.script_man.cmd(settings, &Script::ListAll);
         let result = run_with_timeout(
             move || {
@@ -130,6 +159,7 @@ impl ExternalPlugin {
         data.split_whitespace().map(|v| v.into()).collect()
     }
     fn fetch_latest_stable(&self, settings: &Settings) -> Result<Option<String>> {
+        ensure_online(&format!("fetch latest stable version for {}", self.name))?;
         let latest_stable = self
             .script_man
             .read(settings, &Script::LatestStable)?
@@ -142,6 +172,28 @@ impl ExternalPlugin {
         })
     }
 
+    fn fetch_capabilities(&self) -> HashSet<PluginCapability> {
+        let mut capabilities = HashSet::new();
+        if self.toml.list_legacy_filenames.data.is_some() || self.has_list_legacy_filenames_script()
+        {
+            capabilities.insert(PluginCapability::LegacyFiles);
+        }
+        if self.script_man.script_exists(&ExecEnv) {
+            capabilities.insert(PluginCapability::ExecEnv);
+        }
+        if self.has_list_all_script() {
+            capabilities.insert(PluginCapability::ListAll);
+        }
+        if self.script_man.script_exists(&Install) {
+            // asdf passes ASDF_INSTALL_TYPE=ref to bin/install
+            capabilities.insert(PluginCapability::Ref);
+        }
+        if self.has_latest_stable_script() {
+            capabilities.insert(PluginCapability::LatestStable);
+        }
+        capabilities
+    }
+
     fn has_list_all_script(&self) -> bool {
         self.script_man.script_exists(&Script::ListAll)
     }
@@ -155,7 +207,20 @@ impl ExternalPlugin {
         self.script_man.script_exists(&Script::LatestStable)
     }
     fn fetch_aliases(&self, settings: &Settings) -> Result<Vec<(String, String)>> {
-        let stdout = self.script_man.read(settings, &Script::ListAliases)?;
+        let cmd = self.script_man.cmd(settings, &Script::ListAliases);
+        let result = cmd.stdout_capture().stderr_capture().unchecked().run()?;
+        let stdout = String::from_utf8_lossy(&result.stdout);
+        let stderr = String::from_utf8_lossy(&result.stderr).trim().to_string();
+        if !result.status.success() {
+            return Err(eyre!(
+                "error running {}: exited with code {}\n{}",
+                Script::ListAliases,
+                result.status.code().unwrap_or_default(),
+                stderr
+            ));
+        } else if settings.verbose && !stderr.is_empty() {
+            eprintln!("{stderr}");
+        }
         Ok(self.parse_aliases(&stdout))
     }
     fn parse_aliases(&self, data: &str) -> Vec<(String, String)> {
@@ -237,12 +302,21 @@ impl ExternalPlugin {
             let k = format!("RTX_TOOL_OPTS__{}", key.to_uppercase());
             sm = sm.with_env(k, value.clone());
         }
+        for (key, value) in config.plugin_settings(&self.name) {
+            let k = format!("RTX_PLUGIN_SETTINGS__{}", key.to_uppercase());
+            sm = sm.with_env(k, value);
+        }
+        for (k, v) in config.mirror_env(&self.name) {
+            sm = sm.with_env(k, v);
+        }
         if let Some(project_root) = &config.project_root {
             let project_root = project_root.to_string_lossy().to_string();
             sm = sm.with_env("RTX_PROJECT_ROOT", project_root);
         }
         let install_type = match &tv.request {
-            ToolVersionRequest::Version(_, _) | ToolVersionRequest::Prefix(_, _) => "version",
+            ToolVersionRequest::Version(_, _)
+            | ToolVersionRequest::Prefix(_, _)
+            | ToolVersionRequest::Range(_, _) => "version",
             ToolVersionRequest::Ref(_, _) => "ref",
             ToolVersionRequest::Path(_, _) => "path",
             ToolVersionRequest::Sub { .. } => "sub",
@@ -251,7 +325,8 @@ impl ExternalPlugin {
             }
         };
         let install_version = match &tv.request {
-            ToolVersionRequest::Ref(_, v) => v, // should not have "ref:" prefix
+            // should not have "ref:" prefix, this is the commit sha if the plugin resolved it
+            ToolVersionRequest::Ref(_, _) => tv.version.trim_start_matches("ref:"),
             _ => &tv.version,
         };
         sm = sm
@@ -308,8 +383,20 @@ impl Plugin for ExternalPlugin {
     fn get_type(&self) -> PluginType {
         PluginType::External
     }
+    fn supports(&self, capability: PluginCapability) -> bool {
+        self.capabilities
+            .read()
+            .unwrap()
+            .get_or_init(|| self.fetch_capabilities())
+            .contains(&capability)
+    }
+    fn supported_platforms(&self) -> Option<Vec<String>> {
+        self.toml.platforms.clone()
+    }
     fn list_remote_versions(&self, settings: &Settings) -> Result<Vec<String>> {
         self.remote_version_cache
+            .read()
+            .unwrap()
             .get_or_try_init(|| self.fetch_remote_versions(settings))
             .map_err(|err| {
                 eyre!(
@@ -322,10 +409,12 @@ impl Plugin for ExternalPlugin {
     }
 
     fn latest_stable_version(&self, settings: &Settings) -> Result<Option<String>> {
-        if !self.has_latest_stable_script() {
+        if !self.supports(PluginCapability::LatestStable) {
             return Ok(None);
         }
         self.latest_stable_cache
+            .read()
+            .unwrap()
             .get_or_try_init(|| self.fetch_latest_stable(settings))
             .map_err(|err| {
                 eyre!(
@@ -337,6 +426,15 @@ impl Plugin for ExternalPlugin {
             .cloned()
     }
 
+    fn resolve_ref(&self, settings: &Settings, gitref: &str) -> Result<Option<String>> {
+        let script = Script::ResolveRef(gitref.to_string());
+        if !self.script_man.script_exists(&script) {
+            return Ok(None);
+        }
+        let sha = self.script_man.read(settings, &script)?.trim().to_string();
+        Ok(if sha.is_empty() { None } else { Some(sha) })
+    }
+
     fn get_remote_url(&self) -> Option<String> {
         let git = Git::new(self.plugin_path.to_path_buf());
         git.get_remote_url()
@@ -364,6 +462,8 @@ impl Plugin for ExternalPlugin {
             .ok_or_else(|| eyre!("No repository found for plugin {}", self.name))?;
         let (repo_url, repo_ref) = Git::split_url_and_ref(&repository);
         debug!("install {} {:?}", self.name, repository);
+        // check before uninstalling the existing plugin
+        ensure_online(&format!("install plugin {}", self.name))?;
 
         if self.is_installed() {
             self.uninstall(pr)?;
@@ -383,7 +483,7 @@ impl Plugin for ExternalPlugin {
         }
         if self.has_list_alias_script() {
             pr.set_message("getting plugin aliases");
-            self.get_aliases(&config.settings)?;
+            self.list_aliases(&config.settings)?;
         }
         if self.has_list_legacy_filenames_script() {
             pr.set_message("getting plugin legacy filenames");
@@ -418,7 +518,8 @@ impl Plugin for ExternalPlugin {
         // TODO: asdf_run_hook "pre_plugin_update"
         let (_pre, _post) = git.update(gitref)?;
         // TODO: asdf_run_hook "post_plugin_update"
-        Ok(())
+        // scripts may have changed so the cached output is no longer valid
+        self.clear_caches()
     }
 
     fn uninstall(&self, pr: &ProgressReport) -> Result<()> {
@@ -441,21 +542,32 @@ impl Plugin for ExternalPlugin {
         };
 
         rmdir(&self.downloads_path)?;
-        rmdir(&self.installs_path)?;
+        match custom_installs_path(&self.name) {
+            // set in the config so it isn't rtx's to remove, only the versions installed in it are
+            Some(dir) if dir.exists() => {
+                for version in file::dir_subdirs(&dir)? {
+                    rmdir(&dir.join(version))?;
+                }
+            }
+            Some(_) => {}
+            None => rmdir(&installs_path(&self.name))?,
+        }
         rmdir(&self.plugin_path)?;
 
         Ok(())
     }
 
-    fn get_aliases(&self, settings: &Settings) -> Result<BTreeMap<String, String>> {
+    fn list_aliases(&self, settings: &Settings) -> Result<IndexMap<String, String>> {
         if let Some(data) = &self.toml.list_aliases.data {
             return Ok(self.parse_aliases(data).into_iter().collect());
         }
         if !self.has_list_alias_script() {
-            return Ok(BTreeMap::new());
+            return Ok(IndexMap::new());
         }
         let aliases = self
             .alias_cache
+            .read()
+            .unwrap()
             .get_or_try_init(|| self.fetch_aliases(settings))
             .map_err(|err| {
                 eyre!(
@@ -478,6 +590,8 @@ impl Plugin for ExternalPlugin {
             return Ok(vec![]);
         }
         self.legacy_filename_cache
+            .read()
+            .unwrap()
             .get_or_try_init(|| self.fetch_legacy_filenames(settings))
             .map_err(|err| {
                 eyre!(
@@ -575,9 +689,14 @@ impl Plugin for ExternalPlugin {
         tv: &ToolVersion,
         pr: &ProgressReport,
     ) -> Result<()> {
+        // plugin download/install scripts fetch from the network
+        ensure_online(&format!("install {}", tv))?;
         let run_script = |script| {
-            self.script_man_for_tv(config, tv)
-                .run_by_line(&config.settings, script, pr)
+            let mut sm = self.script_man_for_tv(config, tv);
+            for (k, v) in config.plugin_install_env(&self.name) {
+                sm = sm.with_env(k, v);
+            }
+            sm.run_by_line(&config.settings, script, pr)
         };
 
         if self.script_man_for_tv(config, tv).script_exists(&Download) {
@@ -603,6 +722,38 @@ impl Plugin for ExternalPlugin {
             .list_bin_paths(config, self, tv, || self.fetch_bin_paths(config, tv))
     }
 
+    fn health_check(&self) -> Result<HealthReport> {
+        let required = [Script::ListAll, Install];
+        let optional = [
+            Script::LatestStable,
+            Script::ListAliases,
+            Script::ListLegacyFilenames,
+            ParseLegacyFile(String::new()),
+            Download,
+            ExecEnv,
+            Script::ListBinPaths,
+            Script::Uninstall,
+        ];
+        let mut report = HealthReport::default();
+        for (script, is_required) in required
+            .iter()
+            .map(|s| (s, true))
+            .chain(optional.iter().map(|s| (s, false)))
+        {
+            let name = format!("bin/{script}");
+            let path = self.script_man.get_script_path(script);
+            if !path.is_file() {
+                match is_required {
+                    true => report.missing_required.push(name),
+                    false => report.missing_optional.push(name),
+                }
+            } else if !file::is_executable(&path) {
+                report.not_executable.push(name);
+            }
+        }
+        Ok(report)
+    }
+
     fn exec_env(&self, config: &Config, tv: &ToolVersion) -> Result<HashMap<String, String>> {
         if matches!(tv.request, ToolVersionRequest::System(_)) {
             return Ok(EMPTY_HASH_MAP.clone());
@@ -624,7 +775,7 @@ impl Debug for ExternalPlugin {
             .field("plugin_path", &self.plugin_path)
             .field("cache_path", &self.cache_path)
             .field("downloads_path", &self.downloads_path)
-            .field("installs_path", &self.installs_path)
+            .field("installs_path", &installs_path(&self.name))
             .field("repo_url", &self.repo_url)
             .finish()
     }
@@ -641,4 +792,22 @@ mod tests {
         let plugin = ExternalPlugin::new(&PluginName::from("dummy"));
         assert!(format!("{:?}", plugin).starts_with("ExternalPlugin { name: \"dummy\""));
     }
+
+    #[test]
+    fn test_health_check() {
+        let plugin = ExternalPlugin::new(&PluginName::from("tiny"));
+        let report = plugin.health_check().unwrap();
+        assert!(report.missing_required.is_empty());
+        assert!(report.not_executable.is_empty());
+        assert_eq!(
+            report.missing_optional,
+            vec![
+                "bin/latest-stable",
+                "bin/parse-legacy-file",
+                "bin/download",
+                "bin/list-bin-paths",
+                "bin/uninstall",
+            ]
+        );
+    }
 }
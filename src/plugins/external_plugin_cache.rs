@@ -1,5 +1,6 @@
 use crate::cache::CacheManager;
 use crate::config::Config;
+use crate::git::Git;
 use crate::hash::hash_to_str;
 use crate::plugins::ExternalPlugin;
 use crate::tera::{get_tera, BASE_CONTEXT};
@@ -57,21 +58,29 @@ impl ExternalPluginCache {
     {
         let mut w = self.exec_env.write().unwrap();
         let cm = w.entry(tv.request.clone()).or_insert_with(|| {
-            let exec_env_filename = match &plugin.toml.exec_env.cache_key {
-                Some(key) => {
-                    let key = render_cache_key(config, tv, key);
-                    let filename = format!("{}.msgpack.z", key);
-                    tv.cache_path().join("exec_env").join(filename)
-                }
-                None => tv.cache_path().join("exec_env.msgpack.z"),
-            };
-            CacheManager::new(exec_env_filename)
+            // the output can depend on the plugin's code and where the version is installed
+            let sha = Git::new(plugin.plugin_path.clone()).head_sha();
+            let install_path = tv.install_path();
+            let mut key = hash_to_str(&(sha, &install_path));
+            key.truncate(10);
+            if let Some(cache_key) = &plugin.toml.exec_env.cache_key {
+                key = format!("{key}-{}", render_cache_key(config, tv, cache_key));
+            }
+            let filename = format!("{}.msgpack.z", key);
+            CacheManager::new(tv.cache_path().join("exec_env").join(filename))
                 .with_fresh_file(dirs::ROOT.clone())
                 .with_fresh_file(plugin.plugin_path.clone())
-                .with_fresh_file(tv.install_path())
+                .with_fresh_file(install_path)
+                .with_no_cache(*env::RTX_NO_EXEC_ENV_CACHE)
         });
         cm.get_or_try_init(fetch).cloned()
     }
+
+    /// forgets the values loaded by this process, the cache files are keyed by the plugin's code
+    pub fn clear(&self) {
+        self.list_bin_paths.write().unwrap().clear();
+        self.exec_env.write().unwrap().clear();
+    }
 }
 
 fn render_cache_key(config: &Config, tv: &ToolVersion, cache_key: &[String]) -> String {
@@ -2,13 +2,18 @@ use clap::Command;
 use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
 use std::path::{Path, PathBuf};
+use std::sync::RwLock;
 
 use color_eyre::eyre::Result;
+use indexmap::IndexMap;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
 
 pub use external_plugin::ExternalPlugin;
 pub use rtx_plugin_toml::RtxPluginToml;
 pub use script_manager::{Script, ScriptManager};
 
+use crate::cli::version::{ARCH, OS};
 use crate::config::{Config, Settings};
 use crate::toolset::ToolVersion;
 use crate::ui::progress_report::ProgressReport;
@@ -26,10 +31,25 @@ pub trait Plugin: Debug + Send + Sync {
     fn get_type(&self) -> PluginType {
         PluginType::Core
     }
+    /// whether the plugin provides a feature, lets callers skip scripts that do not exist
+    fn supports(&self, _capability: PluginCapability) -> bool {
+        true
+    }
+    /// the platforms the plugin works on like "linux" or "macos-arm64", `None` if it works
+    /// everywhere
+    fn supported_platforms(&self) -> Option<Vec<String>> {
+        None
+    }
     fn list_remote_versions(&self, settings: &Settings) -> Result<Vec<String>>;
     fn latest_stable_version(&self, _settings: &Settings) -> Result<Option<String>> {
         Ok(None)
     }
+    /// resolves a git ref (e.g.: main) to a commit sha so installs of the same commit are shared.
+    /// External plugins do it with a `bin/resolve-ref` script, an rtx-only extension that asdf
+    /// plugins don't have, so `None` (install the ref under its own name) is the fallback.
+    fn resolve_ref(&self, _settings: &Settings, _gitref: &str) -> Result<Option<String>> {
+        Ok(None)
+    }
     fn get_remote_url(&self) -> Option<String> {
         None
     }
@@ -51,8 +71,10 @@ pub trait Plugin: Debug + Send + Sync {
     fn uninstall(&self, _pr: &ProgressReport) -> Result<()> {
         Ok(())
     }
-    fn get_aliases(&self, _settings: &Settings) -> Result<BTreeMap<String, String>> {
-        Ok(BTreeMap::new())
+    /// the aliases the plugin itself defines in the order it lists them, without the ones from
+    /// config files
+    fn list_aliases(&self, _settings: &Settings) -> Result<IndexMap<String, String>> {
+        Ok(IndexMap::new())
     }
     fn legacy_filenames(&self, _settings: &Settings) -> Result<Vec<String>> {
         Ok(vec![])
@@ -72,6 +94,11 @@ pub trait Plugin: Debug + Send + Sync {
     ) -> Result<()> {
         unimplemented!()
     }
+    /// whether `install_version()` checks what it downloads against a checksum, with the
+    /// require_checksums setting plugins that don't are refused instead of installing unverified
+    fn verifies_checksums(&self) -> bool {
+        false
+    }
     fn install_version(&self, config: &Config, tv: &ToolVersion, pr: &ProgressReport)
         -> Result<()>;
     fn uninstall_version(&self, _config: &Config, _tv: &ToolVersion) -> Result<()> {
@@ -80,17 +107,105 @@ pub trait Plugin: Debug + Send + Sync {
     fn list_bin_paths(&self, _config: &Config, tv: &ToolVersion) -> Result<Vec<PathBuf>> {
         Ok(vec![tv.install_path().join("bin")])
     }
+    /// checks the plugin is usable without running any of its scripts since they may be slow or
+    /// use the network, core plugins are built in so there is nothing to check
+    fn health_check(&self) -> Result<HealthReport> {
+        Ok(HealthReport::default())
+    }
     fn exec_env(&self, _config: &Config, _tv: &ToolVersion) -> Result<HashMap<String, String>> {
         Ok(HashMap::new())
     }
 }
 
-pub fn unalias_plugin(plugin_name: &str) -> &str {
+/// alternate plugin names from the `plugin_aliases` setting, e.g.: `nodejs = "node"`
+static PLUGIN_ALIASES: Lazy<RwLock<BTreeMap<String, PluginName>>> = Lazy::new(Default::default);
+
+pub fn set_plugin_aliases(aliases: BTreeMap<String, PluginName>) {
+    *PLUGIN_ALIASES.write().unwrap() = aliases;
+}
+
+/// the canonical name of a plugin, so `nodejs` and `node` are the same plugin
+pub fn unalias_plugin(plugin_name: &str) -> PluginName {
+    unalias_plugin_with(&PLUGIN_ALIASES.read().unwrap(), plugin_name)
+}
+
+fn unalias_plugin_with(aliases: &BTreeMap<String, PluginName>, plugin_name: &str) -> PluginName {
+    if let Some(name) = aliases.get(plugin_name) {
+        return name.clone();
+    }
     match plugin_name {
         "nodejs" => "node",
         "golang" => "go",
         _ => plugin_name,
     }
+    .to_string()
+}
+
+/// the env vars the usual installers for a plugin read a download mirror from, the node and
+/// python ones cover both the core plugins and the asdf plugins since they use node-build and
+/// python-build
+pub fn mirror_env_vars(plugin_name: &str) -> &'static [&'static str] {
+    match plugin_name {
+        "node" => &["NODEJS_ORG_MIRROR", "NODE_BUILD_MIRROR_URL"],
+        "python" => &["PYTHON_BUILD_MIRROR_URL"],
+        "ruby" => &["RUBY_BUILD_MIRROR_URL"],
+        _ => &[],
+    }
+}
+
+/// the other names that `unalias_plugin` maps to `plugin_name`
+pub fn plugin_aliases_of(plugin_name: &str) -> Vec<String> {
+    plugin_aliases_of_with(&PLUGIN_ALIASES.read().unwrap(), plugin_name)
+}
+
+fn plugin_aliases_of_with(
+    aliases: &BTreeMap<String, PluginName>,
+    plugin_name: &str,
+) -> Vec<String> {
+    aliases
+        .keys()
+        .map(|k| k.as_str())
+        .chain(["nodejs", "golang"])
+        .filter(|alias| *alias != plugin_name && unalias_plugin_with(aliases, alias) == plugin_name)
+        .unique()
+        .map(String::from)
+        .collect()
+}
+
+/// whether the current platform is one of `platforms`, see `Plugin::supported_platforms()`
+pub fn is_platform_supported(platforms: &[String]) -> bool {
+    platform_matches(platforms, &OS, &ARCH)
+}
+
+fn platform_matches(platforms: &[String], os: &str, arch: &str) -> bool {
+    platforms
+        .iter()
+        .any(|p| p == os || *p == format!("{os}-{arch}"))
+}
+
+/// the result of `Plugin::health_check()`, scripts are named relative to the plugin directory
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct HealthReport {
+    /// scripts the plugin can't work without
+    pub missing_required: Vec<String>,
+    /// scripts that only disable a feature when they're missing
+    pub missing_optional: Vec<String>,
+    /// scripts that exist but can't be run
+    pub not_executable: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluginCapability {
+    /// reads versions from files like .node-version
+    LegacyFiles,
+    /// sets env vars for an installed version
+    ExecEnv,
+    /// lists the remote versions available to install
+    ListAll,
+    /// installs `ref:` versions (e.g.: a git branch or sha)
+    Ref,
+    /// picks the latest stable version itself instead of rtx using the highest remote version
+    LatestStable,
 }
 
 pub enum PluginType {
@@ -124,6 +239,28 @@ mod tests {
         assert_str_eq!(version, "3.1.0");
     }
 
+    #[test]
+    fn test_unalias_plugin() {
+        let aliases = [("nd".to_string(), "node".to_string())].into();
+        assert_str_eq!(unalias_plugin_with(&aliases, "nd"), "node");
+        assert_str_eq!(unalias_plugin_with(&aliases, "nodejs"), "node");
+        assert_str_eq!(unalias_plugin_with(&aliases, "tiny"), "tiny");
+        assert_eq!(
+            plugin_aliases_of_with(&aliases, "node"),
+            vec!["nd", "nodejs"]
+        );
+        assert_str_eq!(unalias_plugin_with(&BTreeMap::new(), "nd"), "nd");
+    }
+
+    #[test]
+    fn test_supports() {
+        let plugin = ExternalPlugin::new(&PluginName::from("dummy"));
+        assert!(plugin.supports(PluginCapability::ListAll));
+        assert!(plugin.supports(PluginCapability::Ref));
+        assert!(!plugin.supports(PluginCapability::ExecEnv));
+        assert!(plugin.supports(PluginCapability::LatestStable));
+    }
+
     #[test]
     fn test_latest_stable() {
         let settings = Settings::default();
@@ -132,4 +269,18 @@ mod tests {
         let version = tool.latest_version(&settings, None).unwrap().unwrap();
         assert_str_eq!(version, "2.0.0");
     }
+
+    #[test]
+    fn test_platform_matches() {
+        let platforms = vec!["linux".to_string(), "macos-arm64".to_string()];
+        assert!(platform_matches(&platforms, "linux", "x64"));
+        assert!(platform_matches(&platforms, "macos", "arm64"));
+        assert!(!platform_matches(&platforms, "macos", "x64"));
+        assert!(!platform_matches(&platforms, "windows", "x64"));
+        let dummy = Tool::new(
+            "dummy".into(),
+            Box::new(ExternalPlugin::new(&"dummy".into())),
+        );
+        assert!(dummy.unsupported_platform().is_none());
+    }
 }
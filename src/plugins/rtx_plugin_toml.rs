@@ -19,6 +19,8 @@ pub struct RtxPluginToml {
     pub list_aliases: RtxPluginTomlScriptConfig,
     pub list_bin_paths: RtxPluginTomlScriptConfig,
     pub list_legacy_filenames: RtxPluginTomlScriptConfig,
+    /// the platforms the plugin works on, `None` if it works everywhere
+    pub platforms: Option<Vec<String>>,
 }
 
 impl RtxPluginToml {
@@ -49,6 +51,7 @@ impl RtxPluginToml {
                 "list-legacy-filenames" => {
                     self.list_legacy_filenames = self.parse_script_config(k, v)?
                 }
+                "platforms" => self.platforms = Some(self.parse_string_array(k, v)?),
                 // this is an old key used in rtx-python
                 // this file is invalid, so just stop parsing entirely if we see it
                 "legacy-filenames" => return Ok(()),
@@ -142,6 +145,20 @@ mod tests {
         "###);
     }
 
+    #[test]
+    fn test_platforms() {
+        let cf = parse(r#"platforms = ["linux", "macos-arm64"]"#);
+        assert_debug_snapshot!(cf.platforms, @r###"
+        Some(
+            [
+                "linux",
+                "macos-arm64",
+            ],
+        )
+        "###);
+        assert!(parse("").platforms.is_none());
+    }
+
     fn parse(s: &str) -> RtxPluginToml {
         let mut cf = RtxPluginToml::init();
         cf.parse(s).unwrap();
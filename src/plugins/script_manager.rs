@@ -39,6 +39,8 @@ pub enum Script {
     ListAll,
     ListLegacyFilenames,
     ParseLegacyFile(String),
+    /// rtx-only, asdf doesn't have it. See `Plugin::resolve_ref()`
+    ResolveRef(String),
 
     // RuntimeVersion
     Download,
@@ -58,6 +60,7 @@ impl Display for Script {
             Script::ListLegacyFilenames => write!(f, "list-legacy-filenames"),
             Script::ListAliases => write!(f, "list-aliases"),
             Script::ParseLegacyFile(_) => write!(f, "parse-legacy-file"),
+            Script::ResolveRef(_) => write!(f, "resolve-ref"),
 
             // RuntimeVersion
             Script::Install => write!(f, "install"),
@@ -128,6 +131,7 @@ impl ScriptManager {
     pub fn cmd(&self, settings: &Settings, script: &Script) -> Expression {
         let args = match script {
             Script::ParseLegacyFile(filename) => vec![filename.clone()],
+            Script::ResolveRef(gitref) => vec![gitref.clone()],
             Script::RunExternalCommand(_, args) => args.clone(),
             _ => vec![],
         };
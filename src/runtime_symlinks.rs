@@ -8,14 +8,13 @@ use regex::Regex;
 use versions::Version;
 
 use crate::config::Config;
-use crate::dirs;
 use crate::file::make_symlink;
 use crate::tool::Tool;
 
 pub fn rebuild(config: &Config) -> Result<()> {
     for plugin in config.tools.values() {
         let symlinks = list_symlinks(config, plugin)?;
-        let installs_dir = dirs::INSTALLS.join(&plugin.name);
+        let installs_dir = plugin.installs_path();
         for (from, to) in symlinks {
             let from = installs_dir.join(from);
             if from.exists() {
@@ -53,7 +52,7 @@ fn list_symlinks(config: &Config, plugin: &Tool) -> Result<IndexMap<String, Path
         }
         symlinks.insert("latest".into(), rel_path(&v));
         for (from, to) in config
-            .get_all_aliases()
+            .get_all_aliases()?
             .get(&plugin.name)
             .unwrap_or(&BTreeMap::new())
         {
@@ -84,7 +83,7 @@ fn installed_versions(plugin: &Tool) -> Result<Vec<String>> {
 }
 
 fn remove_missing_symlinks(plugin: &Tool) -> Result<()> {
-    let installs_dir = dirs::INSTALLS.join(&plugin.name);
+    let installs_dir = plugin.installs_path();
     if !installs_dir.exists() {
         return Ok(());
     }
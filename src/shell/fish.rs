@@ -80,8 +80,7 @@ impl Shell for Fish {
 
     fn set_env(&self, k: &str, v: &str) -> String {
         let k = shell_escape::unix::escape(k.into());
-        let v = shell_escape::unix::escape(v.into());
-        let v = v.replace("\\n", "\n");
+        let v = fish_escape(v);
         format!("set -gx {k} {v}\n")
     }
 
@@ -90,6 +89,16 @@ impl Shell for Fish {
     }
 }
 
+/// unlike posix shells, fish reads `\\` and `\'` inside single quotes as escapes so backslashes
+/// need escaping too, e.g.: `'C:\'` would be an unterminated string
+fn fish_escape(s: &str) -> String {
+    let is_safe = |c: char| c.is_ascii_alphanumeric() || "-_=/,.+:%@".contains(c);
+    if !s.is_empty() && s.chars().all(is_safe) {
+        return s.to_string();
+    }
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,6 +124,16 @@ mod tests {
         assert_snapshot!(Fish::default().set_env("FOO", "1"));
     }
 
+    #[test]
+    fn test_set_env_escape() {
+        let fish = Fish::default();
+        assert_snapshot!(fish.set_env("FOO", "it's a \\ \"test\"\n"), @r###"
+        set -gx FOO 'it\'s a \\ "test"
+        '
+        "###);
+        assert_snapshot!(fish.set_env("FOO", ""), @"set -gx FOO ''");
+    }
+
     #[test]
     fn test_unset_env() {
         assert_snapshot!(Fish::default().unset_env("FOO"));
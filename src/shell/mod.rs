@@ -6,6 +6,7 @@ use crate::env;
 mod bash;
 mod fish;
 mod nushell;
+mod pwsh;
 mod xonsh;
 mod zsh;
 
@@ -14,6 +15,7 @@ pub enum ShellType {
     Bash,
     Fish,
     Nu,
+    Pwsh,
     Xonsh,
     Zsh,
 }
@@ -27,6 +29,8 @@ impl ShellType {
             Some(ShellType::Fish)
         } else if shell.ends_with("nu") {
             Some(ShellType::Nu)
+        } else if shell.ends_with("pwsh") || shell.ends_with("powershell") {
+            Some(ShellType::Pwsh)
         } else if shell.ends_with("xonsh") {
             Some(ShellType::Xonsh)
         } else if shell.ends_with("zsh") {
@@ -43,6 +47,7 @@ impl Display for ShellType {
             Self::Bash => write!(f, "bash"),
             Self::Fish => write!(f, "fish"),
             Self::Nu => write!(f, "nu"),
+            Self::Pwsh => write!(f, "pwsh"),
             Self::Xonsh => write!(f, "xonsh"),
             Self::Zsh => write!(f, "zsh"),
         }
@@ -61,6 +66,7 @@ pub fn get_shell(shell: Option<ShellType>) -> Option<Box<dyn Shell>> {
         Some(ShellType::Bash) => Some(Box::<bash::Bash>::default()),
         Some(ShellType::Fish) => Some(Box::<fish::Fish>::default()),
         Some(ShellType::Nu) => Some(Box::<nushell::Nushell>::default()),
+        Some(ShellType::Pwsh) => Some(Box::<pwsh::Pwsh>::default()),
         Some(ShellType::Xonsh) => Some(Box::<xonsh::Xonsh>::default()),
         Some(ShellType::Zsh) => Some(Box::<zsh::Zsh>::default()),
         _ => None,
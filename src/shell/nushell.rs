@@ -77,7 +77,7 @@ impl Shell for Nushell {
           def-env "update-env" [] {{
             for $var in $in {{
               if $var.op == "set" {{
-                load-env {{($var.name): $var.value}}
+                load-env {{($var.name): ($var.value | from json)}}
               }} else if $var.op == "hide" {{
                 hide-env $var.name
               }}
@@ -101,10 +101,9 @@ impl Shell for Nushell {
 
     fn set_env(&self, k: &str, v: &str) -> String {
         let k = shell_escape::unix::escape(k.into());
-        let v = shell_escape::unix::escape(v.into());
-        let v = v.replace("\\n", "\n");
-        let v = v.replace('\'', "");
-
+        // `parse vars` reads one var per line so the value is encoded as a json string, which
+        // escapes newlines, and `update-env` decodes it
+        let v = serde_json::to_string(v).unwrap();
         EnvOp::Set { key: &k, val: &v }.to_string()
     }
 
@@ -139,6 +138,18 @@ mod tests {
         assert_snapshot!(Nushell::default().set_env("FOO", "1"));
     }
 
+    #[test]
+    fn test_set_env_escape() {
+        let nushell = Nushell::default();
+        assert_snapshot!(nushell.set_env("FOO", "it's a \"test\""), @r###"set,FOO,"it's a \"test\"""###);
+    }
+
+    #[test]
+    fn test_set_env_multiline() {
+        let nushell = Nushell::default();
+        assert_snapshot!(nushell.set_env("FOO", "line 1\nline 2"), @r###"set,FOO,"line 1\nline 2""###);
+    }
+
     #[test]
     fn test_unset_env() {
         assert_snapshot!(Nushell::default().unset_env("FOO"));
@@ -0,0 +1,146 @@
+use std::path::Path;
+
+use indoc::formatdoc;
+
+use crate::shell::{is_dir_in_path, is_dir_not_in_nix, Shell};
+
+#[derive(Default)]
+pub struct Pwsh {}
+
+impl Shell for Pwsh {
+    fn activate(&self, exe: &Path, status: bool) -> String {
+        let dir = exe.parent().unwrap();
+        let exe = pwsh_escape(&exe.to_string_lossy());
+        let status = if status { " --status" } else { "" };
+        let mut out = String::new();
+        if is_dir_not_in_nix(dir) && !is_dir_in_path(dir) {
+            out.push_str(&format!(
+                "$env:PATH = {} + [IO.Path]::PathSeparator + $env:PATH\n",
+                pwsh_escape(&dir.to_string_lossy())
+            ));
+        }
+        out.push_str(&formatdoc! {r#"
+            $env:RTX_SHELL = 'pwsh'
+
+            function global:rtx {{
+              if ($args.Count -eq 0) {{
+                & {exe}
+                return
+              }}
+              $command = $args[0]
+              if ($command -in 'deactivate', 'shell') {{
+                & {exe} @args | Out-String | Invoke-Expression
+              }} else {{
+                & {exe} @args
+              }}
+            }}
+
+            function global:_rtx_hook {{
+              & {exe} hook-env{status} -s pwsh | Out-String | Invoke-Expression
+            }}
+
+            if (-not (Test-Path function:_rtx_old_prompt)) {{
+              Copy-Item function:prompt function:global:_rtx_old_prompt
+              function global:prompt {{
+                _rtx_hook
+                _rtx_old_prompt
+              }}
+            }}
+            "#});
+
+        out
+    }
+
+    fn deactivate(&self) -> String {
+        formatdoc! {r#"
+            if (Test-Path function:_rtx_old_prompt) {{
+              Copy-Item function:_rtx_old_prompt function:global:prompt
+              Remove-Item function:_rtx_old_prompt
+            }}
+            Remove-Item -ErrorAction SilentlyContinue function:_rtx_hook
+            Remove-Item -ErrorAction SilentlyContinue function:rtx
+            Remove-Item -ErrorAction SilentlyContinue env:RTX_SHELL
+        "#}
+    }
+
+    fn set_env(&self, k: &str, v: &str) -> String {
+        format!("${{env:{}}} = {}\n", escape_var_name(k), pwsh_escape(v))
+    }
+
+    fn unset_env(&self, k: &str) -> String {
+        format!(
+            "Remove-Item -ErrorAction SilentlyContinue -LiteralPath {}\n",
+            pwsh_escape(&format!("env:{k}"))
+        )
+    }
+}
+
+/// single quoted strings in powershell have no escapes other than doubling the quote, which
+/// includes the "smart" quotes powershell also accepts
+fn pwsh_escape(s: &str) -> String {
+    let mut out = String::from("'");
+    for c in s.chars() {
+        if matches!(c, '\'' | '‘' | '’' | '‚' | '‛') {
+            out.push(c);
+        }
+        out.push(c);
+    }
+    out.push('\'');
+    out
+}
+
+/// `${env:NAME}` allows any name as long as `{`, `}` and the backtick are escaped
+fn escape_var_name(k: &str) -> String {
+    let mut out = String::new();
+    for c in k.chars() {
+        if matches!(c, '{' | '}' | '`') {
+            out.push('`');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::replace_path;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn test_hook_init() {
+        let pwsh = Pwsh::default();
+        let exe = Path::new("/some/dir/rtx");
+        assert_snapshot!(pwsh.activate(exe, true));
+    }
+
+    #[test]
+    fn test_hook_init_nix() {
+        let pwsh = Pwsh::default();
+        let exe = Path::new("/nix/store/rtx");
+        assert_snapshot!(pwsh.activate(exe, true));
+    }
+
+    #[test]
+    fn test_set_env() {
+        assert_snapshot!(Pwsh::default().set_env("FOO", "1"));
+    }
+
+    #[test]
+    fn test_set_env_escape() {
+        let pwsh = Pwsh::default();
+        assert_snapshot!(pwsh.set_env("FOO", "it's $HOME `a`"), @"${env:FOO} = 'it''s $HOME `a`'");
+        assert_snapshot!(pwsh.set_env("A{B}", "1"), @"${env:A`{B`}} = '1'");
+    }
+
+    #[test]
+    fn test_unset_env() {
+        assert_snapshot!(Pwsh::default().unset_env("FOO"));
+    }
+
+    #[test]
+    fn test_deactivate() {
+        let deactivate = Pwsh::default().deactivate();
+        assert_snapshot!(replace_path(&deactivate));
+    }
+}
@@ -35,6 +35,8 @@ pub fn handle_shim(mut config: Config, args: &[String], out: &mut Output) -> Res
         c: None,
         command: Some(args),
         cd: None,
+        only: vec![],
+        resolve_policy: Default::default(),
     };
     exec.run(config, out)?;
     exit(0);
@@ -44,10 +46,8 @@ fn which_shim(config: &mut Config, bin_name: &str) -> Result<PathBuf> {
     let shim = dirs::SHIMS.join(bin_name);
     if shim.exists() {
         let ts = ToolsetBuilder::new().build(config)?;
-        if let Some((p, tv)) = ts.which(config, bin_name) {
-            if let Some(bin) = p.which(config, &tv, bin_name)? {
-                return Ok(bin);
-            }
+        if let Some((_, _, bin)) = ts.which(config, bin_name) {
+            return Ok(bin);
         }
         // fallback for "system"
         for path in &*env::PATH {
@@ -61,6 +61,8 @@ fn which_shim(config: &mut Config, bin_name: &str) -> Result<PathBuf> {
                 return Ok(bin);
             }
         }
+        // the bin may come from a version of any plugin
+        config.load_all_tools()?;
         let tvs = ts.list_rtvs_with_bin(config, bin_name)?;
         err_no_version_set(config, ts, bin_name, tvs)?;
     }
@@ -79,17 +81,7 @@ pub fn reshim(config: &mut Config, ts: &Toolset) -> Result<()> {
     create_dir_all(&*dirs::SHIMS)?;
     let existing_shims = list_executables_in_dir(&dirs::SHIMS)?;
 
-    let shims: HashSet<String> = ts
-        .list_installed_versions(config)?
-        .into_par_iter()
-        .flat_map(|(t, tv)| match list_tool_bins(config, &t, &tv) {
-            Ok(paths) => paths,
-            Err(e) => {
-                warn!("Error listing bin paths for {}: {:#}", tv, e);
-                Vec::new()
-            }
-        })
-        .collect();
+    let shims: HashSet<String> = ts.required_shims(config)?.into_iter().collect();
 
     let shims_to_add = shims.difference(&existing_shims);
     let shims_to_remove = existing_shims.difference(&shims);
@@ -129,7 +121,7 @@ pub fn reshim(config: &mut Config, ts: &Toolset) -> Result<()> {
 }
 
 // lists all the paths to bins in a tv that shims will be needed for
-fn list_tool_bins(config: &Config, t: &Tool, tv: &ToolVersion) -> Result<Vec<String>> {
+pub fn list_tool_bins(config: &Config, t: &Tool, tv: &ToolVersion) -> Result<Vec<String>> {
     Ok(t.list_bin_paths(config, tv)?
         .into_iter()
         .par_bridge()
@@ -192,7 +184,7 @@ fn err_no_version_set(
     }
     let missing_plugins = tvs.iter().map(|tv| &tv.plugin_name).collect::<HashSet<_>>();
     let mut missing_tools = ts
-        .list_missing_versions(config)
+        .list_missing_versions(config, None)
         .into_iter()
         .filter(|t| missing_plugins.contains(&t.plugin_name))
         .collect_vec();
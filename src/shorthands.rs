@@ -11,8 +11,11 @@ use crate::dirs;
 
 pub type Shorthands = HashMap<String, String>;
 
-pub fn get_shorthands(settings: &Settings) -> Shorthands {
+/// loads the default and custom shorthands along with the names of any
+/// default shorthands that were overridden by the custom shorthands file
+pub fn load_shorthands(settings: &Settings) -> (Shorthands, Vec<String>) {
     let mut shorthands = HashMap::new();
+    let mut collisions = vec![];
     if !settings.disable_default_shorthands {
         shorthands.extend(
             DEFAULT_SHORTHANDS
@@ -23,17 +26,23 @@ pub fn get_shorthands(settings: &Settings) -> Shorthands {
     if let Some(f) = &settings.shorthands_file {
         match parse_shorthands_file(f.clone()) {
             Ok(custom) => {
-                shorthands.extend(custom);
+                for (k, v) in custom {
+                    if matches!(shorthands.get(&k), Some(prev) if prev != &v) {
+                        collisions.push(k.clone());
+                    }
+                    shorthands.insert(k, v);
+                }
             }
             Err(err) => {
                 warn!("Failed to read shorthands file: {} {:#}", &f.display(), err);
             }
         }
     }
-    shorthands
+    collisions.sort();
+    (shorthands, collisions)
 }
 
-fn parse_shorthands_file(mut f: PathBuf) -> Result<Shorthands> {
+pub fn parse_shorthands_file(mut f: PathBuf) -> Result<Shorthands> {
     if f.starts_with("~") {
         f = dirs::HOME.join(f.strip_prefix("~")?);
     }
@@ -61,7 +70,7 @@ mod tests {
             shorthands_file: Some("../fixtures/shorthands.toml".into()),
             ..Settings::default()
         };
-        let shorthands = get_shorthands(&settings);
+        let (shorthands, _) = load_shorthands(&settings);
         assert_str_eq!(
             shorthands["elixir"],
             "https://github.com/asdf-vm/asdf-elixir.git"
@@ -70,13 +79,34 @@ mod tests {
         assert_str_eq!(shorthands["xxxxxx"], "https://xxxxxx");
     }
 
+    #[test]
+    fn test_load_shorthands_collisions() {
+        let settings = Settings {
+            shorthands_file: Some("../fixtures/shorthands_collision.toml".into()),
+            ..Settings::default()
+        };
+        let (shorthands, collisions) = load_shorthands(&settings);
+        assert_str_eq!(
+            shorthands["elixir"],
+            "https://github.com/me/asdf-elixir.git"
+        );
+        assert_eq!(collisions, vec!["elixir"]);
+
+        let settings = Settings {
+            disable_default_shorthands: true,
+            ..settings
+        };
+        let (_, collisions) = load_shorthands(&settings);
+        assert!(collisions.is_empty());
+    }
+
     #[test]
     fn test_get_shorthands_missing_file() {
         let settings = Settings {
             shorthands_file: Some("test/fixtures/missing.toml".into()),
             ..Settings::default()
         };
-        let shorthands = get_shorthands(&settings);
+        let (shorthands, _) = load_shorthands(&settings);
         assert!(!shorthands.is_empty());
     }
 }
@@ -21,6 +21,10 @@ fn init() {
     env::set_var("RTX_USE_TOML", "0");
     env::set_var("RTX_DATA_DIR", env::HOME.join("data"));
     env::set_var("RTX_CONFIG_DIR", env::HOME.join("config"));
+    env::set_var(
+        "RTX_SYSTEM_CONFIG_FILE",
+        env::HOME.join("config/system.toml"),
+    );
     env::set_var("RTX_CACHE_DIR", env::HOME.join("data/cache"));
     env::set_var("RTX_DEFAULT_TOOL_VERSIONS_FILENAME", ".test-tool-versions");
     env::set_var("RTX_DEFAULT_CONFIG_FILENAME", ".test.rtx.toml");
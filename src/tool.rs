@@ -1,56 +1,188 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fs;
 use std::fs::File;
 use std::path::{Path, PathBuf};
+use std::sync::{Mutex, RwLock};
+use std::time::SystemTime;
 
 use clap::Command;
 use color_eyre::eyre::{eyre, Result};
 use console::style;
+use indexmap::IndexMap;
 use itertools::Itertools;
+use once_cell::sync::Lazy;
 use regex::Regex;
+use serde_derive::{Deserialize, Serialize};
 use versions::Versioning;
 
+use crate::cli::version::{ARCH, OS};
+use crate::cmd::CmdLineRunner;
 use crate::config::{Config, Settings};
+use crate::errors::InstallError;
 use crate::file::{create_dir_all, display_path, remove_all_with_warning};
 use crate::lock_file::LockFile;
-use crate::plugins::{ExternalPlugin, Plugin, PluginType};
+use crate::plugins::{
+    is_platform_supported, ExternalPlugin, Plugin, PluginCapability, PluginName, PluginType,
+};
 use crate::runtime_symlinks::is_runtime_symlink;
 use crate::toolset::{ToolVersion, ToolVersionRequest};
 use crate::ui::progress_report::{ProgressReport, PROG_TEMPLATE};
 use crate::{dirs, file};
 
+const MAX_COMPLETE_VERSIONS: usize = 100;
+
+/// `[plugins.<name>] install_dir` from the config, core plugins are created before the config is
+/// loaded so this can't live on `Tool`
+static INSTALL_DIRS: Lazy<RwLock<HashMap<PluginName, PathBuf>>> = Lazy::new(Default::default);
+
+/// the `install_dir_template` setting, for plugins without an `install_dir`
+static INSTALL_DIR_TEMPLATE: RwLock<Option<String>> = RwLock::new(None);
+
+pub fn set_install_dirs(install_dirs: HashMap<PluginName, PathBuf>) {
+    *INSTALL_DIRS.write().unwrap() = install_dirs;
+}
+
+pub fn set_install_dir_template(template: Option<String>) {
+    *INSTALL_DIR_TEMPLATE.write().unwrap() = template;
+}
+
+/// the project root, see `Tool::versions_snapshot_path()`
+static VERSIONS_SNAPSHOT_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+pub fn set_versions_snapshot_dir(project_root: Option<PathBuf>) {
+    *VERSIONS_SNAPSHOT_DIR.write().unwrap() = project_root;
+}
+
+/// every remote version of a plugin saved with `rtx ls-remote --save-snapshot`, when one is
+/// committed to the project it's used instead of asking the plugin so resolving `latest`,
+/// prefixes, and ranges gives the same versions on every machine
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionsSnapshot {
+    pub versions: Vec<String>,
+    /// from bin/latest-stable if the plugin has one
+    pub latest_stable: Option<String>,
+}
+
+/// where versions of a plugin are installed, `~/.local/share/rtx/installs/<name>` by default
+pub fn installs_path(plugin_name: &str) -> PathBuf {
+    custom_installs_path(plugin_name).unwrap_or_else(|| dirs::INSTALLS.join(plugin_name))
+}
+
+pub fn custom_installs_path(plugin_name: &str) -> Option<PathBuf> {
+    let install_dirs = INSTALL_DIRS.read().unwrap();
+    let template = INSTALL_DIR_TEMPLATE.read().unwrap();
+    find_custom_installs_path(plugin_name, &install_dirs, template.as_deref())
+}
+
+fn find_custom_installs_path(
+    plugin_name: &str,
+    install_dirs: &HashMap<PluginName, PathBuf>,
+    template: Option<&str>,
+) -> Option<PathBuf> {
+    if let Some(dir) = install_dirs.get(plugin_name) {
+        return Some(dir.clone());
+    }
+    // the template always ends with /{version}, so its parent is where the versions are listed
+    let dir = template?.strip_suffix("/{version}")?;
+    Some(file::replace_path(Path::new(
+        &dir.replace("{plugin}", plugin_name),
+    )))
+}
+
+/// (installs dir, its mtime) and the versions found in it
+type InstalledVersions = ((PathBuf, SystemTime), Vec<String>);
+
 pub struct Tool {
     pub name: String,
     pub plugin: Box<dyn Plugin>,
-    pub installs_path: PathBuf,
     pub plugin_path: PathBuf,
+    /// installed versions keyed by the mtime of installs_path, cleared when a version is
+    /// (un)installed. Core plugins live for the whole process so the mtime catches changes made
+    /// by anything else.
+    installed_versions: Mutex<Option<InstalledVersions>>,
+    /// `list_bin_paths()` for each version this run, PATH, shims, and `which` all need them and
+    /// plugins may run a script to find them. Cleared along with `installed_versions`.
+    bin_paths: Mutex<HashMap<ToolVersion, Vec<PathBuf>>>,
 }
 
 impl Tool {
     pub fn new(name: String, plugin: Box<dyn Plugin>) -> Self {
         Self {
-            installs_path: dirs::INSTALLS.join(&name),
             plugin_path: dirs::PLUGINS.join(&name),
             name,
             plugin,
+            installed_versions: Mutex::new(None),
+            bin_paths: Mutex::new(HashMap::new()),
         }
     }
 
-    pub fn list() -> Result<Vec<Self>> {
+    /// the external plugins installed in `plugins_dir`
+    pub fn list_in(plugins_dir: &Path) -> Result<Vec<Self>> {
+        Ok(file::dir_subdirs(plugins_dir)?
+            .iter()
+            .filter_map(|name| Self::find_in(plugins_dir, name))
+            .collect())
+    }
+
+    /// the installed external plugins that can read legacy version files, only the ones with an
+    /// `rtx.plugin.toml` or a `bin/list-legacy-filenames` script are loaded to find out
+    pub fn list_with_legacy_files() -> Result<Vec<Self>> {
         Ok(file::dir_subdirs(&dirs::PLUGINS)?
             .iter()
-            .map(|name| {
-                let plugin = ExternalPlugin::new(name);
-                Self::new(name.to_string(), Box::new(plugin))
+            .filter(|name| {
+                let path = dirs::PLUGINS.join(name);
+                path.join("rtx.plugin.toml").is_file()
+                    || path.join("bin/list-legacy-filenames").is_file()
             })
+            .filter_map(|name| Self::find(name))
+            .filter(|t| t.supports(PluginCapability::LegacyFiles))
             .collect())
     }
 
+    /// loads a single installed external plugin without listing all of them
+    pub fn find_in(plugins_dir: &Path, name: &str) -> Option<Self> {
+        let plugin_path = plugins_dir.join(name);
+        if !plugin_path.is_dir() {
+            return None;
+        }
+        let plugin = ExternalPlugin::with_path(&name.to_string(), plugin_path);
+        Some(Self::new(name.to_string(), Box::new(plugin)))
+    }
+
+    pub fn installs_path(&self) -> PathBuf {
+        installs_path(&self.name)
+    }
+
+    /// the install directory set in the config, if any
+    pub fn custom_installs_path(&self) -> Option<PathBuf> {
+        custom_installs_path(&self.name)
+    }
+
     pub fn is_installed(&self) -> bool {
         self.plugin.is_installed()
     }
 
+    pub fn supports(&self, capability: PluginCapability) -> bool {
+        self.plugin.supports(capability)
+    }
+
+    /// why the plugin can't be used here, if it declares the platforms it works on and the
+    /// current one isn't among them
+    pub fn unsupported_platform(&self) -> Option<String> {
+        let platforms = self.plugin.supported_platforms()?;
+        if is_platform_supported(&platforms) {
+            return None;
+        }
+        Some(format!(
+            "{} does not support {}-{}, it only supports: {}",
+            self.name,
+            *OS,
+            *ARCH,
+            platforms.join(", ")
+        ))
+    }
+
     pub fn get_remote_url(&self) -> Option<String> {
         self.plugin.get_remote_url()
     }
@@ -63,11 +195,34 @@ impl Tool {
         self.plugin.current_abbrev_ref()
     }
 
+    /// installed versions sorted oldest to newest, skipping incomplete installs
     pub fn list_installed_versions(&self) -> Result<Vec<String>> {
-        Ok(match self.installs_path.exists() {
-            true => file::dir_subdirs(&self.installs_path)?
+        let installs_path = self.installs_path();
+        let Ok(mtime) = installs_path.metadata().and_then(|m| m.modified()) else {
+            return Ok(vec![]);
+        };
+        let key = (installs_path, mtime);
+        let mut cache = self.installed_versions.lock().unwrap();
+        match &*cache {
+            Some((cached_key, versions)) if *cached_key == key => Ok(versions.clone()),
+            _ => {
+                let versions = self.read_installed_versions(&key.0)?;
+                *cache = Some((key, versions.clone()));
+                Ok(versions)
+            }
+        }
+    }
+
+    fn clear_installed_versions(&self) {
+        *self.installed_versions.lock().unwrap() = None;
+        self.bin_paths.lock().unwrap().clear();
+    }
+
+    fn read_installed_versions(&self, installs_path: &Path) -> Result<Vec<String>> {
+        Ok(match installs_path.exists() {
+            true => file::dir_subdirs(installs_path)?
                 .iter()
-                .filter(|v| !is_runtime_symlink(&self.installs_path.join(v)))
+                .filter(|v| !is_runtime_symlink(&installs_path.join(v)))
                 // TODO: share logic with incomplete_file_path
                 .filter(|v| {
                     !dirs::CACHE
@@ -86,16 +241,80 @@ impl Tool {
 
     pub fn list_installed_versions_matching(&self, query: &str) -> Result<Vec<String>> {
         let versions = self.list_installed_versions()?;
-        Ok(self.fuzzy_match_filter(versions, query))
+        Ok(fuzzy_match_filter(versions, query).collect())
     }
 
     pub fn list_remote_versions(&self, settings: &Settings) -> Result<Vec<String>> {
-        self.plugin.list_remote_versions(settings)
+        match self.versions_snapshot()? {
+            Some(snapshot) => Ok(snapshot.versions),
+            None => self.plugin.list_remote_versions(settings),
+        }
+    }
+
+    /// `<project root>/.rtx/<name>/versions-snapshot.json`, none outside of a project
+    pub fn versions_snapshot_path(&self) -> Option<PathBuf> {
+        let dir = VERSIONS_SNAPSHOT_DIR.read().unwrap();
+        Some(
+            dir.as_ref()?
+                .join(".rtx")
+                .join(&self.name)
+                .join("versions-snapshot.json"),
+        )
+    }
+
+    fn versions_snapshot(&self) -> Result<Option<VersionsSnapshot>> {
+        let Some(path) = self.versions_snapshot_path().filter(|p| p.is_file()) else {
+            return Ok(None);
+        };
+        let snapshot = serde_json::from_str(&fs::read_to_string(&path)?)
+            .map_err(|err| eyre!("invalid versions snapshot {}: {err}", display_path(&path)))?;
+        Ok(Some(snapshot))
+    }
+
+    /// asks the plugin for its versions, ignoring any existing snapshot, and saves them to
+    /// `versions_snapshot_path()`
+    pub fn save_versions_snapshot(&self, settings: &Settings) -> Result<PathBuf> {
+        let path = self
+            .versions_snapshot_path()
+            .ok_or_else(|| eyre!("no project config file to save a versions snapshot next to"))?;
+        let latest_stable = match self.supports(PluginCapability::LatestStable) {
+            true => self.plugin.latest_stable_version(settings)?,
+            false => None,
+        };
+        let snapshot = VersionsSnapshot {
+            versions: self.plugin.list_remote_versions(settings)?,
+            latest_stable,
+        };
+        create_dir_all(path.parent().unwrap())?;
+        file::write_atomic(&path, serde_json::to_string_pretty(&snapshot)? + "\n")?;
+        Ok(path)
     }
 
     pub fn list_versions_matching(&self, settings: &Settings, query: &str) -> Result<Vec<String>> {
         let versions = self.list_remote_versions(settings)?;
-        Ok(self.fuzzy_match_filter(versions, query))
+        Ok(fuzzy_match_filter(versions, query).collect())
+    }
+
+    /// versions (installed or remote) starting with `prefix`, newest first
+    /// used for dynamic shell completion so remote errors are ignored
+    pub fn complete_versions(&self, settings: &Settings, prefix: &str) -> Vec<String> {
+        let installed = self.list_installed_versions().unwrap_or_default();
+        let remote = match self.is_installed() {
+            true => self.list_remote_versions(settings).unwrap_or_else(|err| {
+                debug!("error listing remote versions for {}: {:#}", self.name, err);
+                vec![]
+            }),
+            false => vec![],
+        };
+        installed
+            .into_iter()
+            .chain(remote)
+            .filter(|v| v.starts_with(prefix))
+            .unique()
+            .sorted_by_cached_key(|v| Versioning::new(v).unwrap_or_default())
+            .rev()
+            .take(MAX_COMPLETE_VERSIONS)
+            .collect()
     }
 
     pub fn latest_version(
@@ -119,7 +338,7 @@ impl Tool {
                 Ok(find_match_in_list(&matches, &query))
             }
             None => {
-                let installed_symlink = self.installs_path.join("latest");
+                let installed_symlink = self.installs_path().join("latest");
                 if installed_symlink.exists() {
                     let target = installed_symlink.read_link()?;
                     let version = target
@@ -135,20 +354,29 @@ impl Tool {
         }
     }
 
-    pub fn get_aliases(&self, settings: &Settings) -> Result<BTreeMap<String, String>> {
-        self.plugin.get_aliases(settings)
+    pub fn list_aliases(&self, settings: &Settings) -> Result<IndexMap<String, String>> {
+        self.plugin.list_aliases(settings)
     }
 
     pub fn legacy_filenames(&self, settings: &Settings) -> Result<Vec<String>> {
         self.plugin.legacy_filenames(settings)
     }
 
+    /// the plugin's bin/latest-stable knows its own rules for prereleases so it's preferred over
+    /// the highest version from bin/list-all
     fn latest_stable_version(&self, settings: &Settings) -> Result<Option<String>> {
-        if let Some(latest) = self.plugin.latest_stable_version(settings)? {
-            Ok(Some(latest))
-        } else {
-            self.latest_version(settings, Some("latest".into()))
+        if let Some(snapshot) = self.versions_snapshot()? {
+            if snapshot.latest_stable.is_some() {
+                return Ok(snapshot.latest_stable);
+            }
+        } else if self.supports(PluginCapability::LatestStable) {
+            if let Some(latest) = self.plugin.latest_stable_version(settings)? {
+                return Ok(Some(latest));
+            }
         }
+        let versions = self.list_remote_versions(settings)?;
+        Ok(fuzzy_match_filter(versions, "latest")
+            .max_by_key(|v| Versioning::new(v).unwrap_or_default()))
     }
 
     pub fn decorate_progress_bar(&self, pr: &mut ProgressReport, tv: Option<&ToolVersion>) {
@@ -194,8 +422,23 @@ impl Tool {
         }
     }
 
+    /// the commit sha `ref-<gitref>` is symlinked to, if it is installed
+    pub fn installed_ref_sha(&self, gitref: &str) -> Option<String> {
+        let target = self
+            .installs_path()
+            .join(format!("ref-{gitref}"))
+            .read_link()
+            .ok()?;
+        let target = target.file_name()?.to_str()?;
+        let sha = target.strip_prefix("ref-")?;
+        match self.installs_path().join(target).is_dir() {
+            true => Some(sha.to_string()),
+            false => None,
+        }
+    }
+
     pub fn create_symlink(&self, version: &str, target: &Path) -> Result<()> {
-        let link = self.installs_path.join(version);
+        let link = self.installs_path().join(version);
         fs::create_dir_all(link.parent().unwrap())?;
         file::make_symlink(target, &link)
     }
@@ -214,9 +457,24 @@ impl Tool {
                 return Ok(());
             }
         }
+        if matches!(tv.request, ToolVersionRequest::Ref(..))
+            && !self.supports(PluginCapability::Ref)
+        {
+            return Err(eyre!("{} does not support ref versions", self.name));
+        }
+        if config.settings.require_checksums && !self.plugin.verifies_checksums() {
+            return Err(InstallError::ChecksumUnsupported(self.name.clone()).into());
+        }
         self.decorate_progress_bar(pr, Some(tv));
+        let _plugin_lock = self.lock_installs(force)?;
         let _lock = self.get_lock(&tv.install_path(), force)?;
+        if let Some(hook) = config.plugin_hooks(&self.name).pre_install {
+            pr.set_message("pre_install");
+            self.run_hook(config, &hook, tv, pr)
+                .map_err(|err| err.wrap_err(format!("pre_install hook failed for {tv}")))?;
+        }
         self.create_install_dirs(tv)?;
+        self.clear_installed_versions();
 
         if let Err(e) = self.plugin.install_version(config, tv, pr) {
             self.cleanup_install_dirs_on_error(&config.settings, tv);
@@ -235,6 +493,14 @@ impl Tool {
         if let Err(err) = fs::remove_file(self.incomplete_file_path(tv)) {
             debug!("error removing incomplete file: {:?}", err);
         }
+        self.clear_installed_versions();
+        if let ToolVersionRequest::Ref(_, r) = &tv.request {
+            if tv.version != tv.request.version() && &tv.version != r {
+                // remember which commit the ref resolved to
+                let target = PathBuf::from(".").join(format!("ref-{}", tv.version));
+                self.create_symlink(&format!("ref-{r}"), &target)?;
+            }
+        }
         pr.set_message("");
         pr.finish();
 
@@ -266,9 +532,36 @@ impl Tool {
         rmdir(&tv.install_path())?;
         rmdir(&tv.download_path())?;
         rmdir(&tv.cache_path())?;
+        self.clear_installed_versions();
+        let post_uninstall = config.plugin_hooks(&self.name).post_uninstall;
+        if let Some(hook) = post_uninstall.filter(|_| !dryrun) {
+            pr.set_message("post_uninstall");
+            if let Err(err) = self.run_hook(config, &hook, tv, pr) {
+                warn!("post_uninstall hook failed for {tv}: {err:#}");
+            }
+        }
         Ok(())
     }
 
+    /// runs a `[plugins.<name>] pre_install` or `post_uninstall` command with `sh -c`, it gets
+    /// rtx's env plus RTX_PLUGIN_NAME, RTX_INSTALL_VERSION, and RTX_INSTALL_PATH
+    fn run_hook(
+        &self,
+        config: &Config,
+        hook: &str,
+        tv: &ToolVersion,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        CmdLineRunner::new(&config.settings, "sh")
+            .arg("-c")
+            .arg(hook)
+            .with_pr(pr)
+            .env("RTX_PLUGIN_NAME", &self.name)
+            .env("RTX_INSTALL_VERSION", &tv.version)
+            .env("RTX_INSTALL_PATH", tv.install_path())
+            .execute()
+    }
+
     pub fn install(&self, config: &Config, pr: &mut ProgressReport, force: bool) -> Result<()> {
         if matches!(self.plugin.get_type(), PluginType::Core) {
             return Ok(());
@@ -298,11 +591,21 @@ impl Tool {
     pub fn parse_legacy_file(&self, path: &Path, settings: &Settings) -> Result<String> {
         self.plugin.parse_legacy_file(path, settings)
     }
+    /// the directories with the bins of an installed version, `bin` unless the plugin says
+    /// otherwise, e.g.: with a `list-bin-paths` script
     pub fn list_bin_paths(&self, config: &Config, tv: &ToolVersion) -> Result<Vec<PathBuf>> {
-        match tv.request {
-            ToolVersionRequest::System(_) => Ok(vec![]),
-            _ => self.plugin.list_bin_paths(config, tv),
+        if let ToolVersionRequest::System(_) = tv.request {
+            return Ok(vec![]);
+        }
+        if let Some(bin_paths) = self.bin_paths.lock().unwrap().get(tv) {
+            return Ok(bin_paths.clone());
         }
+        let bin_paths = self.plugin.list_bin_paths(config, tv)?;
+        self.bin_paths
+            .lock()
+            .unwrap()
+            .insert(tv.clone(), bin_paths.clone());
+        Ok(bin_paths)
     }
     pub fn exec_env(&self, config: &Config, tv: &ToolVersion) -> Result<HashMap<String, String>> {
         match tv.request {
@@ -317,7 +620,7 @@ impl Tool {
         tv: &ToolVersion,
         bin_name: &str,
     ) -> Result<Option<PathBuf>> {
-        let bin_paths = self.plugin.list_bin_paths(config, tv)?;
+        let bin_paths = self.list_bin_paths(config, tv)?;
         for bin_path in bin_paths {
             let bin_path = bin_path.join(bin_name);
             if bin_path.exists() {
@@ -354,6 +657,13 @@ impl Tool {
         }
     }
 
+    /// versions of the same plugin are installed one at a time, even from different processes,
+    /// since plugins may share state between versions like a download cache. Keyed by the
+    /// installs dir since core plugins have no plugin dir.
+    fn lock_installs(&self, force: bool) -> Result<Option<fslock::LockFile>> {
+        self.get_lock(&self.installs_path(), force)
+    }
+
     fn get_lock(&self, path: &Path, force: bool) -> Result<Option<fslock::LockFile>> {
         let lock = if force {
             None
@@ -367,30 +677,14 @@ impl Tool {
         };
         Ok(lock)
     }
+}
 
-    fn fuzzy_match_filter(&self, versions: Vec<String>, query: &str) -> Vec<String> {
-        let mut query = query;
-        if query == "latest" {
-            query = "[0-9].*";
-        }
-        let query_regex =
-            Regex::new(&format!("^{}([-.].+)?$", query)).expect("error parsing regex");
-        let version_regex = regex!(
-            r"(^Available versions:|-src|-dev|-latest|-stm|[-\\.]rc|-milestone|-alpha|-beta|[-\\.]pre|-next|(a|b|c)[0-9]+|snapshot|master)"
-        );
-        versions
-            .into_iter()
-            .filter(|v| {
-                if query == v {
-                    return true;
-                }
-                if version_regex.is_match(v) {
-                    return false;
-                }
-                query_regex.is_match(v)
-            })
-            .collect()
-    }
+/// versions like 3.12.0rc1, 21.0.0-beta, or dev builds that `latest` skips
+pub fn is_prerelease(version: &str) -> bool {
+    let re = regex!(
+        r"(-src|-dev|-latest|-stm|[-\\.]rc|-milestone|-alpha|-beta|[-\\.]pre|-next|(a|b|c)[0-9]+|snapshot|master)"
+    );
+    re.is_match(version)
 }
 
 impl PartialEq for Tool {
@@ -403,12 +697,32 @@ impl Debug for Tool {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Tool")
             .field("name", &self.name)
-            .field("installs_path", &self.installs_path)
+            .field("installs_path", &self.installs_path())
             .field("plugin", &self.plugin)
             .finish()
     }
 }
 
+fn fuzzy_match_filter<'a>(
+    versions: impl IntoIterator<Item = String> + 'a,
+    query: &str,
+) -> impl Iterator<Item = String> + 'a {
+    let query = match query {
+        "latest" => "[0-9].*".to_string(),
+        _ => query.to_string(),
+    };
+    let query_regex = Regex::new(&format!("^{}([-.].+)?$", query)).expect("error parsing regex");
+    versions.into_iter().filter(move |v| {
+        if &query == v {
+            return true;
+        }
+        if v.starts_with("Available versions:") || is_prerelease(v) {
+            return false;
+        }
+        query_regex.is_match(v)
+    })
+}
+
 fn find_match_in_list(list: &[String], query: &str) -> Option<String> {
     let v = match list.contains(&query.to_string()) {
         true => Some(query.to_string()),
@@ -419,7 +733,13 @@ fn find_match_in_list(list: &[String], query: &str) -> Option<String> {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::thread;
+    use std::time::Duration;
+
     use super::*;
+    use crate::config::PluginHooks;
+    use crate::plugins::core::CORE_PLUGINS;
     use crate::plugins::PluginName;
 
     #[test]
@@ -432,4 +752,225 @@ mod tests {
         assert!(debug.contains("installs_path"));
         assert!(debug.contains("plugin"));
     }
+
+    #[test]
+    fn test_list_bin_paths() {
+        let config = Config::load().unwrap();
+        let tool = Tool::find_in(&dirs::PLUGINS, "tiny").unwrap();
+        let tv = ToolVersion::new(
+            &tool,
+            ToolVersionRequest::new("tiny".into(), "3.1.0"),
+            Default::default(),
+            "3.1.0".into(),
+        );
+        let bin_paths = tool.list_bin_paths(&config, &tv).unwrap();
+        assert_eq!(bin_paths, vec![tv.install_path().join("bin")]);
+        assert!(tool.bin_paths.lock().unwrap().contains_key(&tv));
+
+        let system = ToolVersionRequest::new("tiny".into(), "system");
+        let tv = ToolVersion::new(&tool, system, Default::default(), "system".into());
+        assert!(tool.list_bin_paths(&config, &tv).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_lock_installs() {
+        let tool = Tool::find_in(&dirs::PLUGINS, "tiny").unwrap();
+        let installing = AtomicBool::new(false);
+        let lock = tool.lock_installs(false).unwrap();
+        thread::scope(|s| {
+            let other = s.spawn(|| {
+                let _lock = tool.lock_installs(false).unwrap();
+                installing.store(true, Ordering::SeqCst);
+            });
+            thread::sleep(Duration::from_millis(100));
+            assert!(!installing.load(Ordering::SeqCst));
+            drop(lock);
+            other.join().unwrap();
+        });
+        assert!(installing.load(Ordering::SeqCst));
+
+        // other plugins aren't blocked
+        let _lock = tool.lock_installs(false).unwrap();
+        let dummy = Tool::find_in(&dirs::PLUGINS, "dummy").unwrap();
+        assert!(dummy.lock_installs(false).unwrap().is_some());
+
+        let go = CORE_PLUGINS.get("go").unwrap();
+        assert!(go.lock_installs(false).unwrap().is_some());
+        assert!(!go.plugin_path.exists());
+    }
+
+    #[test]
+    fn test_install_version_require_checksums() {
+        let mut config = Config::load().unwrap();
+        config.settings.require_checksums = true;
+        let tool = Tool::find_in(&dirs::PLUGINS, "tiny").unwrap();
+        let tvr = ToolVersionRequest::new("tiny".into(), "9.9.9");
+        let tv = ToolVersion::new(&tool, tvr, Default::default(), "9.9.9".into());
+        let mut pr = ProgressReport::new(false);
+        let err = tool
+            .install_version(&config, &tv, &mut pr, false)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "tiny does not verify checksums, it can't be installed with the require_checksums setting"
+        );
+        assert!(!tv.install_path().exists());
+    }
+
+    #[test]
+    fn test_install_version_pre_install_fails() {
+        let mut config = Config::load().unwrap();
+        let hooks = PluginHooks {
+            pre_install: Some("exit 1".into()),
+            ..PluginHooks::default()
+        };
+        config.set_plugin_hooks("tiny", hooks);
+        let tool = Tool::find_in(&dirs::PLUGINS, "tiny").unwrap();
+        let tvr = ToolVersionRequest::new("tiny".into(), "9.9.8");
+        let tv = ToolVersion::new(&tool, tvr, Default::default(), "9.9.8".into());
+        let mut pr = ProgressReport::new(false);
+        let err = tool
+            .install_version(&config, &tv, &mut pr, false)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "pre_install hook failed for tiny@9.9.8");
+        // aborted before create_install_dirs()
+        assert!(!tv.install_path().exists());
+        assert!(!tv.download_path().exists());
+    }
+
+    #[test]
+    fn test_uninstall_version_post_uninstall_fails() {
+        let mut config = Config::load().unwrap();
+        let marker = dirs::CACHE.join("test-post-uninstall");
+        let hooks = PluginHooks {
+            post_uninstall: Some(format!(
+                "echo $RTX_INSTALL_VERSION > {}; exit 1",
+                marker.display()
+            )),
+            ..PluginHooks::default()
+        };
+        config.set_plugin_hooks("tiny", hooks);
+        let tool = Tool::find_in(&dirs::PLUGINS, "tiny").unwrap();
+        let tvr = ToolVersionRequest::new("tiny".into(), "9.9.7");
+        let tv = ToolVersion::new(&tool, tvr, Default::default(), "9.9.7".into());
+        create_dir_all(tv.install_path()).unwrap();
+        let pr = ProgressReport::new(false);
+        tool.uninstall_version(&config, &tv, &pr, false).unwrap();
+        assert!(!tv.install_path().exists());
+        assert_eq!(fs::read_to_string(&marker).unwrap(), "9.9.7\n");
+        fs::remove_file(&marker).unwrap();
+    }
+
+    #[test]
+    fn test_latest_without_latest_stable() {
+        let tool = Tool::find_in(&dirs::PLUGINS, "tiny").unwrap();
+        assert!(!tool.supports(PluginCapability::LatestStable));
+        let latest = tool.latest_version(&Settings::default(), None).unwrap();
+        assert_eq!(latest, Some("3.1.0".into()));
+    }
+
+    #[test]
+    fn test_find() {
+        let tool = Tool::find_in(&dirs::PLUGINS, "tiny").unwrap();
+        assert_eq!(tool.name, "tiny");
+        assert!(Tool::find_in(&dirs::PLUGINS, "not-a-plugin").is_none());
+    }
+
+    #[test]
+    fn test_list_installed_versions() {
+        // a plugin of its own so other tests never see the versions it creates
+        let name = PluginName::from("rtx-test-installed-versions");
+        let tool = Tool::new(name.clone(), Box::new(ExternalPlugin::new(&name)));
+        let _ = file::remove_all(tool.installs_path());
+        assert!(tool.list_installed_versions().unwrap().is_empty());
+        for v in ["1.0.1", "2.1.0", "10.0.0"] {
+            file::create_dir_all(tool.installs_path().join(v)).unwrap();
+        }
+        let versions = tool.list_installed_versions().unwrap();
+        assert_eq!(versions, vec!["1.0.1", "2.1.0", "10.0.0"]);
+
+        let installing = tool.installs_path().join("11.0.0");
+        let incomplete = dirs::CACHE.join(&name).join("11.0.0").join("incomplete");
+        file::create_dir_all(&installing).unwrap();
+        file::create_dir_all(incomplete.parent().unwrap()).unwrap();
+        fs::write(&incomplete, "").unwrap();
+        // cached until a version is installed or uninstalled
+        assert_eq!(tool.list_installed_versions().unwrap(), versions);
+        tool.clear_installed_versions();
+        assert_eq!(tool.list_installed_versions().unwrap(), versions);
+
+        fs::remove_file(&incomplete).unwrap();
+        tool.clear_installed_versions();
+        let mut expected = versions.clone();
+        expected.push("11.0.0".into());
+        assert_eq!(tool.list_installed_versions().unwrap(), expected);
+        file::remove_all(tool.installs_path()).unwrap();
+        file::remove_all(dirs::CACHE.join(&name)).unwrap();
+    }
+
+    #[test]
+    fn test_installs_path() {
+        let tool = Tool::find_in(&dirs::PLUGINS, "tiny").unwrap();
+        assert_eq!(tool.installs_path(), dirs::INSTALLS.join("tiny"));
+        assert_eq!(tool.custom_installs_path(), None);
+
+        let find = |install_dirs: &HashMap<PluginName, PathBuf>, template: Option<&str>| {
+            find_custom_installs_path("tiny", install_dirs, template)
+        };
+        let dir = dirs::HOME.join("sdks/tiny");
+        let install_dirs = [("tiny".to_string(), dir.clone())].into();
+        assert_eq!(find(&install_dirs, None), Some(dir));
+        assert_eq!(find(&HashMap::new(), None), None);
+        let install_dirs = [("dummy".to_string(), dirs::HOME.join("dummy"))].into();
+        assert_eq!(find(&install_dirs, None), None);
+
+        let template = Some("~/sdks/{plugin}/{version}");
+        assert_eq!(
+            find(&install_dirs, template),
+            Some(dirs::HOME.join("sdks/tiny"))
+        );
+        let install_dirs = [("tiny".to_string(), dirs::HOME.join("tiny"))].into();
+        assert_eq!(find(&install_dirs, template), Some(dirs::HOME.join("tiny")));
+        assert_eq!(find(&HashMap::new(), Some("~/sdks/{plugin}")), None);
+    }
+
+    #[test]
+    fn test_complete_versions() {
+        let plugin = ExternalPlugin::new(&PluginName::from("dummy"));
+        let tool = Tool::new("dummy".to_string(), Box::new(plugin));
+        let settings = Settings::default();
+        assert_eq!(tool.complete_versions(&settings, "1.1"), vec!["1.1.0"]);
+        assert_eq!(tool.complete_versions(&settings, "2")[0], "2.0.0");
+        assert!(tool.complete_versions(&settings, "9").is_empty());
+    }
+
+    #[test]
+    fn test_versions_snapshot() {
+        let config = Config::load().unwrap();
+        // a plugin that isn't installed so nothing but the snapshot can list its versions
+        let name = PluginName::from("snapshot-dummy");
+        let tool = Tool::new(name.clone(), Box::new(ExternalPlugin::new(&name)));
+        let path = tool.versions_snapshot_path().unwrap();
+
+        let snapshot = VersionsSnapshot {
+            versions: vec!["1.0.0".into(), "1.1.0".into(), "2.0.0-rc1".into()],
+            latest_stable: None,
+        };
+        create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, serde_json::to_string(&snapshot).unwrap()).unwrap();
+        let latest = |query: Option<&str>| {
+            let query = query.map(String::from);
+            tool.latest_version(&config.settings, query).unwrap()
+        };
+        assert_eq!(latest(None), Some("1.1.0".into()));
+        assert_eq!(latest(Some("1.0")), Some("1.0.0".into()));
+
+        let snapshot = VersionsSnapshot {
+            latest_stable: Some("1.0.0".into()),
+            ..snapshot
+        };
+        fs::write(&path, serde_json::to_string(&snapshot).unwrap()).unwrap();
+        assert_eq!(latest(None), Some("1.0.0".into()));
+        remove_all_with_warning(path.parent().unwrap()).unwrap();
+    }
 }
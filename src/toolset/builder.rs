@@ -1,11 +1,19 @@
 use color_eyre::eyre::Result;
 use itertools::Itertools;
 use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 use crate::cli::args::tool::ToolArg;
-use crate::config::Config;
+use crate::config::config_file::legacy_version::LegacyVersionFile;
+use crate::config::config_file::tool_versions::ToolVersions;
+use crate::config::config_file::{self, ConfigFile, ConfigFileType};
+use crate::config::{Config, ConfigMap};
 use crate::env;
-use crate::toolset::{ToolSource, ToolVersionRequest, Toolset};
+use crate::file::display_path;
+use crate::git::Git;
+use crate::hash::hash_to_str;
+use crate::plugins::unalias_plugin;
+use crate::toolset::{ResolvePolicy, ToolSource, ToolSourceKind, ToolVersionRequest, Toolset};
 use crate::ui::multi_progress_report::MultiProgressReport;
 
 #[derive(Debug, Default)]
@@ -14,6 +22,11 @@ pub struct ToolsetBuilder {
     install_missing: bool,
     latest_versions: bool,
     tool_filter: Option<Vec<String>>,
+    git_ref: Option<String>,
+    resolve_policy: ResolvePolicy,
+    tool_versions: Option<(String, ToolSource)>,
+    path: Option<PathBuf>,
+    fail_fast: bool,
 }
 
 impl ToolsetBuilder {
@@ -36,28 +49,96 @@ impl ToolsetBuilder {
         self
     }
 
+    /// installs stop at the first tool that fails instead of installing the rest, see
+    /// `Toolset::install_versions()`
+    pub fn with_fail_fast(mut self, fail_fast: bool) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    /// reads the config files as they were at a git ref instead of from the working tree
+    pub fn with_git_ref(mut self, git_ref: &str) -> Self {
+        self.git_ref = Some(git_ref.to_string());
+        self
+    }
+
+    pub fn with_resolve_policy(mut self, resolve_policy: ResolvePolicy) -> Self {
+        self.resolve_policy = resolve_policy;
+        self
+    }
+
+    /// versions from `.tool-versions` contents that aren't in a file, e.g.: piped to
+    /// `rtx install -`. They override the config files and env vars like args do.
+    pub fn with_tool_versions(mut self, contents: &str, source: ToolSource) -> Self {
+        self.tool_versions = Some((contents.to_string(), source));
+        self
+    }
+
+    /// uses the config files for a file or directory instead of the cwd, see
+    /// `Config::config_files_for_path()`
+    pub fn with_path(mut self, path: &Path) -> Self {
+        self.path = Some(path.to_path_buf());
+        self
+    }
+
     pub fn with_tools(mut self, tools: &[&str]) -> Self {
         self.tool_filter = Some(tools.iter().map(|s| s.to_string()).collect());
         self
     }
 
+    /// the sources are merged in reverse `source_precedence` order so the first kind wins
     pub fn build(self, config: &mut Config) -> Result<Toolset> {
+        let precedence = config.settings.source_precedence()?;
+        let mut disable_tools = config.settings.disable_tools.clone();
+        disable_tools.extend(config.disabled_plugins.keys().cloned());
         let mut toolset = Toolset {
             latest_versions: self.latest_versions,
-            disable_tools: config.settings.disable_tools.clone(),
+            resolve_policy: self.resolve_policy,
+            disable_tools,
+            fail_fast: self.fail_fast,
             ..Default::default()
         };
-        load_config_files(config, &mut toolset);
-        load_runtime_env(&mut toolset, env::vars().collect());
-        load_runtime_args(&mut toolset, &self.args);
+        for kind in precedence.iter().rev() {
+            match kind {
+                ToolSourceKind::Config => match (&self.git_ref, &self.path) {
+                    (Some(git_ref), _) => load_config_files_at_ref(config, &mut toolset, git_ref)?,
+                    (None, Some(path)) => {
+                        let config_files = config.config_files_for_path(path)?;
+                        load_config_files(config, &config_files, &mut toolset)
+                    }
+                    (None, None) => load_config_files(config, &config.config_files, &mut toolset),
+                },
+                ToolSourceKind::Env => {
+                    load_runtime_env(config, &mut toolset, env::vars().collect())?
+                }
+                ToolSourceKind::Stdin => {
+                    if let Some((contents, source)) = &self.tool_versions {
+                        let tv_ts = ToolVersions::parse_toolset(contents, source.clone())?;
+                        warn_disabled(config, &tv_ts);
+                        toolset.merge(&tv_ts);
+                    }
+                }
+                ToolSourceKind::Arg => load_runtime_args(config, &mut toolset, &self.args),
+            }
+        }
         if let Some(tools) = self.tool_filter {
             toolset.versions.retain(|p, _| tools.contains(p));
         }
-        toolset.resolve(config);
+        match (&self.git_ref, &self.path) {
+            (None, Some(path)) => config.with_aliases_for_path(path, |c| toolset.resolve(c))?,
+            _ => toolset.resolve(config),
+        }
+        config.check_strict()?;
 
         if self.install_missing {
             let mpr = MultiProgressReport::new(config.show_progress_bars());
-            toolset.install_missing(config, mpr)?;
+            let installed = toolset.install_missing(config, mpr, None)?;
+            if !installed.is_empty() {
+                let installed = installed
+                    .iter()
+                    .map(|(p, tv)| format!("{} {}", p, tv.version));
+                debug!("installed: {}", installed.collect_vec().join(", "));
+            }
         }
 
         debug!("{}", toolset);
@@ -65,39 +146,360 @@ impl ToolsetBuilder {
     }
 }
 
-fn load_config_files(config: &Config, ts: &mut Toolset) {
-    for cf in config.config_files.values().rev() {
+fn load_config_files(config: &Config, config_files: &ConfigMap, ts: &mut Toolset) {
+    for cf in config_files.values().rev() {
+        warn_disabled(config, cf.to_toolset());
         ts.merge(cf.to_toolset());
     }
 }
 
-fn load_runtime_env(ts: &mut Toolset, env: BTreeMap<String, String>) {
-    for (k, v) in env {
-        if k.starts_with("RTX_") && k.ends_with("_VERSION") && k != "RTX_VERSION" {
-            let plugin_name = k[4..k.len() - 8].to_lowercase();
-            if plugin_name == "install" {
-                // ignore RTX_INSTALL_VERSION
-                continue;
+/// config files in a git work tree are read as they were at `git_ref` and left out if they didn't
+/// exist then, the others (like the global config) are read as they are now. Only the config files
+/// found in the working tree are read, a file that was removed since `git_ref` isn't. Legacy
+/// version files are parsed by the plugins that read them now.
+fn load_config_files_at_ref(config: &mut Config, ts: &mut Toolset, git_ref: &str) -> Result<()> {
+    let mut config_files = vec![];
+    for (path, cf) in config.config_files.iter().rev() {
+        let git = Git::new(path.parent().unwrap().to_path_buf());
+        if !git.is_inside_work_tree() {
+            config_files.push(cf.to_toolset().clone());
+            continue;
+        }
+        let cf = match cf.get_type() {
+            ConfigFileType::LegacyVersion => {
+                let tools = cf.to_toolset().versions.keys();
+                let tools = tools.filter_map(|p| config.tools.get(p)).collect_vec();
+                git.show_file(git_ref, path)?
+                    .map(|body| {
+                        LegacyVersionFile::parse_str(&config.settings, path.clone(), &body, &tools)
+                    })
+                    .transpose()?
+                    .map(|cf| Box::new(cf) as Box<dyn ConfigFile>)
             }
-            let source = ToolSource::Environment(k, v.clone());
+            _ => config_file::parse_at_ref(&config.settings, path, git_ref)?,
+        };
+        match cf {
+            Some(cf) => config_files.push(cf.to_toolset().clone()),
+            None => debug!("{} did not exist at {git_ref}", display_path(path)),
+        }
+    }
+    for cf_ts in config_files {
+        warn_disabled(config, &cf_ts);
+        for plugin_name in cf_ts.versions.keys() {
+            if !config.get_or_create_tool(plugin_name).is_installed() {
+                config.warnings.push(
+                    cf_ts
+                        .source
+                        .as_ref()
+                        .map(|s| s.to_string())
+                        .unwrap_or_default(),
+                    format!("{plugin_name} is not installed"),
+                );
+            }
+        }
+        ts.merge(&cf_ts);
+    }
+    Ok(())
+}
+
+/// versions requested for a plugin that `disable_plugins` turned off
+fn warn_disabled(config: &Config, ts: &Toolset) {
+    for (plugin_name, tvl) in &ts.versions {
+        let Some(path) = config.disabled_plugins.get(plugin_name) else {
+            continue;
+        };
+        for (tvr, _) in &tvl.requests {
+            config.warnings.push(
+                tvl.source.to_string(),
+                format!(
+                    "ignoring {tvr}, {plugin_name} is disabled by {}",
+                    display_path(path)
+                ),
+            );
+        }
+    }
+}
+
+/// the contents of a `.tool-versions` file, for setups configured only with env vars
+const TOOL_VERSIONS_ENV_VAR: &str = "RTX_TOOL_VERSIONS";
+
+/// RTX_<PLUGIN>_VERSION, other than RTX_VERSION and RTX_INSTALL_VERSION
+fn is_runtime_env_var(k: &str) -> bool {
+    let k = env::normalize_key(k);
+    k.starts_with("RTX_")
+        && k.ends_with("_VERSION")
+        && k != "RTX_VERSION"
+        && !k[4..k.len() - 8].eq_ignore_ascii_case("install")
+}
+
+/// a hash of the vars `load_runtime_env` reads versions from, it changes when one of them is
+/// added, removed, or changed
+pub fn env_fingerprint<I: IntoIterator<Item = (String, String)>>(env: I) -> String {
+    let vars: BTreeMap<_, _> = env
+        .into_iter()
+        .filter(|(k, _)| is_runtime_env_var(k) || env::normalize_key(k) == TOOL_VERSIONS_ENV_VAR)
+        .collect();
+    hash_to_str(&vars)
+}
+
+/// RTX_TOOL_VERSIONS overrides the config files and RTX_<PLUGIN>_VERSION overrides both
+fn load_runtime_env(
+    config: &Config,
+    ts: &mut Toolset,
+    env: BTreeMap<String, String>,
+) -> Result<()> {
+    let tool_versions = env
+        .iter()
+        .find(|(k, _)| env::normalize_key(k) == TOOL_VERSIONS_ENV_VAR);
+    if let Some((_, v)) = tool_versions {
+        // the value is a whole .tool-versions file, too much to show as the source
+        let source = ToolSource::Environment(TOOL_VERSIONS_ENV_VAR.into(), None);
+        let env_ts = ToolVersions::parse_toolset(v, source)?;
+        warn_disabled(config, &env_ts);
+        ts.merge(&env_ts);
+    }
+    for (k, v) in env {
+        if is_runtime_env_var(&k) {
+            let key = env::normalize_key(&k);
+            let plugin_name = unalias_plugin(&key[4..key.len() - 8].to_lowercase());
+            let source = ToolSource::Environment(k, Some(v.clone()));
             let mut env_ts = Toolset::new(source);
             for v in v.split_whitespace() {
                 let tvr = ToolVersionRequest::new(plugin_name.clone(), v);
                 env_ts.add_version(tvr, Default::default());
             }
+            warn_disabled(config, &env_ts);
             ts.merge(&env_ts);
         }
     }
+    Ok(())
 }
 
-fn load_runtime_args(ts: &mut Toolset, args: &[ToolArg]) {
-    for (_, args) in args.iter().into_group_map_by(|arg| arg.plugin.clone()) {
+/// `plugin@version` args replace the versions from config for that plugin, a bare `plugin` only
+/// selects it: it keeps the version from config or falls back to latest if there isn't one
+fn load_runtime_args(config: &Config, ts: &mut Toolset, args: &[ToolArg]) {
+    for plugin in args.iter().map(|arg| &arg.plugin).unique() {
         let mut arg_ts = Toolset::new(ToolSource::Argument);
-        for arg in args {
+        for arg in args.iter().filter(|arg| &arg.plugin == plugin) {
             if let Some(tvr) = &arg.tvr {
                 arg_ts.add_version(tvr.clone(), Default::default());
             }
         }
+        if arg_ts.versions.is_empty() && !ts.versions.contains_key(plugin) {
+            let tvr = ToolVersionRequest::new(plugin.clone(), "latest");
+            arg_ts.add_version(tvr, Default::default());
+        }
+        warn_disabled(config, &arg_ts);
         ts.merge(&arg_ts);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use insta::assert_display_snapshot;
+
+    use crate::cmd;
+    use crate::config::default_tool_versions_filename;
+
+    use super::*;
+
+    #[test]
+    fn test_with_path() {
+        let mut config = Config::load().unwrap();
+        let dir = std::env::temp_dir().join("rtx-test-with-path");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        let tool_versions = dir.join(default_tool_versions_filename());
+        std::fs::write(&tool_versions, "tiny 2.0.0\n").unwrap();
+        let file = dir.join("src").join("main.js");
+        let tiny_version = |config: &mut Config| {
+            let ts = ToolsetBuilder::new()
+                .with_path(&file)
+                .build(config)
+                .unwrap();
+            let tvl = &ts.versions["tiny"];
+            (tvl.source.to_string(), tvl.versions[0].version.clone())
+        };
+        let source = display_path(&tool_versions);
+        assert_eq!(tiny_version(&mut config), (source.clone(), "2.0.0".into()));
+
+        // cached until a config file changes
+        let cached = config.config_files_for_path(&file).unwrap();
+        assert!(Arc::ptr_eq(
+            &cached,
+            &config.config_files_for_path(&file).unwrap()
+        ));
+        std::fs::write(&tool_versions, "tiny 1.0.1\n").unwrap();
+        filetime::set_file_mtime(&tool_versions, filetime::FileTime::from_unix_time(0, 0)).unwrap();
+        assert_eq!(tiny_version(&mut config), (source, "1.0.1".into()));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_with_path_aliases() {
+        let mut config = Config::load().unwrap();
+        let dir = std::env::temp_dir().join("rtx-test-with-path-aliases");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        // the alias is used before the table that defines it
+        std::fs::write(
+            dir.join(".test.rtx.toml"),
+            "[tools]\ntiny = \"myalias\"\n\n[alias.tiny]\nmyalias = \"3.1.0\"\n",
+        )
+        .unwrap();
+        let ts = ToolsetBuilder::new()
+            .with_path(&dir)
+            .build(&mut config)
+            .unwrap();
+        let tv = &ts.versions["tiny"].versions[0];
+        assert_eq!(tv.request.version(), "myalias");
+        assert_eq!(tv.version, "3.1.0");
+        assert!(!config.aliases["tiny"].contains_key("myalias"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_env_fingerprint() {
+        let fingerprint = |vars: &[(&str, &str)]| {
+            env_fingerprint(vars.iter().map(|(k, v)| (k.to_string(), v.to_string())))
+        };
+        let base = fingerprint(&[("RTX_NODE_VERSION", "20"), ("HOME", "/home")]);
+        assert_eq!(base, fingerprint(&[("RTX_NODE_VERSION", "20")]));
+        assert_eq!(
+            base,
+            fingerprint(&[
+                ("RTX_NODE_VERSION", "20"),
+                ("RTX_VERSION", "1"),
+                ("RTX_INSTALL_VERSION", "1"),
+            ])
+        );
+        assert_ne!(base, fingerprint(&[("RTX_NODE_VERSION", "18")]));
+        assert_ne!(base, fingerprint(&[]));
+        assert_ne!(
+            base,
+            fingerprint(&[("RTX_NODE_VERSION", "20"), ("RTX_TINY_VERSION", "3")])
+        );
+    }
+
+    #[test]
+    fn test_load_runtime_env() {
+        let config = Config::load().unwrap();
+        let mut ts = Toolset::default();
+        load_config_files(&config, &config.config_files, &mut ts);
+        let env = BTreeMap::from([
+            (
+                "RTX_TOOL_VERSIONS".to_string(),
+                "# pinned in the image\ntiny 2.1.0\nnodejs 20 18 # lts\n\ndummy 1.0.0".to_string(),
+            ),
+            ("RTX_DUMMY_VERSION".to_string(), "1.1.0".to_string()),
+        ]);
+        load_runtime_env(&config, &mut ts, env).unwrap();
+        assert_display_snapshot!(ts, @"dummy@1.1.0, tiny@2.1.0, node@20 node@18");
+        // the contents aren't shown even in verbose output
+        assert_eq!(
+            format!("{:#}", ts.versions["tiny"].source),
+            "RTX_TOOL_VERSIONS"
+        );
+
+        let env = BTreeMap::from([(
+            "RTX_TOOL_VERSIONS".to_string(),
+            "tiny 2.1.0\nnode".to_string(),
+        )]);
+        let err = load_runtime_env(&config, &mut ts, env).unwrap_err();
+        assert_display_snapshot!(err, @"invalid RTX_TOOL_VERSIONS: no version for node");
+    }
+
+    #[test]
+    fn test_with_tool_versions() {
+        let mut config = Config::load().unwrap();
+        let ts = ToolsetBuilder::new()
+            .with_tool_versions("# piped\ntiny 2.1.0\n", ToolSource::Stdin)
+            .build(&mut config)
+            .unwrap();
+        let tvl = &ts.versions["tiny"];
+        assert_eq!(tvl.source.to_string(), "stdin");
+        assert_display_snapshot!(tvl.versions[0], @"tiny@2.1.0");
+
+        let err = ToolsetBuilder::new()
+            .with_tool_versions("tiny 2.1.0\ndummy # todo", ToolSource::Stdin)
+            .build(&mut config)
+            .unwrap_err();
+        assert_display_snapshot!(err, @"invalid stdin: no version for dummy");
+    }
+
+    #[test]
+    fn test_load_runtime_args() {
+        let config = Config::load().unwrap();
+        let mut ts = Toolset::default();
+        load_config_files(&config, &config.config_files, &mut ts);
+        let args = ["tiny", "dummy@1.0.0", "newtool"].map(ToolArg::parse);
+        load_runtime_args(&config, &mut ts, &args);
+        assert_display_snapshot!(ts, @"newtool@latest, dummy@1.0.0, tiny@3");
+    }
+
+    #[test]
+    fn test_source_precedence() {
+        let mut config = Config::load().unwrap();
+        let tiny = |config: &mut Config| {
+            let ts = ToolsetBuilder::new()
+                .with_args(&[ToolArg::parse("tiny@2.1.0")])
+                .build(config)
+                .unwrap();
+            ts.versions["tiny"].requests[0].0.version()
+        };
+        assert_eq!(tiny(&mut config), "2.1.0");
+        config.settings.source_precedence =
+            ["config", "arg", "stdin", "env"].map(String::from).to_vec();
+        assert_eq!(tiny(&mut config), "3");
+    }
+
+    #[test]
+    fn test_git_ref() {
+        let mut config = Config::load().unwrap();
+        let dir = std::env::temp_dir().join("rtx-test-git-ref");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let tool_versions = dir.join(default_tool_versions_filename());
+        let legacy_file = dir.join(".tiny-version");
+        std::fs::write(&tool_versions, "tiny 2.1.0\n").unwrap();
+        std::fs::write(&legacy_file, "2.0.0\n").unwrap();
+        cmd!("git", "-C", &dir, "init", "-q").run().unwrap();
+        cmd!("git", "-C", &dir, "add", ".").run().unwrap();
+        cmd!(
+            "git",
+            "-C",
+            &dir,
+            "-c",
+            "user.name=rtx",
+            "-c",
+            "user.email=rtx@example.com",
+            "commit",
+            "-qm",
+            "init"
+        )
+        .run()
+        .unwrap();
+        std::fs::write(&tool_versions, "tiny 3\n").unwrap();
+        std::fs::write(&legacy_file, "1.0.0\n").unwrap();
+
+        let tiny = |config: &mut Config, path: &Path, git_ref: &str| {
+            let tools = [config.tools.get("tiny").unwrap()];
+            let cf: Box<dyn ConfigFile> = match path == legacy_file {
+                true => Box::new(
+                    LegacyVersionFile::parse(&config.settings, path.into(), &tools).unwrap(),
+                ),
+                false => config_file::parse(path, true).unwrap(),
+            };
+            config.config_files = [(path.to_path_buf(), cf)].into_iter().collect();
+            let ts = ToolsetBuilder::new().with_git_ref(git_ref).build(config)?;
+            Ok::<_, color_eyre::Report>(ts.versions["tiny"].requests[0].0.version())
+        };
+        assert_eq!(tiny(&mut config, &tool_versions, "HEAD").unwrap(), "2.1.0");
+        assert_eq!(tiny(&mut config, &legacy_file, "HEAD").unwrap(), "2.0.0");
+        let err = tiny(&mut config, &tool_versions, "not-a-ref").unwrap_err();
+        assert!(err.to_string().starts_with("failed to read not-a-ref"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
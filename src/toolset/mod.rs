@@ -1,40 +1,64 @@
-use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::env::join_paths;
+use std::error::Error;
 use std::fmt::{Display, Formatter};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use std::thread;
 
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 use console::style;
 use dialoguer::theme::ColorfulTheme;
 use dialoguer::MultiSelect;
 use indexmap::IndexMap;
 use itertools::Itertools;
+use once_cell::sync::OnceCell;
 use rayon::prelude::*;
+use tera::Value;
 
-pub use builder::ToolsetBuilder;
-pub use tool_source::ToolSource;
-pub use tool_version::ToolVersion;
+pub use builder::{env_fingerprint, ToolsetBuilder};
+pub use tool_source::{ToolSource, ToolSourceKind};
+pub use tool_version::{ResolvedFrom, ToolVersion};
 pub use tool_version_list::ToolVersionList;
 pub use tool_version_request::ToolVersionRequest;
 
 use crate::config::{Config, MissingRuntimeBehavior};
-use crate::env;
-use crate::plugins::PluginName;
+use crate::file::display_path;
+use crate::hash::hash_parts_sha256;
+use crate::hook_env::EnvChange;
+use crate::plugins::{unalias_plugin, PluginName};
 use crate::runtime_symlinks;
+use crate::shell::{get_shell, ShellType};
 use crate::shims;
+use crate::tera::{get_tera, BASE_CONTEXT};
 use crate::tool::Tool;
+use crate::toolset::version_changes::VersionChanges;
 use crate::ui::multi_progress_report::MultiProgressReport;
+use crate::{dirs, env, hook_env};
 
 mod builder;
 mod tool_source;
 mod tool_version;
 mod tool_version_list;
 mod tool_version_request;
+mod version_changes;
 
 pub type ToolVersionOptions = BTreeMap<String, String>;
 
+/// what to use when a requested version resolves to one that isn't installed
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ResolvePolicy {
+    /// use the version it resolves to, it's installed or reported as missing
+    #[default]
+    Strict,
+    /// use the newest installed version matching the request, e.g.: 20.1.0 for `node@20` even if
+    /// 20.5.0 is available
+    FallbackToInstalled,
+    /// like fallback-to-installed, but use the newest installed version of the same major version
+    /// if none match, e.g.: 20.5.0 for `node@20.1`
+    FallbackToLatest,
+}
+
 /// a toolset is a collection of tools for various plugins
 ///
 /// one example is a .tool-versions file
@@ -45,7 +69,11 @@ pub struct Toolset {
     pub versions: IndexMap<PluginName, ToolVersionList>,
     pub source: Option<ToolSource>,
     pub latest_versions: bool,
+    pub resolve_policy: ResolvePolicy,
     pub disable_tools: BTreeSet<PluginName>,
+    /// `install_versions()` stops at the first failure and returns its error instead of
+    /// installing the rest and returning all of them
+    pub fail_fast: bool,
 }
 
 impl Toolset {
@@ -84,43 +112,113 @@ impl Toolset {
             .iter_mut()
             .collect::<Vec<_>>()
             .par_iter_mut()
-            .for_each(|(_, v)| v.resolve(config, self.latest_versions));
+            .for_each(|(_, v)| v.resolve(config, self.latest_versions, self.resolve_policy));
+        for plugin_name in self.versions.keys() {
+            let unsupported = config
+                .tools
+                .get(plugin_name)
+                .and_then(|t| t.unsupported_platform());
+            if let Some(msg) = unsupported {
+                config.warnings.push(plugin_name, msg);
+            }
+        }
+        for tvl in self.versions.values() {
+            for (requested, tv) in &tvl.fallbacks {
+                config.warnings.push(
+                    tvl.source.to_string(),
+                    format!("{requested} is not installed, using {tv}"),
+                );
+            }
+        }
+        if !self.latest_versions && !config.settings.disable_version_change_warnings {
+            self.warn_version_changes(config);
+        }
+    }
+    /// warns if a request like `node@lts` resolves to a different version than it did last time
+    fn warn_version_changes(&self, config: &Config) {
+        let mut vc = VersionChanges::load(&dirs::CACHE.join("resolved_versions.toml"));
+        for tvl in self.versions.values() {
+            for (request, old, new) in vc.update(tvl.versions.iter()) {
+                let msg = format!("{request} changed from {old} to {new}");
+                config.warnings.push(tvl.source.to_string(), msg);
+            }
+        }
+        if !vc.dirty {
+            return;
+        }
+        if let Err(err) = vc.save() {
+            warn!("failed to save resolved versions: {:#}", err);
+        }
     }
-    pub fn install_missing(&mut self, config: &mut Config, mpr: MultiProgressReport) -> Result<()> {
+    /// drops every tool except `plugins`, errors if one of them has no resolved version
+    pub fn retain_plugins(&mut self, plugins: &BTreeSet<PluginName>) -> Result<()> {
+        for plugin in plugins {
+            match self.versions.get(plugin) {
+                Some(tvl) if !tvl.versions.is_empty() => {}
+                _ => return Err(eyre!("{plugin} has no version set")),
+            }
+        }
+        self.versions.retain(|p, _| plugins.contains(p));
+        Ok(())
+    }
+    /// installs any missing versions (depending on missing_runtime_behavior)
+    /// and returns the versions that were installed
+    /// with `scope` only the versions from that config file are installed
+    pub fn install_missing(
+        &mut self,
+        config: &mut Config,
+        mpr: MultiProgressReport,
+        scope: Option<&Path>,
+    ) -> Result<Vec<(PluginName, ToolVersion)>> {
         let versions = self
-            .list_missing_versions(config)
+            .list_missing_versions(config, scope)
             .into_iter()
             .cloned()
             .collect_vec();
         if versions.is_empty() {
-            return Ok(());
+            return Ok(vec![]);
         }
-        let display_versions = display_versions(&versions);
-        let plural_versions = if versions.len() == 1 { "" } else { "s" };
-        let warn = || {
-            warn!(
-                "Tool{} not installed: {} (install with: rtx install)",
-                plural_versions, display_versions
-            );
+        // one warning per config file so strict mode can say where the versions came from
+        let warn = |config: &Config| -> Result<()> {
+            let by_source = versions.iter().into_group_map_by(|tv| {
+                let tvl = self.versions.get(&tv.plugin_name);
+                tvl.map(|tvl| tvl.source.to_string()).unwrap_or_default()
+            });
+            for (source, versions) in by_source {
+                let plural_versions = if versions.len() == 1 { "" } else { "s" };
+                let versions = versions.into_iter().cloned().collect_vec();
+                let msg = format!(
+                    "Tool{} not installed: {} (install with: rtx install)",
+                    plural_versions,
+                    display_versions(&versions)
+                );
+                config.warnings.push(source, msg);
+            }
+            config.check_strict()
         };
-        match config.settings.missing_runtime_behavior {
-            MissingRuntimeBehavior::Ignore => {}
+        let installed = match config.settings.missing_runtime_behavior {
+            MissingRuntimeBehavior::Ignore => vec![],
             MissingRuntimeBehavior::Warn => {
-                warn();
+                warn(config)?;
+                vec![]
             }
             MissingRuntimeBehavior::Prompt => {
                 let versions = prompt_for_versions(&versions)?;
                 if versions.is_empty() {
-                    warn();
+                    warn(config)?;
+                    vec![]
                 } else {
-                    self.install_versions(config, versions, &mpr, false)?;
+                    self.install_versions(config, versions, &mpr, false)?
                 }
             }
             MissingRuntimeBehavior::AutoInstall => {
-                self.install_versions(config, versions, &mpr, false)?;
+                self.install_versions(config, versions, &mpr, false)?
             }
-        }
-        Ok(())
+        };
+        Ok(installed
+            .into_iter()
+            .map(|tv| (tv.plugin_name.clone(), tv))
+            .collect())
     }
 
     pub fn list_missing_plugins(&self, config: &mut Config) -> Vec<PluginName> {
@@ -141,7 +239,8 @@ impl Toolset {
         versions: Vec<ToolVersion>,
         mpr: &MultiProgressReport,
         force: bool,
-    ) -> Result<()> {
+    ) -> Result<Vec<ToolVersion>> {
+        check_version_policy(config, &versions)?;
         self.latest_versions = true;
         let queue: Vec<_> = versions
             .into_iter()
@@ -150,38 +249,86 @@ impl Toolset {
             .map(|(pn, v)| (config.get_or_create_tool(&pn), v.collect_vec()))
             .collect();
         let queue = Arc::new(Mutex::new(queue));
-        thread::scope(|s| {
+        let fail_fast = self.fail_fast;
+        let first_error = OnceCell::new();
+        let results = thread::scope(|s| {
             (0..config.settings.jobs)
                 .map(|_| {
                     let queue = queue.clone();
                     let config = &*config;
+                    let first_error = &first_error;
                     s.spawn(move || {
-                        let next_job = || queue.lock().unwrap().pop();
+                        let failed = || fail_fast && first_error.get().is_some();
+                        let next_job = || match failed() {
+                            true => None,
+                            false => queue.lock().unwrap().pop(),
+                        };
+                        let mut installed = vec![];
+                        let mut errors = vec![];
                         while let Some((t, versions)) = next_job() {
-                            if !t.is_installed() {
-                                t.install(config, &mut mpr.add(), force)?;
-                            }
-                            for tv in versions {
-                                let tv = tv.request.resolve(config, &t, tv.opts.clone(), true)?;
-                                let mut pr = mpr.add();
-                                t.install_version(config, &tv, &mut pr, force)?;
+                            let install_tool = || -> Result<()> {
+                                if !t.is_installed() {
+                                    t.install(config, &mut mpr.add(), force)?;
+                                }
+                                if let Some(msg) = t.unsupported_platform() {
+                                    config.warnings.push(&t.name, format!("{msg}, skipping"));
+                                    return Ok(());
+                                }
+                                for tv in versions {
+                                    if failed() {
+                                        break;
+                                    }
+                                    let tv =
+                                        tv.request.resolve(config, &t, tv.opts.clone(), true)?;
+                                    let mut pr = mpr.add();
+                                    t.install_version(config, &tv, &mut pr, force)?;
+                                    installed.push(tv);
+                                }
+                                Ok(())
+                            };
+                            match install_tool() {
+                                Err(err) if fail_fast => {
+                                    let _ = first_error.set((t.name.clone(), err));
+                                }
+                                Err(err) => errors.push((t.name.clone(), err)),
+                                Ok(()) => {}
                             }
                         }
-                        Ok(())
+                        (installed, errors)
                     })
                 })
                 .collect_vec()
                 .into_iter()
                 .map(|t| t.join().unwrap())
-                .collect::<Result<Vec<()>>>()
-        })?;
+                .collect_vec()
+        });
+        let (installed, errors): (Vec<_>, Vec<_>) = results.into_iter().unzip();
+        let installed = installed.into_iter().flatten().collect_vec();
+        let errors = errors.into_iter().flatten().collect_vec();
         self.resolve(config);
         shims::reshim(config, self)?;
-        runtime_symlinks::rebuild(config)
+        runtime_symlinks::rebuild(config)?;
+        if let Some((plugin, err)) = first_error.into_inner() {
+            return Err(err.wrap_err(format!("failed to install {plugin}")));
+        }
+        if !errors.is_empty() {
+            let msg = errors
+                .iter()
+                .map(|(plugin, err)| format!("{}: {:#}", plugin, err))
+                .join("\n");
+            return Err(eyre!("failed to install tools:\n{}", msg));
+        }
+        Ok(installed)
     }
-    pub fn list_missing_versions(&self, config: &Config) -> Vec<&ToolVersion> {
+    /// with `scope` only the versions from that config file are listed
+    pub fn list_missing_versions(
+        &self,
+        config: &Config,
+        scope: Option<&Path>,
+    ) -> Vec<&ToolVersion> {
         self.versions
             .iter()
+            .filter(|(_, tvl)| scope.is_none() || tvl.source.path() == scope)
             .map(|(p, tvl)| {
                 let p = config.tools.get(p).unwrap();
                 (p, tvl)
@@ -229,6 +376,14 @@ impl Toolset {
 
         Ok(versions)
     }
+    /// the versions as they were requested (e.g.: `lts` or `prefix:20`), `resolve()` only fills in
+    /// the concrete versions next to them
+    pub fn unresolved(&self) -> Vec<(&PluginName, Vec<&ToolVersionRequest>)> {
+        self.versions
+            .iter()
+            .map(|(p, tvl)| (p, tvl.requests.iter().map(|(tvr, _)| tvr).collect()))
+            .collect()
+    }
     pub fn list_versions_by_plugin(&self, config: &Config) -> Vec<(Arc<Tool>, &Vec<ToolVersion>)> {
         self.versions
             .iter()
@@ -244,6 +399,39 @@ impl Toolset {
             .flat_map(|(p, v)| v.iter().map(|v| (p.clone(), v.clone())))
             .collect()
     }
+    /// the resolved versions whose source matches `predicate`, e.g.: only the ones set with env
+    /// vars with `|s| s.kind() == ToolSourceKind::Env`
+    pub fn by_source<F>(&self, predicate: F) -> Vec<(PluginName, ToolVersion)>
+    where
+        F: Fn(&ToolSource) -> bool,
+    {
+        self.versions
+            .iter()
+            .filter(|(_, tvl)| predicate(&tvl.source))
+            .flat_map(|(p, tvl)| tvl.versions.iter().map(|tv| (p.clone(), tv.clone())))
+            .collect()
+    }
+    /// a hash of the resolved versions in precedence order, their options, and whether they're
+    /// installed, which changes whenever the PATH and env from this toolset would
+    pub fn content_hash(&self, config: &Config) -> String {
+        let parts = self
+            .list_current_versions(config)
+            .into_iter()
+            .map(|(t, tv)| {
+                let opts = tv.opts.iter().map(|(k, v)| format!("{k}={v}")).join(",");
+                let installed = t.is_version_installed(&tv);
+                format!("{}@{} {opts} {installed}", tv.plugin_name, tv.version)
+            });
+        hash_parts_sha256(parts)
+    }
+    /// fails if a current version isn't allowed by its plugin's `version_policy` setting
+    pub fn check_version_policy(&self, config: &Config) -> Result<()> {
+        let versions = self.list_current_versions(config);
+        check_version_policy(
+            config,
+            &versions.into_iter().map(|(_, tv)| tv).collect_vec(),
+        )
+    }
     pub fn list_current_installed_versions(
         &self,
         config: &Config,
@@ -253,36 +441,17 @@ impl Toolset {
             .filter(|(p, v)| p.is_version_installed(v))
             .collect()
     }
-    pub fn list_outdated_versions(&self, config: &Config) -> Vec<(Arc<Tool>, ToolVersion, String)> {
-        self.list_current_versions(config)
-            .into_iter()
-            .filter_map(|(t, tv)| {
-                if t.symlink_path(&tv).is_some() {
-                    // do not consider symlinked versions to be outdated
-                    return None;
-                }
-                let latest = match tv.latest_version(config, &t) {
-                    Ok(latest) => latest,
-                    Err(e) => {
-                        warn!("Error getting latest version for {}: {:#}", t.name, e);
-                        return None;
-                    }
-                };
-                if !t.is_version_installed(&tv) || tv.version != latest {
-                    Some((t, tv, latest))
-                } else {
-                    None
-                }
-            })
-            .collect()
-    }
-    pub fn env_with_path(&self, config: &Config) -> BTreeMap<String, String> {
-        let mut env = self.env(config);
+    pub fn env_with_path(&self, config: &Config) -> Result<BTreeMap<String, String>> {
+        let mut env = self.env(config)?;
         let path_env = self.path_env(config);
         env.insert("PATH".to_string(), path_env);
-        env
+        Ok(env)
     }
-    pub fn env(&self, config: &Config) -> BTreeMap<String, String> {
+    /// later steps override earlier ones: plugin exec-env, config `[env]`, then templates and
+    /// `when_installed` vars which need the resolved toolset, then the vars config unsets. The
+    /// vars from config replace the ones the shell set unless the env_overrides_shell setting is
+    /// off, then those are left out so the shell's value stays.
+    pub fn env(&self, config: &Config) -> Result<BTreeMap<String, String>> {
         let mut entries: BTreeMap<String, String> = self
             .list_current_installed_versions(config)
             .into_par_iter()
@@ -299,8 +468,72 @@ impl Toolset {
             .filter(|(k, _)| !k.starts_with("RTX_TOOL_OPTS__"))
             .rev()
             .collect();
-        entries.extend(config.env.clone());
-        entries
+        let shell_wins =
+            |k: &String| !config.settings.env_overrides_shell && env::PRISTINE_ENV.contains_key(k);
+        let config_env = config.env.clone().into_iter();
+        entries.extend(config_env.filter(|(k, _)| !shell_wins(k)));
+        let templates = self.render_env_templates(config)?.into_iter();
+        entries.extend(templates.filter(|(k, _)| !shell_wins(k)));
+        entries.retain(|k, _| !config.env_remove.contains(k));
+        Ok(entries)
+    }
+    /// renders env vars that reference tools, e.g.: `{{ install_path(tool="java") }}`
+    fn render_env_templates(&self, config: &Config) -> Result<BTreeMap<String, String>> {
+        let mut env = BTreeMap::new();
+        if config.env_templates.is_empty() {
+            return Ok(env);
+        }
+        let install_paths: HashMap<PluginName, String> = self
+            .versions
+            .iter()
+            .filter_map(|(p, tvl)| {
+                let tv = tvl.versions.first()?;
+                Some((p.clone(), tv.install_path().to_string_lossy().to_string()))
+            })
+            .collect();
+        let installed: HashSet<PluginName> = self
+            .list_current_installed_versions(config)
+            .into_iter()
+            .map(|(p, _)| p.name.clone())
+            .collect();
+        for (k, t) in &config.env_templates {
+            if let Some(plugin) = &t.when_installed {
+                if !installed.contains(plugin) {
+                    trace!("skipping env.{k}, {plugin} is not installed");
+                    continue;
+                }
+            }
+            let dir = t.path.parent().unwrap();
+            let mut tera = get_tera(dir);
+            let install_paths = install_paths.clone();
+            tera.register_function(
+                "install_path",
+                move |args: &HashMap<String, Value>| -> tera::Result<Value> {
+                    match args.get("tool") {
+                        Some(Value::String(tool)) => {
+                            match install_paths.get(&unalias_plugin(tool)) {
+                                Some(path) => Ok(Value::String(path.clone())),
+                                None => Err(format!("tool not found in toolset: {tool}").into()),
+                            }
+                        }
+                        _ => Err("install_path tool must be a string".into()),
+                    }
+                },
+            );
+            let mut context = BASE_CONTEXT.clone();
+            context.insert("config_root", dir.to_str().unwrap());
+            let v = tera.render_str(&t.template, &context).map_err(|err| {
+                eyre!(
+                    "failed to parse template: {} env.{}='{}': {}",
+                    display_path(&t.path),
+                    k,
+                    t.template,
+                    render_error_chain(&err)
+                )
+            })?;
+            env.insert(k.clone(), v);
+        }
+        Ok(env)
     }
     pub fn path_env(&self, config: &Config) -> String {
         let installs = self.list_paths(config);
@@ -321,16 +554,77 @@ impl Toolset {
             })
             .collect()
     }
-    pub fn which(&self, config: &Config, bin_name: &str) -> Option<(Arc<Tool>, ToolVersion)> {
-        self.list_current_installed_versions(config)
+    /// what `rtx activate` prints: the env for this toolset like hook-env sets it, including
+    /// __RTX_DIFF so `Config::is_activated()` is true and the next hook-env can undo it, followed
+    /// by the shell's hook that calls back into rtx on prompt or directory change
+    pub fn activation_script(
+        &self,
+        config: &Config,
+        shell_type: ShellType,
+        exe: &Path,
+        status: bool,
+    ) -> Result<String> {
+        let shell = get_shell(Some(shell_type)).unwrap();
+        let mut out = String::new();
+        // nushell reads the env from hook-env as data in its hook, it can't be eval'd here
+        if shell_type != ShellType::Nu {
+            out.push_str(&hook_env::build_env_script(config, self, &*shell)?);
+        }
+        out.push_str(&shell.activate(exe, status));
+        Ok(out)
+    }
+    /// the env of this toolset as a `.envrc` snippet so direnv can load it without rtx's shell
+    /// hook. PATH entries use direnv's `PATH_add`, the last one added comes first in PATH.
+    pub fn envrc(&self, config: &Config) -> Result<String> {
+        let escape = |s: String| shell_escape::unix::escape(s.into()).to_string();
+        let mut out = String::new();
+        for (k, v) in self.env(config)? {
+            out.push_str(&format!("export {}={}\n", escape(k), escape(v)));
+        }
+        let paths = [config.path_dirs.clone(), self.list_paths(config)].concat();
+        for path in paths.into_iter().rev() {
+            let path = path.to_string_lossy().to_string();
+            out.push_str(&format!("PATH_add {}\n", escape(path)));
+        }
+        Ok(out)
+    }
+    /// names of the shims needed for every installed version. The versions in this toolset come
+    /// first in precedence order, so when 2 tools provide the same bin the shim runs the first one.
+    pub fn required_shims(&self, config: &Config) -> Result<Vec<String>> {
+        let current = self.list_current_installed_versions(config);
+        let others = self
+            .list_installed_versions(config)?
+            .into_iter()
+            .filter(|(_, tv)| !current.iter().any(|(_, c)| c == tv))
+            .collect_vec();
+        let bins = current
+            .into_iter()
+            .chain(others)
+            .collect_vec()
             .into_par_iter()
-            .find_first(|(p, tv)| {
-                if let Ok(x) = p.which(config, tv, bin_name) {
-                    x.is_some()
-                } else {
-                    false
+            .map(|(t, tv)| match shims::list_tool_bins(config, &t, &tv) {
+                Ok(bins) => bins.into_iter().sorted().collect_vec(),
+                Err(e) => {
+                    warn!("Error listing bin paths for {}: {:#}", tv, e);
+                    vec![]
                 }
             })
+            .collect::<Vec<_>>();
+        Ok(bins.into_iter().flatten().unique().collect())
+    }
+    /// the installed tool that provides `bin_name` and the path to it, when several tools have
+    /// the bin the one that comes first in PATH wins
+    pub fn which(
+        &self,
+        config: &Config,
+        bin_name: &str,
+    ) -> Option<(PluginName, ToolVersion, PathBuf)> {
+        self.list_current_installed_versions(config)
+            .into_par_iter()
+            .find_map_first(|(p, tv)| match p.which(config, &tv, bin_name) {
+                Ok(Some(path)) => Some((p.name.clone(), tv, path)),
+                _ => None,
+            })
     }
 
     pub fn list_rtvs_with_bin(&self, config: &Config, bin_name: &str) -> Result<Vec<ToolVersion>> {
@@ -349,6 +643,20 @@ impl Toolset {
     }
 }
 
+/// the env changes from running rtx in `from` to running it in `to` (files or directories), e.g.:
+/// to preview what changes on `cd` without changing directories
+pub fn env_diff_for_paths(config: &mut Config, from: &Path, to: &Path) -> Result<Vec<EnvChange>> {
+    let mut env_for_path = |path: &Path| {
+        config.with_env_for_path(path, |config| {
+            let ts = ToolsetBuilder::new().with_path(path).build(config)?;
+            ts.env_with_path(config)
+        })
+    };
+    let from = env_for_path(from)?;
+    let to = env_for_path(to)?;
+    Ok(hook_env::diff_envs(&from, &to))
+}
+
 impl Display for Toolset {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let plugins = &self
@@ -360,6 +668,20 @@ impl Display for Toolset {
     }
 }
 
+/// tera's top level error is always "Failed to render", the useful part is in the sources
+fn render_error_chain(err: &tera::Error) -> String {
+    let mut chain = vec![];
+    let mut source = err.source();
+    while let Some(err) = source {
+        chain.push(err.to_string());
+        source = err.source();
+    }
+    match chain.is_empty() {
+        true => err.to_string(),
+        false => chain.join(": "),
+    }
+}
+
 fn display_versions(versions: &[ToolVersion]) -> String {
     let display_versions = versions
         .iter()
@@ -381,3 +703,233 @@ fn prompt_for_versions(versions: &[ToolVersion]) -> Result<Vec<ToolVersion>> {
         .map(|i| versions[i].clone())
         .collect())
 }
+
+fn check_version_policy(config: &Config, versions: &[ToolVersion]) -> Result<()> {
+    let denied = versions
+        .iter()
+        .filter(|tv| {
+            !matches!(
+                tv.request,
+                ToolVersionRequest::System(_) | ToolVersionRequest::Path(_, _)
+            )
+        })
+        .filter_map(|tv| {
+            let policy = config.settings.version_policy.get(&tv.plugin_name)?;
+            let reason = policy.check(&tv.version)?;
+            Some(format!("{tv}: {reason}"))
+        })
+        .collect_vec();
+    if !denied.is_empty() {
+        return Err(eyre!(
+            "versions not allowed by version_policy:\n  {}",
+            denied.join("\n  ")
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use insta::assert_snapshot;
+
+    use crate::config::config_file::rtx_toml::RtxToml;
+    use crate::config::config_file::ConfigFile;
+    use crate::config::EnvTemplate;
+    use crate::dirs;
+    use crate::env_diff::EnvDiff;
+    use crate::hash::hash_to_str;
+
+    use super::*;
+
+    #[test]
+    fn test_required_shims() {
+        let mut config = Config::load().unwrap();
+        let ts = ToolsetBuilder::new().build(&mut config).unwrap();
+        let shims = ts.required_shims(&config).unwrap();
+        assert!(shims.contains(&"rtx-tiny".to_string()));
+        assert!(shims.contains(&"dummy".to_string()));
+        assert_eq!(shims.iter().unique().count(), shims.len());
+    }
+
+    #[test]
+    fn test_resolve_saves_resolved_versions() {
+        let path = dirs::CACHE.join("resolved_versions.toml");
+        let _ = fs::remove_file(&path);
+        let mut config = Config::load().unwrap();
+        let mut ts = ToolsetBuilder::new().build(&mut config).unwrap();
+        let resolved = fs::read_to_string(&path).unwrap();
+        assert!(resolved.contains(r#""tiny@3" = "3.1.0""#));
+        ts.resolve(&mut config);
+        let resolved = fs::read_to_string(&path).unwrap();
+        assert!(resolved.contains(r#""tiny@3" = "3.1.0""#));
+    }
+
+    #[test]
+    fn test_install_versions_fail_fast() {
+        let mut config = Config::load().unwrap();
+        config.settings.jobs = 1;
+        let tiny = config.get_or_create_tool(&"tiny".into());
+        let dummy = config.get_or_create_tool(&"dummy".into());
+        let tv = |tool: &Tool, v: &str| {
+            let tvr = ToolVersionRequest::new(tool.name.clone(), v);
+            ToolVersion::new(tool, tvr, Default::default(), v.into())
+        };
+        let tiny_tv = tv(&tiny, "ref:fail-fast");
+        let versions = vec![tiny_tv.clone(), tv(&dummy, "other-dummy")];
+        let mut ts = Toolset {
+            fail_fast: true,
+            ..Default::default()
+        };
+        let mpr = MultiProgressReport::new(false);
+        let err = ts
+            .install_versions(&mut config, versions, &mpr, false)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "failed to install dummy");
+        // the queue is taken from the end so dummy is tried first, after it fails tiny is never
+        // started: nothing was locked or created for it
+        let lock = dirs::CACHE
+            .join("lockfiles")
+            .join(hash_to_str(&tiny_tv.install_path()));
+        assert!(!lock.exists());
+        assert!(!tiny_tv.install_path().exists());
+        assert!(!tiny_tv.cache_path().exists());
+    }
+
+    #[test]
+    fn test_check_version_policy() {
+        let mut config = Config::load().unwrap();
+        let ts = ToolsetBuilder::new().build(&mut config).unwrap();
+        assert!(ts.check_version_policy(&config).is_ok());
+        let body = "[settings.version_policy.tiny]\ndeny = [\"3\"]";
+        let cf = RtxToml::parse_str(body, &dirs::CONFIG.join("config.toml")).unwrap();
+        config.settings.version_policy = cf.settings().version_policy;
+        let err = ts.check_version_policy(&config).unwrap_err();
+        assert_snapshot!(err.to_string(), @r###"
+        versions not allowed by version_policy:
+          tiny@3.1.0: versions matching 3 are denied
+        "###);
+    }
+
+    #[test]
+    fn test_activation_script() {
+        let mut config = Config::load().unwrap();
+        let ts = ToolsetBuilder::new().build(&mut config).unwrap();
+        let exe = Path::new("/some/dir/rtx");
+        let script = ts
+            .activation_script(&config, ShellType::Bash, exe, false)
+            .unwrap();
+        let diff = script
+            .lines()
+            .find_map(|l| l.strip_prefix("export __RTX_DIFF="))
+            .unwrap();
+        let diff = EnvDiff::deserialize(diff.trim_matches('\'')).unwrap();
+        let mut paths = config.path_dirs.clone();
+        paths.extend(ts.list_paths(&config));
+        assert_eq!(diff.path, paths);
+        assert!(script.contains("export __RTX_WATCH="));
+        // the hook is set up after the env so it isn't lost when PATH is set
+        assert!(script.find("export PATH=").unwrap() < script.find("_rtx_hook()").unwrap());
+
+        let script = ts
+            .activation_script(&config, ShellType::Nu, exe, false)
+            .unwrap();
+        assert!(!script.contains("__RTX_DIFF"));
+    }
+
+    #[test]
+    fn test_which() {
+        let mut config = Config::load().unwrap();
+        let ts = ToolsetBuilder::new().build(&mut config).unwrap();
+        let (plugin, tv, path) = ts.which(&config, "rtx-tiny").unwrap();
+        assert_eq!(plugin, "tiny");
+        assert_eq!(path, tv.install_path().join("bin/rtx-tiny"));
+        assert!(ts.which(&config, "not-a-bin").is_none());
+    }
+
+    #[test]
+    fn test_env_install_path_template() {
+        let mut config = Config::load().unwrap();
+        let ts = ToolsetBuilder::new().build(&mut config).unwrap();
+        let path = dirs::CURRENT.join(".test.rtx.toml");
+        let template = |t: &str| EnvTemplate {
+            path: path.clone(),
+            template: t.to_string(),
+            when_installed: None,
+        };
+        config.env_templates.insert(
+            "TINY_HOME".into(),
+            template(r#"{{ install_path(tool="tiny") }}"#),
+        );
+        let env = ts.env(&config).unwrap();
+        let tv = ts.versions.get("tiny").unwrap().versions.first().unwrap();
+        assert_eq!(env["TINY_HOME"], tv.install_path().to_string_lossy());
+
+        config.env_templates.insert(
+            "NOPE_HOME".into(),
+            template(r#"{{ install_path(tool="nope") }}"#),
+        );
+        let err = ts.env(&config).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("~/cwd/.test.rtx.toml env.NOPE_HOME"));
+        assert!(err.to_string().contains("tool not found in toolset: nope"));
+    }
+
+    #[test]
+    fn test_env_when_installed() {
+        let mut config = Config::load().unwrap();
+        let ts = ToolsetBuilder::new().build(&mut config).unwrap();
+        let template = |plugin: &str| EnvTemplate {
+            path: dirs::CURRENT.join(".test.rtx.toml"),
+            template: "{{ config_root }}/home".to_string(),
+            when_installed: Some(plugin.to_string()),
+        };
+        config
+            .env_templates
+            .insert("TINY_HOME".into(), template("tiny"));
+        config
+            .env_templates
+            .insert("NOPE_HOME".into(), template("nope"));
+        let env = ts.env(&config).unwrap();
+        assert_eq!(
+            env["TINY_HOME"],
+            dirs::CURRENT.join("home").to_string_lossy()
+        );
+        assert!(!env.contains_key("NOPE_HOME"));
+    }
+
+    #[test]
+    fn test_env_overrides_shell() {
+        let mut config = Config::load().unwrap();
+        let ts = ToolsetBuilder::new().build(&mut config).unwrap();
+        config.env.insert("HOME".into(), "/from/config".into());
+        assert_eq!(ts.env(&config).unwrap()["HOME"], "/from/config");
+
+        config.settings.env_overrides_shell = false;
+        let env = ts.env(&config).unwrap();
+        assert!(!env.contains_key("HOME"));
+        assert_eq!(env["JDXCODE_TINY"], "3.1.0");
+    }
+
+    #[test]
+    fn test_envrc() {
+        let mut config = Config::load().unwrap();
+        let ts = ToolsetBuilder::new()
+            .with_tools(&["tiny"])
+            .build(&mut config)
+            .unwrap();
+        config.env.insert("GREETING".into(), "it's $HOME".into());
+        config.path_dirs = vec![PathBuf::from("/my bin")];
+        let envrc = ts.envrc(&config).unwrap();
+        let envrc = envrc.replace(&*dirs::HOME.to_string_lossy(), "~");
+        let envrc = envrc.lines().filter(|l| !l.starts_with("export REMOTE_"));
+        assert_snapshot!(envrc.collect::<Vec<_>>().join("\n"), @r###"
+        export GREETING='it'\''s $HOME'
+        export JDXCODE_TINY=3.1.0
+        PATH_add ~/data/installs/tiny/3.1.0/bin
+        PATH_add '/my bin'
+        "###);
+    }
+}
@@ -1,34 +1,75 @@
 use std::fmt::{Display, Formatter};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use indexmap::{indexmap, IndexMap};
-use serde_derive::Serialize;
+use serde_derive::{Deserialize, Serialize};
 
 use crate::file::display_path;
 
 /// where a tool version came from (e.g.: .tool-versions)
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ToolSource {
     ToolVersions(PathBuf),
     RtxToml(PathBuf),
     LegacyVersionFile(PathBuf),
     Argument,
-    Environment(String, String),
+    /// an env var and its value, `None` for RTX_TOOL_VERSIONS which holds a whole
+    /// `.tool-versions` file
+    Environment(String, Option<String>),
+    /// a `.tool-versions` list piped to `rtx install -`
+    Stdin,
 }
 
+/// env var values are only shown with `{:#}` since they may contain secrets
 impl Display for ToolSource {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
             ToolSource::ToolVersions(path) => write!(f, "{}", display_path(path)),
             ToolSource::RtxToml(path) => write!(f, "{}", display_path(path)),
             ToolSource::LegacyVersionFile(path) => write!(f, "{}", display_path(path)),
-            ToolSource::Argument => write!(f, "--runtime"),
-            ToolSource::Environment(k, v) => write!(f, "{k}={v}"),
+            ToolSource::Argument => write!(f, "command line argument"),
+            ToolSource::Stdin => write!(f, "stdin"),
+            ToolSource::Environment(k, Some(v)) if f.alternate() => write!(f, "{k}={v}"),
+            ToolSource::Environment(k, _) => write!(f, "{k}"),
         }
     }
 }
 
+/// the kinds of `ToolSource`, e.g.: for `rtx ls --source env`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ToolSourceKind {
+    /// a config file: .tool-versions, .rtx.toml, or a legacy version file
+    Config,
+    /// an env var like RTX_NODE_VERSION
+    Env,
+    /// a command line argument
+    Arg,
+    /// a `.tool-versions` list piped to `rtx install -`
+    Stdin,
+}
+
 impl ToolSource {
+    pub fn kind(&self) -> ToolSourceKind {
+        match self {
+            ToolSource::ToolVersions(_)
+            | ToolSource::RtxToml(_)
+            | ToolSource::LegacyVersionFile(_) => ToolSourceKind::Config,
+            ToolSource::Environment(..) => ToolSourceKind::Env,
+            ToolSource::Argument => ToolSourceKind::Arg,
+            ToolSource::Stdin => ToolSourceKind::Stdin,
+        }
+    }
+
+    /// the config file the version came from, if any
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            ToolSource::ToolVersions(path)
+            | ToolSource::RtxToml(path)
+            | ToolSource::LegacyVersionFile(path) => Some(path),
+            ToolSource::Argument | ToolSource::Environment(..) | ToolSource::Stdin => None,
+        }
+    }
+
     pub fn as_json(&self) -> IndexMap<String, String> {
         match self {
             ToolSource::ToolVersions(path) => indexmap! {
@@ -46,10 +87,18 @@ impl ToolSource {
             ToolSource::Argument => indexmap! {
                 "type".to_string() => "argument".to_string(),
             },
-            ToolSource::Environment(key, value) => indexmap! {
-                "type".to_string() => "environment".to_string(),
-                "key".to_string() => key.to_string(),
-                "value".to_string() => value.to_string(),
+            ToolSource::Environment(key, value) => {
+                let mut json = indexmap! {
+                    "type".to_string() => "environment".to_string(),
+                    "key".to_string() => key.to_string(),
+                };
+                if let Some(value) = value {
+                    json.insert("value".to_string(), value.to_string());
+                }
+                json
+            }
+            ToolSource::Stdin => indexmap! {
+                "type".to_string() => "stdin".to_string(),
             },
         }
     }
@@ -75,10 +124,13 @@ mod tests {
         assert_str_eq!(ts.to_string(), "/home/user/.node-version");
 
         let ts = ToolSource::Argument;
-        assert_str_eq!(ts.to_string(), "--runtime");
+        assert_str_eq!(ts.to_string(), "command line argument");
+
+        let ts = ToolSource::Environment("RTX_NODE_VERSION".to_string(), Some("18".to_string()));
+        assert_str_eq!(ts.to_string(), "RTX_NODE_VERSION");
+        assert_str_eq!(format!("{:#}", ts), "RTX_NODE_VERSION=18");
 
-        let ts = ToolSource::Environment("RTX_NODE_VERSION".to_string(), "18".to_string());
-        assert_str_eq!(ts.to_string(), "RTX_NODE_VERSION=18");
+        assert_str_eq!(ToolSource::Stdin.to_string(), "stdin");
     }
 
     #[test]
@@ -118,7 +170,7 @@ mod tests {
             }
         );
 
-        let ts = ToolSource::Environment("RTX_NODE_VERSION".to_string(), "18".to_string());
+        let ts = ToolSource::Environment("RTX_NODE_VERSION".to_string(), Some("18".to_string()));
         assert_eq!(
             ts.as_json(),
             indexmap! {
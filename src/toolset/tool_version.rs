@@ -1,24 +1,48 @@
 use std::fmt::{Display, Formatter};
 use std::fs;
+use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
 
-use color_eyre::eyre::Result;
-use versions::{Chunk, Version};
+use color_eyre::eyre::{eyre, Report, Result, WrapErr};
+use itertools::Itertools;
+use semver::VersionReq;
+use serde_derive::Serialize;
+use versions::{Chunk, Version, Versioning};
 
 use crate::config::Config;
 use crate::dirs;
 use crate::hash::hash_to_str;
-use crate::plugins::PluginName;
-use crate::tool::Tool;
-use crate::toolset::{ToolVersionOptions, ToolVersionRequest};
+use crate::plugins::{PluginCapability, PluginName};
+use crate::tool::{installs_path, Tool};
+use crate::toolset::{ResolvePolicy, ToolVersionOptions, ToolVersionRequest};
 
 /// represents a single version of a tool for a particular plugin
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone)]
 pub struct ToolVersion {
     pub request: ToolVersionRequest,
     pub plugin_name: PluginName,
     pub version: String,
     pub opts: ToolVersionOptions,
+    /// whether resolving the request found an installed version or one that needs to be
+    /// installed, None for requests that aren't resolved against a list of versions like `ref:`
+    pub resolved_from: Option<ResolvedFrom>,
+}
+
+/// where a resolved version came from, see `ToolVersion::resolved_from`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResolvedFrom {
+    Installed,
+    Remote,
+}
+
+impl Display for ResolvedFrom {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResolvedFrom::Installed => write!(f, "installed"),
+            ResolvedFrom::Remote => write!(f, "remote"),
+        }
+    }
 }
 
 impl ToolVersion {
@@ -33,6 +57,7 @@ impl ToolVersion {
             version,
             request,
             opts,
+            resolved_from: None,
         }
     }
 
@@ -43,12 +68,15 @@ impl ToolVersion {
         opts: ToolVersionOptions,
         latest_versions: bool,
     ) -> Result<Self> {
-        let tv = match request.clone() {
+        let mut tv = match request.clone() {
             ToolVersionRequest::Version(_, v) => {
                 Self::resolve_version(config, tool, request, latest_versions, &v, opts)?
             }
             ToolVersionRequest::Prefix(_, prefix) => {
-                Self::resolve_prefix(config, tool, request, &prefix, opts)?
+                Self::resolve_prefix(config, tool, request, &prefix, opts, latest_versions)?
+            }
+            ToolVersionRequest::Range(_, range) => {
+                Self::resolve_range(config, tool, request, &range, opts, latest_versions)?
             }
             ToolVersionRequest::Sub {
                 sub, orig_version, ..
@@ -61,11 +89,25 @@ impl ToolVersion {
                 &orig_version,
                 opts,
             )?,
+            ToolVersionRequest::Ref(_, r) => {
+                Self::resolve_ref(config, tool, r, opts, latest_versions)?
+            }
+            ToolVersionRequest::Path(_, p) => Self::resolve_path(config, tool, p, opts)?,
             _ => {
                 let version = request.version();
                 Self::new(tool, request, opts, version)
             }
         };
+        if let ToolVersionRequest::Version(..)
+        | ToolVersionRequest::Prefix(..)
+        | ToolVersionRequest::Range(..)
+        | ToolVersionRequest::Sub { .. } = tv.request
+        {
+            tv.resolved_from = Some(match tool.is_version_installed(&tv) {
+                true => ResolvedFrom::Installed,
+                false => ResolvedFrom::Remote,
+            });
+        }
         Ok(tv)
     }
 
@@ -74,7 +116,7 @@ impl ToolVersion {
             ToolVersionRequest::Path(_, p) => p.to_string_lossy().to_string(),
             _ => self.tv_pathname(),
         };
-        dirs::INSTALLS.join(&self.plugin_name).join(pathname)
+        installs_path(&self.plugin_name).join(pathname)
     }
     pub fn cache_path(&self) -> PathBuf {
         dirs::CACHE.join(&self.plugin_name).join(self.tv_pathname())
@@ -90,12 +132,53 @@ impl ToolVersion {
             .resolve(config, tool, self.opts.clone(), true)?;
         Ok(tv.version)
     }
+    /// the installed version to use instead of this one, which isn't installed
+    pub fn installed_fallback(
+        &self,
+        config: &Config,
+        tool: &Tool,
+        policy: ResolvePolicy,
+    ) -> Result<Option<Self>> {
+        let installed = tool.list_installed_versions()?;
+        let matching = match &self.request {
+            ToolVersionRequest::Version(_, v) => {
+                let v = config.resolve_alias(&tool.name, v)?;
+                tool.list_installed_versions_matching(&v)?.pop()
+            }
+            ToolVersionRequest::Prefix(_, prefix) => {
+                tool.list_installed_versions_matching(prefix)?.pop()
+            }
+            ToolVersionRequest::Range(_, range) => {
+                newest_in_range(&parse_range(range)?, &installed)
+            }
+            _ => return Ok(None),
+        };
+        let version = match (policy, &self.request) {
+            (ResolvePolicy::Strict, _) => None,
+            (ResolvePolicy::FallbackToLatest, ToolVersionRequest::Version(..))
+            | (ResolvePolicy::FallbackToLatest, ToolVersionRequest::Prefix(..))
+                if matching.is_none() =>
+            {
+                // widen to the major version but never past it, node@20.1 can't become node@18
+                let major = self.version.split('.').next().unwrap_or_default();
+                tool.list_installed_versions_matching(major)?.pop()
+            }
+            _ => matching,
+        };
+        let tv = version.map(|v| Self::new(tool, self.request.clone(), self.opts.clone(), v));
+        Ok(tv.filter(|tv| tool.is_version_installed(tv)))
+    }
+
     fn tv_pathname(&self) -> String {
         match &self.request {
             ToolVersionRequest::Version(_, _) => self.version.to_string(),
             ToolVersionRequest::Prefix(_, _) => self.version.to_string(),
+            ToolVersionRequest::Range(_, _) => self.version.to_string(),
             ToolVersionRequest::Sub { .. } => self.version.to_string(),
-            ToolVersionRequest::Ref(_, r) => format!("ref-{}", r),
+            // version is the commit sha if the plugin could resolve the ref
+            ToolVersionRequest::Ref(_, _) => {
+                format!("ref-{}", self.version.trim_start_matches("ref:"))
+            }
             ToolVersionRequest::Path(_, p) => format!("path-{}", hash_to_str(p)),
             ToolVersionRequest::System(_) => "system".to_string(),
         }
@@ -109,20 +192,42 @@ impl ToolVersion {
         v: &str,
         opts: ToolVersionOptions,
     ) -> Result<ToolVersion> {
-        let v = config.resolve_alias(&tool.name, v)?;
-        match v.split_once(':') {
-            Some(("ref", r)) => {
-                return Ok(Self::resolve_ref(tool, r.to_string(), opts));
+        let alias = config.resolve_alias(&tool.name, v)?;
+        // with refresh_floating_aliases, an alias like `lts` -> `20` is resolved against the
+        // remote versions on every run instead of sticking to whatever 20.x is installed.
+        // rtx has no lockfile so the only way to freeze a version is to pin the concrete
+        // version in the config file (e.g.: `rtx use --pin`), those are never aliases so
+        // they are not affected by this setting.
+        let refresh =
+            config.settings.refresh_floating_aliases && alias != v && is_floating_version(&alias);
+        let latest_versions = latest_versions || refresh;
+        let v = alias;
+        // aliases support the same syntax as any other version
+        match ToolVersionRequest::new(tool.name.clone(), &v) {
+            ToolVersionRequest::Ref(_, r) => {
+                return Self::resolve_ref(config, tool, r, opts, latest_versions);
             }
-            Some(("path", p)) => {
-                return Self::resolve_path(tool, PathBuf::from(p), opts);
+            ToolVersionRequest::Path(_, p) => {
+                return Self::resolve_path(config, tool, p, opts);
             }
-            Some(("prefix", p)) => {
-                return Self::resolve_prefix(config, tool, request, p, opts);
+            ToolVersionRequest::Prefix(_, p) => {
+                return Self::resolve_prefix(config, tool, request, &p, opts, latest_versions);
             }
-            Some((part, v)) if part.starts_with("sub-") => {
-                let sub = part.split_once('-').unwrap().1;
-                return Self::resolve_sub(config, tool, request, latest_versions, sub, v, opts);
+            ToolVersionRequest::Range(_, r) => {
+                return Self::resolve_range(config, tool, request, &r, opts, latest_versions);
+            }
+            ToolVersionRequest::Sub {
+                sub, orig_version, ..
+            } => {
+                return Self::resolve_sub(
+                    config,
+                    tool,
+                    request,
+                    latest_versions,
+                    &sub,
+                    &orig_version,
+                    opts,
+                );
             }
             _ => (),
         }
@@ -133,13 +238,22 @@ impl ToolVersion {
             return build(v);
         }
         let existing = build(v.clone())?;
-        if tool.is_version_installed(&existing) {
+        if !refresh && tool.is_version_installed(&existing) {
             // if the version is already installed, no need to fetch all the remote versions
             return Ok(existing);
         }
+        if !tool.supports(PluginCapability::ListAll) {
+            // without bin/list-all there is nothing to match against
+            return Ok(existing);
+        }
 
+        let prefer_installed = !latest_versions && config.settings.prefer_installed_versions;
+        if v == "latest" && config.settings.version_filter.contains_key(&tool.name) {
+            // the plugin's latest stable version may be filtered out so pick from all of them
+            return Self::resolve_prefix(config, tool, request, &v, opts, latest_versions);
+        }
         if v == "latest" {
-            if !latest_versions {
+            if prefer_installed {
                 if let Some(v) = tool.latest_installed_version(None)? {
                     return build(v);
                 }
@@ -148,8 +262,8 @@ impl ToolVersion {
                 return build(v);
             }
         }
-        if !latest_versions {
-            let matches = tool.list_installed_versions_matching(&v)?;
+        if prefer_installed {
+            let matches = filter_versions(config, tool, tool.list_installed_versions_matching(&v)?);
             if matches.contains(&v) {
                 return build(v);
             }
@@ -165,7 +279,7 @@ impl ToolVersion {
         if let Some((v, sub)) = v.split_once("!-") {
             return Self::resolve_sub(config, tool, request.clone(), latest_versions, sub, v, opts);
         }
-        Self::resolve_prefix(config, tool, request, &v, opts)
+        Self::resolve_prefix(config, tool, request, &v, opts, latest_versions)
     }
 
     /// resolve a version like `sub-1:12.0.0` which becomes `11.0.0`, `sub-0.1:12.1.0` becomes `12.0.0`
@@ -186,34 +300,146 @@ impl ToolVersion {
         Self::resolve_version(config, tool, request, latest_versions, &v, opts)
     }
 
+    /// the newest installed match if there is one and `prefer_installed_versions` is set,
+    /// otherwise the newest match of the remote and installed versions
     fn resolve_prefix(
         config: &Config,
         tool: &Tool,
         request: ToolVersionRequest,
         prefix: &str,
         opts: ToolVersionOptions,
+        latest_versions: bool,
     ) -> Result<Self> {
+        let installed = tool.list_installed_versions_matching(prefix)?;
+        let installed = filter_versions(config, tool, installed).pop();
+        if !latest_versions && config.settings.prefer_installed_versions {
+            if let Some(v) = installed {
+                return Ok(Self::new(tool, request, opts, v));
+            }
+        }
         let matches = tool.list_versions_matching(&config.settings, prefix)?;
-        let v = match matches.last() {
-            Some(v) => v,
-            None => prefix,
+        let filtered = filter_versions(config, tool, matches.clone());
+        if filtered.is_empty() && !matches.is_empty() && installed.is_none() {
+            return Err(err_filtered(tool, prefix));
+        }
+        let matches = filtered;
+        let v = match (matches.last(), installed) {
+            (Some(remote), Some(installed))
+                if Versioning::new(&installed) > Versioning::new(remote) =>
+            {
+                installed
+            }
+            (Some(v), _) => v.to_string(),
+            (None, Some(installed)) => installed,
+            (None, None) => prefix.to_string(),
             // None => Err(VersionNotFound(plugin.name.clone(), prefix.to_string()))?,
         };
-        Ok(Self::new(tool, request, opts, v.to_string()))
+        Ok(Self::new(tool, request, opts, v))
     }
 
-    fn resolve_ref(tool: &Tool, r: String, opts: ToolVersionOptions) -> Self {
-        let request = ToolVersionRequest::Ref(tool.name.clone(), r);
-        let version = request.version();
-        Self::new(tool, request, opts, version)
+    /// the newest installed version satisfying the range, or the newest remote one
+    fn resolve_range(
+        config: &Config,
+        tool: &Tool,
+        request: ToolVersionRequest,
+        range: &str,
+        opts: ToolVersionOptions,
+        latest_versions: bool,
+    ) -> Result<Self> {
+        let reqs = parse_range(range)?;
+        let newest_match = |versions: &[String]| newest_in_range(&reqs, versions);
+        if !latest_versions {
+            let installed = filter_versions(config, tool, tool.list_installed_versions()?);
+            if let Some(v) = newest_match(&installed) {
+                return Ok(Self::new(tool, request, opts, v));
+            }
+        }
+        let remote = tool.list_remote_versions(&config.settings)?;
+        match newest_match(&filter_versions(config, tool, remote.clone())) {
+            Some(v) => Ok(Self::new(tool, request, opts, v)),
+            None if newest_match(&remote).is_some() => Err(err_filtered(tool, range)),
+            None => {
+                let candidates = remote
+                    .iter()
+                    .sorted_by_cached_key(|v| Versioning::new(v).unwrap_or_default())
+                    .rev()
+                    .take(5)
+                    .join(", ");
+                Err(eyre!(
+                    "no version of {} satisfies {range}, the newest available: {candidates}",
+                    tool.name
+                ))
+            }
+        }
     }
 
-    fn resolve_path(tool: &Tool, path: PathBuf, opts: ToolVersionOptions) -> Result<ToolVersion> {
-        let path = fs::canonicalize(path)?;
-        let request = ToolVersionRequest::Path(tool.name.clone(), path);
-        let version = request.version();
+    /// resolves `ref:main` to the commit sha it points to so `ref:main` and `ref:<sha>` share
+    /// one install. After install `ref-main` is symlinked to `ref-<sha>`, that is used unless
+    /// `latest_versions` is set so we don't need to ask the plugin every time. If `main` has
+    /// moved, the new sha won't be installed yet so it gets installed fresh.
+    fn resolve_ref(
+        config: &Config,
+        tool: &Tool,
+        r: String,
+        opts: ToolVersionOptions,
+        latest_versions: bool,
+    ) -> Result<Self> {
+        let request = ToolVersionRequest::Ref(tool.name.clone(), r.clone());
+        if !latest_versions {
+            if let Some(sha) = tool.installed_ref_sha(&r) {
+                return Ok(Self::new(tool, request, opts, sha));
+            }
+        }
+        let version = match tool.plugin.resolve_ref(&config.settings, &r)? {
+            Some(sha) => sha,
+            None => request.version(),
+        };
         Ok(Self::new(tool, request, opts, version))
     }
+
+    /// relative paths from config files are already relative to the config file, ones from cli
+    /// args are relative to the cwd
+    fn resolve_path(
+        config: &Config,
+        tool: &Tool,
+        path: PathBuf,
+        opts: ToolVersionOptions,
+    ) -> Result<ToolVersion> {
+        let path = fs::canonicalize(&path)
+            .wrap_err_with(|| format!("{}: {} does not exist", tool.name, path.display()))?;
+        let request = ToolVersionRequest::Path(tool.name.clone(), path.clone());
+        let version = request.version();
+        let tv = Self::new(tool, request, opts, version);
+        if !tool.list_bin_paths(config, &tv)?.iter().any(|p| p.is_dir()) {
+            return Err(eyre!(
+                "{}: {} does not contain any bin directories",
+                tool.name,
+                path.display()
+            ));
+        }
+        Ok(tv)
+    }
+}
+
+/// `resolved_from` is left out since it describes how the version was found, not the version
+impl PartialEq for ToolVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.request == other.request
+            && self.plugin_name == other.plugin_name
+            && self.version == other.version
+            && self.opts == other.opts
+    }
+}
+
+impl Eq for ToolVersion {}
+
+impl Hash for ToolVersion {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.request.hash(state);
+        self.plugin_name.hash(state);
+        self.version.hash(state);
+        self.opts.hash(state);
+    }
 }
 
 impl Display for ToolVersion {
@@ -222,6 +448,62 @@ impl Display for ToolVersion {
     }
 }
 
+/// converts an npm-style range (`>=18 <20`, `^18 || ^20`) to rust's semver syntax
+pub(super) fn parse_range(range: &str) -> Result<Vec<VersionReq>> {
+    range
+        .split("||")
+        .map(|part| {
+            let part = regex!(r"([<>=~^]+)\s+").replace_all(part.trim(), "$1");
+            let part = part.split_whitespace().join(", ");
+            VersionReq::parse(&part).wrap_err_with(|| format!("invalid version range: {range}"))
+        })
+        .collect()
+}
+
+/// `versions` without the ones the plugin's `version_filter` setting excludes
+fn filter_versions(config: &Config, tool: &Tool, versions: Vec<String>) -> Vec<String> {
+    match config.settings.version_filter.get(&tool.name) {
+        Some(filter) => filter.retain(versions),
+        None => versions,
+    }
+}
+
+fn err_filtered(tool: &Tool, query: &str) -> Report {
+    eyre!(
+        "every version of {} matching {query} is excluded by its version_filter setting",
+        tool.name
+    )
+}
+
+/// the newest of `versions` matching any of `reqs`, versions that aren't semver are skipped
+fn newest_in_range(reqs: &[VersionReq], versions: &[String]) -> Option<String> {
+    versions
+        .iter()
+        .filter_map(|v| Some((parse_semver(v)?, v)))
+        .filter(|(sv, _)| reqs.iter().any(|req| req.matches(sv)))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, v)| v.to_string())
+}
+
+/// parses a version like "20" or "v1.2" as semver by filling in missing parts
+fn parse_semver(v: &str) -> Option<semver::Version> {
+    let v = v.trim_start_matches('v');
+    let v = match v.split('.').count() {
+        1 => format!("{v}.0.0"),
+        2 => format!("{v}.0"),
+        _ => v.to_string(),
+    };
+    semver::Version::parse(&v).ok()
+}
+
+/// true if the version can point to different versions over time
+/// e.g. is_floating_version("lts") -> true
+/// e.g. is_floating_version("20") -> true
+/// e.g. is_floating_version("20.1.0") -> false
+fn is_floating_version(v: &str) -> bool {
+    v == "latest" || v.starts_with("lts") || v.split('.').count() < 3
+}
+
 /// subtracts sub from orig and removes suffix
 /// e.g. version_sub("18.2.3", "2") -> "16"
 /// e.g. version_sub("18.2.3", "0.1") -> "18.1"
@@ -242,6 +524,12 @@ fn version_sub(orig: &str, sub: &str) -> String {
 mod tests {
     use pretty_assertions::assert_str_eq;
 
+    use regex::Regex;
+
+    use crate::config::config_file::rtx_toml::RtxToml;
+    use crate::config::config_file::ConfigFile;
+    use crate::file;
+
     use super::*;
 
     #[test]
@@ -249,4 +537,146 @@ mod tests {
         assert_str_eq!(version_sub("18.2.3", "2"), "16");
         assert_str_eq!(version_sub("18.2.3", "0.1"), "18.1");
     }
+
+    #[test]
+    fn test_is_floating_version() {
+        assert!(is_floating_version("latest"));
+        assert!(is_floating_version("lts"));
+        assert!(is_floating_version("lts/hydrogen"));
+        assert!(is_floating_version("20"));
+        assert!(is_floating_version("3.11"));
+        assert!(!is_floating_version("20.1.0"));
+    }
+
+    #[test]
+    fn test_parse_range() {
+        let matches = |range: &str, v: &str| {
+            let v = parse_semver(v).unwrap();
+            parse_range(range)
+                .unwrap()
+                .iter()
+                .any(|req| req.matches(&v))
+        };
+        assert!(matches(">=18 <20", "19.1.0"));
+        assert!(!matches(">=18 <20", "20.0.0"));
+        assert!(matches(">= 18", "18"));
+        assert!(matches("^18 || ^20", "20.3"));
+        assert!(!matches("^18 || ^20", "19.0.0"));
+        assert!(matches("~1.2", "1.2.9"));
+        assert!(parse_range(">=foo").is_err());
+    }
+
+    #[test]
+    fn test_resolve_range() {
+        let mut config = Config::load().unwrap();
+        let tool = config.plugin("tiny").unwrap();
+        let resolve = |range: &str, latest_versions| {
+            let request = ToolVersionRequest::new("tiny".into(), range);
+            ToolVersion::resolve(&config, &tool, request, Default::default(), latest_versions)
+        };
+        assert_str_eq!(resolve(">=1 <2", false).unwrap().version, "1.0.1");
+        assert_str_eq!(resolve(">=1 <2", true).unwrap().version, "1.1.0");
+        assert_str_eq!(resolve("^2", false).unwrap().version, "2.1.0");
+        let err = resolve(">=9", false).unwrap_err();
+        assert_str_eq!(
+            err.to_string(),
+            "no version of tiny satisfies >=9, the newest available: 3.1.0, 3.0.1, 3.0.0, 2.1.0, 2.0.1"
+        );
+    }
+
+    #[test]
+    fn test_resolve_prefix() {
+        let mut config = Config::load().unwrap();
+        let resolve = |config: &Config| {
+            let tool = &config.tools["dummy"];
+            let request = ToolVersionRequest::Prefix("dummy".into(), "1".into());
+            ToolVersion::resolve(config, tool, request, Default::default(), false).unwrap()
+        };
+        let tv = resolve(&config);
+        assert_str_eq!(tv.version, "1.0.1");
+        assert_eq!(tv.resolved_from, Some(ResolvedFrom::Installed));
+
+        config.settings.prefer_installed_versions = false;
+        let tv = resolve(&config);
+        assert_str_eq!(tv.version, "1.1.0");
+        assert_eq!(tv.resolved_from, Some(ResolvedFrom::Remote));
+    }
+
+    #[test]
+    fn test_resolve_version_filter() {
+        let mut config = Config::load().unwrap();
+        let tool = config.plugin("tiny").unwrap();
+        let body = "[settings.version_filter.tiny]\nexclude = '^3\\.1'";
+        let cf = RtxToml::parse_str(body, &dirs::CONFIG.join("config.toml")).unwrap();
+        config.settings.version_filter = cf.settings().version_filter;
+        let resolve = |config: &Config, v: &str| {
+            let request = ToolVersionRequest::new("tiny".into(), v);
+            ToolVersion::resolve(config, &tool, request, Default::default(), true)
+        };
+        assert_str_eq!(resolve(&config, "latest").unwrap().version, "3.0.1");
+        assert_str_eq!(resolve(&config, "3").unwrap().version, "3.0.1");
+        assert_str_eq!(resolve(&config, ">=3").unwrap().version, "3.0.1");
+        assert_str_eq!(resolve(&config, "3.1.0").unwrap().version, "3.1.0");
+
+        config
+            .settings
+            .version_filter
+            .get_mut("tiny")
+            .unwrap()
+            .include = Some(Regex::new("^2").unwrap());
+        let err = resolve(&config, "3").unwrap_err();
+        assert_str_eq!(
+            err.to_string(),
+            "every version of tiny matching 3 is excluded by its version_filter setting"
+        );
+        let err = resolve(&config, "^3").unwrap_err();
+        assert_str_eq!(
+            err.to_string(),
+            "every version of tiny matching ^3 is excluded by its version_filter setting"
+        );
+    }
+
+    #[test]
+    fn test_resolve_path() {
+        let mut config = Config::load().unwrap();
+        let tool = config.plugin("tiny").unwrap();
+        let resolve = |p: &str| {
+            let request = ToolVersionRequest::new("tiny".into(), &format!("path:{p}"))
+                .relative_to(&dirs::CURRENT);
+            ToolVersion::resolve(&config, &tool, request, Default::default(), false)
+        };
+        let tv = resolve("../data/installs/tiny/3.1.0").unwrap();
+        let expected = fs::canonicalize(tool.installs_path().join("3.1.0")).unwrap();
+        assert_eq!(tv.install_path(), expected);
+        assert_str_eq!(tv.version, format!("path:{}", expected.display()));
+
+        let err = resolve("../nope").unwrap_err();
+        assert_str_eq!(
+            err.to_string(),
+            format!(
+                "tiny: {} does not exist",
+                dirs::CURRENT.join("../nope").display()
+            )
+        );
+        let err = resolve("../fixtures").unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("does not contain any bin directories"));
+    }
+
+    #[test]
+    fn test_resolve_ref_installed() {
+        let mut config = Config::load().unwrap();
+        let tool = config.plugin("dummy").unwrap();
+        let sha_path = tool.installs_path().join("ref-abc123");
+        let ref_path = tool.installs_path().join("ref-test-branch");
+        fs::create_dir_all(&sha_path).unwrap();
+        file::make_symlink(&PathBuf::from("./ref-abc123"), &ref_path).unwrap();
+        let request = ToolVersionRequest::Ref("dummy".into(), "test-branch".into());
+        let tv = ToolVersion::resolve(&config, &tool, request, Default::default(), false).unwrap();
+        fs::remove_file(&ref_path).unwrap();
+        fs::remove_dir_all(&sha_path).unwrap();
+        assert_str_eq!(tv.version, "abc123");
+        assert_eq!(tv.install_path(), sha_path);
+    }
 }
@@ -1,6 +1,9 @@
+use color_eyre::eyre::Report;
+use itertools::Itertools;
+
 use crate::config::Config;
 use crate::toolset::tool_version_request::ToolVersionRequest;
-use crate::toolset::{ToolSource, ToolVersion, ToolVersionOptions};
+use crate::toolset::{ResolvePolicy, ToolSource, ToolVersion, ToolVersionOptions};
 
 /// represents several versions of a tool for a particular plugin
 #[derive(Debug, Clone)]
@@ -9,6 +12,13 @@ pub struct ToolVersionList {
     pub versions: Vec<ToolVersion>,
     pub requests: Vec<(ToolVersionRequest, ToolVersionOptions)>,
     pub source: ToolSource,
+    /// versions that weren't installed and the installed version used instead, from
+    /// `ResolvePolicy::FallbackToInstalled` or `ResolvePolicy::FallbackToLatest`
+    pub fallbacks: Vec<(ToolVersion, ToolVersion)>,
+    /// requests for the plugin from the same config file that were replaced by `requests`, e.g.: an
+    /// earlier `.tool-versions` line for it, or `nodejs` next to `node`. It's a warning if they
+    /// resolve to a version that `requests` don't.
+    pub shadowed: Vec<ToolVersionRequest>,
 }
 
 impl ToolVersionList {
@@ -18,23 +28,65 @@ impl ToolVersionList {
             versions: Vec::new(),
             requests: vec![],
             source,
+            fallbacks: vec![],
+            shadowed: vec![],
         }
     }
-    pub fn resolve(&mut self, config: &Config, latest_versions: bool) {
+    pub fn resolve(&mut self, config: &Config, latest_versions: bool, policy: ResolvePolicy) {
         self.versions.clear();
+        self.fallbacks.clear();
+        let source = self.source.to_string();
         let plugin = match config.tools.get(&self.plugin_name) {
             Some(p) => p,
             _ => {
-                debug!("Plugin {} is not installed", self.plugin_name);
+                let msg = format!("plugin {} is not installed", self.plugin_name);
+                config.warnings.push(&source, msg);
                 return;
             }
         };
+        let warn = |err: Report| {
+            let msg = format!("failed to resolve tool version: {err:#}");
+            config.warnings.push(&source, msg);
+        };
         for (tvr, opts) in &mut self.requests {
             match tvr.resolve(config, plugin, opts.clone(), latest_versions) {
+                Ok(v) if policy != ResolvePolicy::Strict && !plugin.is_version_installed(&v) => {
+                    match v.installed_fallback(config, plugin, policy) {
+                        Ok(Some(fallback)) => {
+                            self.versions.push(fallback.clone());
+                            self.fallbacks.push((v, fallback));
+                        }
+                        Ok(None) => self.versions.push(v),
+                        Err(err) => warn(err),
+                    }
+                }
                 Ok(v) => self.versions.push(v),
-                Err(err) => warn!("failed to resolve tool version: {:#}", err),
+                Err(err) => warn(err),
             }
         }
+        for tvr in &self.shadowed {
+            let shadowed = match tvr.resolve(config, plugin, Default::default(), latest_versions) {
+                Ok(tv) => tv,
+                Err(err) => {
+                    debug!("failed to resolve shadowed {tvr}: {err:#}");
+                    continue;
+                }
+            };
+            if self
+                .versions
+                .iter()
+                .any(|tv| tv.version == shadowed.version)
+            {
+                continue;
+            }
+            let requests = self.requests.iter().map(|(tvr, _)| tvr).join(" ");
+            let versions = self.versions.iter().map(|tv| &tv.version).join(" ");
+            let msg = format!(
+                "conflicting versions of {}: {tvr} ({}) is replaced by {requests} ({versions}) in the same file",
+                self.plugin_name, shadowed.version
+            );
+            config.warnings.push(&source, msg);
+        }
     }
 }
 
@@ -44,6 +96,8 @@ mod tests {
     use std::fs;
     use std::sync::Arc;
 
+    use insta::assert_snapshot;
+
     use super::*;
     use crate::plugins::ExternalPlugin;
     use crate::tool::Tool;
@@ -60,7 +114,7 @@ mod tests {
             ToolVersionRequest::new(plugin_name, "latest"),
             ToolVersionOptions::default(),
         ));
-        tvl.resolve(&config, true);
+        tvl.resolve(&config, true, ResolvePolicy::Strict);
         assert_eq!(tvl.versions.len(), 1);
     }
 
@@ -78,8 +132,84 @@ mod tests {
             ToolVersionRequest::new(plugin_name, "latest"),
             ToolVersionOptions::default(),
         ));
-        tvl.resolve(&config, true);
+        tvl.resolve(&config, true, ResolvePolicy::Strict);
         assert_eq!(tvl.versions.len(), 0);
         env::remove_var("RTX_FAILURE");
     }
+
+    #[test]
+    fn test_tool_version_list_missing_plugin() {
+        let mut config = Config::default();
+        config.settings.strict = true;
+        let mut tvl = ToolVersionList::new("missing".into(), ToolSource::Argument);
+        tvl.requests.push((
+            ToolVersionRequest::new("missing".into(), "1.0.0"),
+            ToolVersionOptions::default(),
+        ));
+        tvl.resolve(&config, true, ResolvePolicy::Strict);
+        assert!(tvl.versions.is_empty());
+        let err = config.check_strict().unwrap_err();
+        assert!(err.to_string().contains("plugin missing is not installed"));
+    }
+
+    #[test]
+    fn test_tool_version_list_fallback() {
+        let mut config = Config::default();
+        let plugin_name = "tiny".to_string();
+        let plugin = ExternalPlugin::new(&plugin_name);
+        let tool = Tool::new(plugin_name.clone(), Box::new(plugin));
+        config.tools.insert(plugin_name.clone(), Arc::new(tool));
+        let mut tvl = ToolVersionList::new(plugin_name.clone(), ToolSource::Argument);
+        for v in ["^1", "2.0", "4"] {
+            tvl.requests.push((
+                ToolVersionRequest::new(plugin_name.clone(), v),
+                ToolVersionOptions::default(),
+            ));
+        }
+        let versions = |tvl: &ToolVersionList| {
+            let versions = tvl.versions.iter().map(|tv| tv.version.as_str());
+            versions.collect::<Vec<_>>().join(" ")
+        };
+
+        tvl.resolve(&config, true, ResolvePolicy::Strict);
+        assert_eq!(versions(&tvl), "1.1.0 2.0.1 4");
+        assert!(tvl.fallbacks.is_empty());
+
+        tvl.resolve(&config, true, ResolvePolicy::FallbackToInstalled);
+        assert_eq!(versions(&tvl), "1.0.1 2.0.1 4");
+        assert_eq!(tvl.fallbacks.len(), 1);
+
+        // 2.0 can use the installed 2.1.0 but nothing is installed for 4, 3.1.0 isn't used
+        tvl.resolve(&config, true, ResolvePolicy::FallbackToLatest);
+        assert_eq!(versions(&tvl), "1.0.1 2.1.0 4");
+        let fallbacks = tvl.fallbacks.iter().map(|(a, b)| format!("{a} -> {b}"));
+        assert_eq!(
+            fallbacks.collect::<Vec<_>>(),
+            vec!["tiny@1.1.0 -> tiny@1.0.1", "tiny@2.0.1 -> tiny@2.1.0"]
+        );
+    }
+
+    #[test]
+    fn test_tool_version_list_shadowed() {
+        let mut config = Config::load().unwrap();
+        let plugin_name = "tiny".to_string();
+        let source = ToolSource::ToolVersions(dirs::CURRENT.join(".tool-versions"));
+        let mut tvl = ToolVersionList::new(plugin_name.clone(), source);
+        tvl.requests.push((
+            ToolVersionRequest::new(plugin_name.clone(), "3"),
+            ToolVersionOptions::default(),
+        ));
+        for v in ["3.1", "lts-prev"] {
+            tvl.shadowed
+                .push(ToolVersionRequest::new(plugin_name.clone(), v));
+        }
+        config.warnings.take();
+        tvl.resolve(&config, false, ResolvePolicy::Strict);
+        let warnings = config.warnings.take().into_iter().join("\n");
+        assert_snapshot!(warnings, @"~/cwd/.tool-versions: conflicting versions of tiny: tiny@lts-prev (2.0.0) is replaced by tiny@3 (3.1.0) in the same file");
+
+        config.settings.strict = true;
+        tvl.resolve(&config, false, ResolvePolicy::Strict);
+        assert!(config.check_strict().is_err());
+    }
 }
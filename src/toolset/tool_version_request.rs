@@ -1,17 +1,21 @@
 use std::fmt::{Display, Formatter};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 
 use crate::config::Config;
+use crate::dirs;
 use crate::plugins::PluginName;
 use crate::tool::Tool;
+use crate::toolset::tool_version::parse_range;
 use crate::toolset::{ToolVersion, ToolVersionOptions};
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum ToolVersionRequest {
     Version(PluginName, String),
     Prefix(PluginName, String),
+    /// npm-style range like `>=18 <20`
+    Range(PluginName, String),
     Ref(PluginName, String),
     Path(PluginName, PathBuf),
     Sub {
@@ -23,7 +27,12 @@ pub enum ToolVersionRequest {
 }
 
 impl ToolVersionRequest {
+    /// parses a version string from any source (cli args, env vars, config files, aliases)
+    /// so they all support the same syntax:
+    /// `1.2.3`, `latest`, `system`, `ref:<REF>` (or `ref-<REF>`), `prefix:<PREFIX>`,
+    /// `path:<PATH>`, `sub-<PARTIAL>:<ORIG>`, and ranges like `>=18 <20` or `^18 || ^20`
     pub fn new(plugin_name: PluginName, s: &str) -> Self {
+        let s = s.trim();
         let s = match s.split_once('-') {
             Some(("ref", r)) => format!("ref:{}", r),
             _ => s.to_string(),
@@ -37,14 +46,10 @@ impl ToolVersionRequest {
                 sub: p.split_once('-').unwrap().1.to_string(),
                 orig_version: v.to_string(),
             },
-            None => {
-                if s == "system" {
-                    Self::System(plugin_name)
-                } else {
-                    Self::Version(plugin_name, s.to_string())
-                }
-            }
-            _ => panic!("invalid tool version request: {s}"),
+            None if s == "system" => Self::System(plugin_name),
+            None if is_range(&s) => Self::Range(plugin_name, s),
+            // versions can contain ":" so anything else is a version for the plugin to handle
+            _ => Self::Version(plugin_name, s),
         }
     }
 
@@ -52,6 +57,7 @@ impl ToolVersionRequest {
         match self {
             Self::Version(p, _) => p,
             Self::Prefix(p, _) => p,
+            Self::Range(p, _) => p,
             Self::Ref(p, _) => p,
             Self::Path(p, _) => p,
             Self::Sub { plugin_name, .. } => plugin_name,
@@ -63,6 +69,7 @@ impl ToolVersionRequest {
         match self {
             Self::Version(_, v) => v.clone(),
             Self::Prefix(_, p) => format!("prefix:{p}"),
+            Self::Range(_, r) => r.clone(),
             Self::Ref(_, r) => format!("ref:{r}"),
             Self::Path(_, p) => format!("path:{}", p.display()),
             Self::Sub {
@@ -72,6 +79,33 @@ impl ToolVersionRequest {
         }
     }
 
+    /// resolves a relative `path:` version against `dir`, the directory of the config file that
+    /// declared it, rather than wherever rtx happens to run from
+    pub fn relative_to(self, dir: &Path) -> Self {
+        match self {
+            Self::Path(plugin_name, p) => {
+                let p = match p.strip_prefix("~") {
+                    Ok(p) => dirs::HOME.join(p),
+                    Err(_) => dir.join(p),
+                };
+                Self::Path(plugin_name, p)
+            }
+            _ => self,
+        }
+    }
+
+    /// catches versions that can never resolve without asking the plugin
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Self::Prefix(_, s) | Self::Ref(_, s) if s.is_empty() => {
+                Err(eyre!("missing value after ':'"))
+            }
+            Self::Path(_, p) if p.as_os_str().is_empty() => Err(eyre!("missing value after ':'")),
+            Self::Range(_, r) => parse_range(r).map(|_| ()),
+            _ => Ok(()),
+        }
+    }
+
     pub fn resolve(
         &self,
         config: &Config,
@@ -83,8 +117,79 @@ impl ToolVersionRequest {
     }
 }
 
+fn is_range(s: &str) -> bool {
+    s.starts_with(['<', '>', '^', '~']) || s.contains("||")
+}
+
 impl Display for ToolVersionRequest {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}@{}", self.plugin_name(), self.version())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let new = |s| ToolVersionRequest::new("tiny".into(), s);
+        let v = |s: &str| ToolVersionRequest::Version("tiny".into(), s.into());
+        assert_eq!(new("1.2.3"), v("1.2.3"));
+        assert_eq!(new(" 1.2.3\n"), v("1.2.3"));
+        assert_eq!(new("latest"), v("latest"));
+        assert_eq!(new("lts/hydrogen"), v("lts/hydrogen"));
+        assert_eq!(new("temurin-17.0.7+7"), v("temurin-17.0.7+7"));
+        assert_eq!(new("foo:bar"), v("foo:bar"));
+        assert_eq!(new("system"), ToolVersionRequest::System("tiny".into()));
+        assert_eq!(
+            new("ref:main"),
+            ToolVersionRequest::Ref("tiny".into(), "main".into())
+        );
+        assert_eq!(
+            new("ref-main"),
+            ToolVersionRequest::Ref("tiny".into(), "main".into())
+        );
+        assert_eq!(
+            new("prefix:1.2"),
+            ToolVersionRequest::Prefix("tiny".into(), "1.2".into())
+        );
+        assert_eq!(
+            new("path:~/.nodes/14"),
+            ToolVersionRequest::Path("tiny".into(), "~/.nodes/14".into())
+        );
+        assert_eq!(
+            new(">=18 <20"),
+            ToolVersionRequest::Range("tiny".into(), ">=18 <20".into())
+        );
+        assert_eq!(
+            new("^18 || ^20"),
+            ToolVersionRequest::Range("tiny".into(), "^18 || ^20".into())
+        );
+        assert_eq!(
+            new("sub-0.1:latest"),
+            ToolVersionRequest::Sub {
+                plugin_name: "tiny".into(),
+                sub: "0.1".into(),
+                orig_version: "latest".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_version_roundtrip() {
+        for s in [
+            "1.2.3",
+            "system",
+            "ref:main",
+            "prefix:1.2",
+            "path:/opt/node",
+            "sub-2:lts",
+            "~1.2",
+        ] {
+            assert_eq!(ToolVersionRequest::new("tiny".into(), s).version(), s);
+        }
+    }
+}
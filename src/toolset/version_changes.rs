@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::Result;
+
+use crate::file;
+use crate::toolset::{ToolVersion, ToolVersionRequest};
+
+/// remembers what requests like `node@lts` resolved to last time so users can be told when
+/// they start getting a different version
+#[derive(Debug, Default)]
+pub struct VersionChanges {
+    path: PathBuf,
+    versions: BTreeMap<String, String>,
+    /// set when `update()` recorded a new or different version, see `save()`
+    pub dirty: bool,
+}
+
+impl VersionChanges {
+    pub fn load(path: &Path) -> Self {
+        let versions = fs::read_to_string(path)
+            .ok()
+            .and_then(|body| toml::from_str(&body).ok())
+            .unwrap_or_default();
+        Self {
+            path: path.to_path_buf(),
+            versions,
+            dirty: false,
+        }
+    }
+
+    /// records the resolved versions, returning (request, old, new) for the ones that changed
+    pub fn update<'a>(
+        &mut self,
+        tvs: impl Iterator<Item = &'a ToolVersion>,
+    ) -> Vec<(String, String, String)> {
+        let mut changes = vec![];
+        for tv in tvs {
+            let request = tv.request.version();
+            // pinned versions and paths can't change on their own
+            if request == tv.version || matches!(tv.request, ToolVersionRequest::Path(..)) {
+                continue;
+            }
+            let key = format!("{}@{}", tv.plugin_name, request);
+            match self.versions.insert(key.clone(), tv.version.clone()) {
+                Some(old) if old == tv.version => continue,
+                Some(old) => changes.push((key, old, tv.version.clone())),
+                None => {}
+            }
+            self.dirty = true;
+        }
+        changes
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            file::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, toml::to_string(&self.versions)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Config;
+    use crate::dirs;
+
+    use super::*;
+
+    #[test]
+    fn test_update() {
+        let mut config = Config::load().unwrap();
+        let tool = config.plugin("tiny").unwrap();
+        let path = dirs::CACHE.join("test-version-changes.toml");
+        let _ = fs::remove_file(&path);
+        let tv = |request: &str, version: &str| {
+            let request = ToolVersionRequest::new("tiny".into(), request);
+            ToolVersion::new(&tool, request, Default::default(), version.into())
+        };
+
+        let mut vc = VersionChanges::load(&path);
+        assert!(vc
+            .update([tv("lts", "3.0.0"), tv("2.1.0", "2.1.0")].iter())
+            .is_empty());
+        assert!(vc.dirty);
+        vc.save().unwrap();
+
+        let mut vc = VersionChanges::load(&path);
+        assert!(vc.update([tv("lts", "3.0.0")].iter()).is_empty());
+        assert!(!vc.dirty);
+
+        let mut vc = VersionChanges::load(&path);
+        let changes = vc.update([tv("lts", "3.1.0"), tv("2.1.0", "2.1.0")].iter());
+        assert_eq!(
+            changes,
+            vec![("tiny@lts".into(), "3.0.0".into(), "3.1.0".into())]
+        );
+        assert!(vc.dirty);
+        fs::remove_file(&path).unwrap();
+    }
+}